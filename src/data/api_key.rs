@@ -0,0 +1,82 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// An API key information
+#[derive(Serialize, Deserialize, Default)]
+pub struct ApiKey {
+    /// The access rights of the key, empty for full access
+    #[serde(rename = "ACL")]
+    #[serde(default)]
+    pub acl: String,
+
+    /// The public API key
+    #[serde(rename = "APIKey")]
+    #[serde(default)]
+    pub api_key: String,
+
+    /// Timestamp of the key's creation
+    #[serde(rename = "CreatedAt")]
+    #[serde(default)]
+    pub created_at: String,
+
+    /// Unique numeric ID of the API key
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether the key is active or not
+    #[serde(rename = "IsActive")]
+    #[serde(default)]
+    pub is_active: bool,
+
+    /// Indicates whether this is the main account key or a sub-account key
+    #[serde(rename = "IsMaster")]
+    #[serde(default)]
+    pub is_master: bool,
+
+    /// User-selected name for this key
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    pub name: String,
+
+    /// The quarantine level of the key
+    #[serde(rename = "QuarantineValue")]
+    #[serde(default)]
+    pub quarantine_value: i64,
+
+    /// The key's run level, `Normal`, `Softlock` or `Hardlock`
+    #[serde(rename = "Runlevel")]
+    #[serde(default)]
+    pub runlevel: String,
+
+    /// The secret key
+    #[serde(rename = "SecretKey")]
+    #[serde(default)]
+    pub secret_key: String,
+
+    /// The host used for open and click tracking
+    #[serde(rename = "TrackHost")]
+    #[serde(default)]
+    pub track_host: String,
+
+    /// Unique numeric ID of the user owning the key
+    #[serde(rename = "UserID")]
+    #[serde(default)]
+    pub user_id: i128,
+}
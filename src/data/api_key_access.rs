@@ -0,0 +1,67 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// An API key access information
+#[derive(Serialize, Deserialize, Default)]
+pub struct ApiKeyAccess {
+    /// The features the user can access with this key
+    #[serde(rename = "AllowedAccess")]
+    #[serde(default)]
+    pub allowed_access: String,
+
+    /// Unique numeric ID of the API key
+    #[serde(rename = "APIKeyID")]
+    #[serde(default)]
+    pub api_key_id: i128,
+
+    /// Timestamp of the access creation
+    #[serde(rename = "CreatedAt")]
+    #[serde(default)]
+    pub created_at: String,
+
+    /// User-selected name for this access
+    #[serde(rename = "CustomName")]
+    #[serde(default)]
+    pub custom_name: String,
+
+    /// Unique numeric ID of the access
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether the access is active or not
+    #[serde(rename = "IsActive")]
+    #[serde(default)]
+    pub is_active: bool,
+
+    /// Timestamp of the last activity with this access
+    #[serde(rename = "LastActivityAt")]
+    #[serde(default)]
+    pub last_activity_at: String,
+
+    /// Unique numeric ID of the user the access is granted to
+    #[serde(rename = "RealUserID")]
+    #[serde(default)]
+    pub real_user_id: i128,
+
+    /// Unique numeric ID of the user owning the API key
+    #[serde(rename = "UserID")]
+    #[serde(default)]
+    pub user_id: i128,
+}
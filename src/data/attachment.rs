@@ -18,7 +18,7 @@
 use serde::{Deserialize, Serialize};
 
 /// An email attachment
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
 pub struct Attachment {
     /// Defines the type of content being sent out using a MIME type
     ///
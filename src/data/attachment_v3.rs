@@ -0,0 +1,53 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::Attachment;
+use serde::{Deserialize, Serialize};
+
+/// An email attachment for Send API v3
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct AttachmentV3 {
+    /// Defines the type of content being sent out using a MIME type
+    #[serde(rename = "Content-type")]
+    #[serde(default)]
+    pub content_type: String,
+
+    /// The full name of the file (including the file extension)
+    ///
+    /// For inline attachments, this is the name to use in `cid:FILENAME.EXT`
+    #[serde(rename = "Filename")]
+    #[serde(default)]
+    pub filename: String,
+
+    /// Base64 encoded content of the attached file
+    #[serde(rename = "content")]
+    #[serde(default)]
+    pub content: String,
+}
+
+impl From<&Attachment> for AttachmentV3 {
+    fn from(attachment: &Attachment) -> Self {
+        Self {
+            content_type: attachment.content_type.clone(),
+            filename: attachment
+                .content_id
+                .clone()
+                .unwrap_or_else(|| attachment.filename.clone()),
+            content: attachment.base64_content.clone(),
+        }
+    }
+}
@@ -0,0 +1,120 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::BatchJobStatus;
+use crate::polling::PollableJob;
+use serde::{Deserialize, Serialize};
+
+/// A batch job information
+///
+/// Batch jobs are used by asynchronous operations like contact imports and exports
+#[derive(Serialize, Deserialize, Default)]
+pub struct BatchJob {
+    /// Timestamp of the last sign of activity of the job
+    #[serde(rename = "AliveAt")]
+    #[serde(default)]
+    pub alive_at: String,
+
+    /// Unique numeric ID of the API key owning the job
+    #[serde(rename = "APIKeyID")]
+    #[serde(default)]
+    pub api_key_id: i128,
+
+    /// The backend processing the job
+    #[serde(rename = "Backend")]
+    #[serde(default)]
+    pub backend: i64,
+
+    /// Number of items to process
+    #[serde(rename = "Count")]
+    #[serde(default)]
+    pub count: i64,
+
+    /// Number of items already processed
+    #[serde(rename = "Current")]
+    #[serde(default)]
+    pub current: i64,
+
+    /// The job's data, like the id of an uploaded file
+    #[serde(rename = "Data")]
+    #[serde(default)]
+    pub data: String,
+
+    /// Number of items which failed
+    #[serde(rename = "Errcount")]
+    #[serde(default)]
+    pub error_count: i64,
+
+    /// Number of failures after which the job is stopped
+    #[serde(rename = "ErrTreshold")]
+    #[serde(default)]
+    pub error_threshold: i64,
+
+    /// Unique numeric ID of the batch job
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Timestamp of the job's end
+    #[serde(rename = "JobEnd")]
+    #[serde(default)]
+    pub job_end: String,
+
+    /// Timestamp of the job's start
+    #[serde(rename = "JobStart")]
+    #[serde(default)]
+    pub job_start: String,
+
+    /// The type of the job, like `Contact list import csv`
+    #[serde(rename = "JobType")]
+    #[serde(default)]
+    pub job_type: String,
+
+    /// The method used to process items, like `addnoforce`
+    #[serde(rename = "Method")]
+    #[serde(default)]
+    pub method: String,
+
+    /// Unique numeric ID of the object the job works on, like a contact list
+    #[serde(rename = "RefID")]
+    #[serde(default)]
+    pub ref_id: i128,
+
+    /// Timestamp of the job's submission
+    #[serde(rename = "RequestAt")]
+    #[serde(default)]
+    pub request_at: String,
+
+    /// The job's status
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: BatchJobStatus,
+
+    /// The job's throttle level
+    #[serde(rename = "Throttle")]
+    #[serde(default)]
+    pub throttle: i64,
+}
+
+impl PollableJob for BatchJob {
+    fn is_finished(&self) -> bool {
+        matches!(
+            self.status,
+            BatchJobStatus::Completed | BatchJobStatus::Error | BatchJobStatus::Abort
+        )
+    }
+}
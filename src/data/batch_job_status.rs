@@ -0,0 +1,45 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The status of a batch job
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BatchJobStatus {
+    /// The job's data is being uploaded
+    #[serde(rename = "Upload")]
+    Upload,
+    /// The job is waiting to be processed
+    #[serde(rename = "Pending")]
+    #[default]
+    Pending,
+    /// The job is being processed
+    #[serde(rename = "Running")]
+    Running,
+    /// The job is done
+    #[serde(rename = "Completed")]
+    Completed,
+    /// The job failed
+    #[serde(rename = "Error")]
+    Error,
+    /// The job was aborted
+    #[serde(rename = "Abort")]
+    Abort,
+    /// A status unknown to this library
+    #[serde(other)]
+    Unknown,
+}
@@ -0,0 +1,48 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::ContactFilterStatus;
+use serde::{Deserialize, Serialize};
+
+/// A contact filter (segment) information
+#[derive(Serialize, Deserialize, Default)]
+pub struct ContactFilter {
+    /// A description of the segment
+    #[serde(rename = "Description")]
+    #[serde(default)]
+    pub description: String,
+
+    /// The segmentation formula, see [`SegmentExpression`](super::SegmentExpression)
+    #[serde(rename = "Expression")]
+    #[serde(default)]
+    pub expression: String,
+
+    /// Unique numeric ID of the contact filter
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// User-selected name for this contact filter
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    pub name: String,
+
+    /// Indicates whether the filter is used by a campaign or not
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: ContactFilterStatus,
+}
@@ -15,6 +15,8 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
+use std::fmt::{self, Display, Formatter};
+
 /// The contact identifier, can be an id or an email address
 pub enum ContactIdentifier {
     /// Unique numeric ID of the contact you want to retrieve
@@ -23,11 +25,11 @@ pub enum ContactIdentifier {
     ContactEmail(String),
 }
 
-impl ToString for ContactIdentifier {
-    fn to_string(&self) -> String {
+impl Display for ContactIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            ContactIdentifier::ContactId(v) => v.to_string(),
-            ContactIdentifier::ContactEmail(v) => v.to_string(),
+            ContactIdentifier::ContactId(v) => write!(f, "{}", v),
+            ContactIdentifier::ContactEmail(v) => write!(f, "{}", v),
         }
     }
 }
@@ -0,0 +1,113 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The sending statistics of a contact
+#[derive(Serialize, Deserialize, Default)]
+pub struct ContactStatistics {
+    /// Number of messages sent to this contact that were blocked
+    #[serde(rename = "BlockedCount")]
+    #[serde(default)]
+    pub blocked_count: i64,
+
+    /// Number of messages sent to this contact that bounced
+    #[serde(rename = "BouncedCount")]
+    #[serde(default)]
+    pub bounced_count: i64,
+
+    /// Number of messages sent to this contact that were clicked
+    #[serde(rename = "ClickedCount")]
+    #[serde(default)]
+    pub clicked_count: i64,
+
+    /// Unique numeric ID of the contact
+    #[serde(rename = "ContactID")]
+    #[serde(default)]
+    pub contact_id: i128,
+
+    /// Number of messages sent to this contact that were deferred
+    #[serde(rename = "DeferredCount")]
+    #[serde(default)]
+    pub deferred_count: i64,
+
+    /// Number of messages delivered to this contact
+    #[serde(rename = "DeliveredCount")]
+    #[serde(default)]
+    pub delivered_count: i64,
+
+    /// Number of messages sent to this contact that hard bounced
+    #[serde(rename = "HardBouncedCount")]
+    #[serde(default)]
+    pub hard_bounced_count: i64,
+
+    /// Timestamp of last registered activity for this contact - receiving an email, open,
+    /// click, unsubscribe etc
+    #[serde(rename = "LastActivityAt")]
+    #[serde(default)]
+    pub last_activity_at: String,
+
+    /// Number of marketing contacts
+    #[serde(rename = "MarketingContacts")]
+    #[serde(default)]
+    pub marketing_contacts: i64,
+
+    /// Number of messages sent to this contact that were opened
+    #[serde(rename = "OpenedCount")]
+    #[serde(default)]
+    pub opened_count: i64,
+
+    /// Number of messages sent to this contact that are waiting to be processed
+    #[serde(rename = "PreQueuedCount")]
+    #[serde(default)]
+    pub pre_queued_count: i64,
+
+    /// Number of messages sent to this contact that were processed
+    #[serde(rename = "ProcessedCount")]
+    #[serde(default)]
+    pub processed_count: i64,
+
+    /// Number of messages sent to this contact that are waiting in the send queue
+    #[serde(rename = "QueuedCount")]
+    #[serde(default)]
+    pub queued_count: i64,
+
+    /// Number of messages sent to this contact that soft bounced
+    #[serde(rename = "SoftBouncedCount")]
+    #[serde(default)]
+    pub soft_bounced_count: i64,
+
+    /// Number of messages sent to this contact that were reported as spam
+    #[serde(rename = "SpamComplaintCount")]
+    #[serde(default)]
+    pub spam_complaint_count: i64,
+
+    /// Number of messages sent to this contact that led to an unsubscription
+    #[serde(rename = "UnsubscribedCount")]
+    #[serde(default)]
+    pub unsubscribed_count: i64,
+
+    /// Number of marketing contacts created by the user
+    #[serde(rename = "UserMarketingContacts")]
+    #[serde(default)]
+    pub user_marketing_contacts: i64,
+
+    /// Number of times this contact exited a workflow
+    #[serde(rename = "WorkFlowExitedCount")]
+    #[serde(default)]
+    pub work_flow_exited_count: i64,
+}
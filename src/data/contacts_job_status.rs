@@ -0,0 +1,65 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::ContactsListAction;
+use crate::polling::PollableJob;
+use serde::{Deserialize, Serialize};
+
+/// The progress of a bulk contact management job
+#[derive(Serialize, Deserialize, Default)]
+pub struct ContactsJobStatus {
+    /// The actions applied on contact lists
+    #[serde(rename = "ContactsLists")]
+    #[serde(default)]
+    pub contacts_lists: Vec<ContactsListAction>,
+
+    /// Number of contacts processed
+    #[serde(rename = "Count")]
+    #[serde(default)]
+    pub count: i64,
+
+    /// The error message if the job failed
+    #[serde(rename = "Error")]
+    #[serde(default)]
+    pub error: String,
+
+    /// The URL of a file describing each error
+    #[serde(rename = "ErrorFile")]
+    #[serde(default)]
+    pub error_file: String,
+
+    /// Timestamp of the job's end
+    #[serde(rename = "JobEnd")]
+    #[serde(default)]
+    pub job_end: String,
+
+    /// Timestamp of the job's start
+    #[serde(rename = "JobStart")]
+    #[serde(default)]
+    pub job_start: String,
+
+    /// The job's status, like `In Progress`, `Completed` or `Error`
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: String,
+}
+
+impl PollableJob for ContactsJobStatus {
+    fn is_finished(&self) -> bool {
+        matches!(self.status.as_str(), "Completed" | "Error" | "Abort") || !self.error.is_empty()
+    }
+}
@@ -0,0 +1,33 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::SubscriptionAction;
+use serde::{Deserialize, Serialize};
+
+/// An action to apply on a contact list during bulk contact management
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct ContactsListAction {
+    /// Unique numeric ID of the contact list
+    #[serde(rename = "ListID")]
+    #[serde(default)]
+    pub list_id: i128,
+
+    /// The action to apply to the contacts
+    #[serde(rename = "Action")]
+    #[serde(default)]
+    pub action: SubscriptionAction,
+}
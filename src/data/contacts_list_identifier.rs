@@ -15,6 +15,8 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
+use std::fmt::{self, Display, Formatter};
+
 /// The contact list identifier, can be an id or an address
 pub enum ContactsListIdentifier {
     /// Unique numeric ID assigned to this contact list,
@@ -23,11 +25,11 @@ pub enum ContactsListIdentifier {
     ListAddress(String),
 }
 
-impl ToString for ContactsListIdentifier {
-    fn to_string(&self) -> String {
+impl Display for ContactsListIdentifier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
-            ContactsListIdentifier::ListId(v) => v.to_string(),
-            ContactsListIdentifier::ListAddress(v) => v.to_string(),
+            ContactsListIdentifier::ListId(v) => write!(f, "{}", v),
+            ContactsListIdentifier::ListAddress(v) => write!(f, "{}", v),
         }
     }
 }
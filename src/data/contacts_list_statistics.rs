@@ -0,0 +1,102 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The sending statistics of a contact list
+#[derive(Serialize, Deserialize, Default)]
+pub struct ContactsListStatistics {
+    /// Number of active contacts in the list
+    #[serde(rename = "ActiveCount")]
+    #[serde(default)]
+    pub active_count: i64,
+
+    /// Number of active contacts that unsubscribed from the list
+    #[serde(rename = "ActiveUnsubscribedCount")]
+    #[serde(default)]
+    pub active_unsubscribed_count: i64,
+
+    /// Unique email address generated by Mailjet for this list
+    #[serde(rename = "Address")]
+    #[serde(default)]
+    pub address: String,
+
+    /// Number of messages sent to this list that were blocked
+    #[serde(rename = "BlockedCount")]
+    #[serde(default)]
+    pub blocked_count: i64,
+
+    /// Number of messages sent to this list that bounced
+    #[serde(rename = "BouncedCount")]
+    #[serde(default)]
+    pub bounced_count: i64,
+
+    /// Number of messages sent to this list that were clicked
+    #[serde(rename = "ClickedCount")]
+    #[serde(default)]
+    pub clicked_count: i64,
+
+    /// Timestamp of when the contact list was created
+    #[serde(rename = "CreatedAt")]
+    #[serde(default)]
+    pub created_at: String,
+
+    /// Number of messages delivered to this list
+    #[serde(rename = "DeliveredCount")]
+    #[serde(default)]
+    pub delivered_count: i64,
+
+    /// Unique numeric ID of the contact list
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether the contact list is marked as deleted or not
+    #[serde(rename = "IsDeleted")]
+    #[serde(default)]
+    pub is_deleted: bool,
+
+    /// Timestamp of last registered activity for this list
+    #[serde(rename = "LastActivityAt")]
+    #[serde(default)]
+    pub last_activity_at: String,
+
+    /// User-specified name of the contact list
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    pub name: String,
+
+    /// Number of messages sent to this list that were opened
+    #[serde(rename = "OpenedCount")]
+    #[serde(default)]
+    pub opened_count: i64,
+
+    /// Number of messages sent to this list that were reported as spam
+    #[serde(rename = "SpamComplaintCount")]
+    #[serde(default)]
+    pub spam_complaint_count: i64,
+
+    /// Number of contacts registered in this contact list
+    #[serde(rename = "SubscriberCount")]
+    #[serde(default)]
+    pub subscriber_count: i64,
+
+    /// Number of contacts that unsubscribed from the list
+    #[serde(rename = "UnsubscribedCount")]
+    #[serde(default)]
+    pub unsubscribed_count: i64,
+}
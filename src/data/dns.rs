@@ -0,0 +1,78 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::DnsRecordStatus;
+use serde::{Deserialize, Serialize};
+
+/// The DNS configuration of a sending domain
+#[derive(Serialize, Deserialize, Default)]
+pub struct Dns {
+    /// Name of the TXT record holding the DKIM public key
+    #[serde(rename = "DKIMRecordName")]
+    #[serde(default)]
+    pub dkim_record_name: String,
+
+    /// Expected value of the DKIM record
+    #[serde(rename = "DKIMRecordValue")]
+    #[serde(default)]
+    pub dkim_record_value: String,
+
+    /// Status of the DKIM record at the last check
+    #[serde(rename = "DKIMStatus")]
+    #[serde(default)]
+    pub dkim_status: DnsRecordStatus,
+
+    /// The domain name
+    #[serde(rename = "Domain")]
+    #[serde(default)]
+    pub domain: String,
+
+    /// Unique numeric ID of the domain
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether a DNS check is running for this domain or not
+    #[serde(rename = "IsCheckInProgress")]
+    #[serde(default)]
+    pub is_check_in_progress: bool,
+
+    /// Timestamp of the last DNS check
+    #[serde(rename = "LastCheckAt")]
+    #[serde(default)]
+    pub last_check_at: String,
+
+    /// Token to put in a TXT record to prove the domain's ownership
+    #[serde(rename = "OwnerShipToken")]
+    #[serde(default)]
+    pub ownership_token: String,
+
+    /// Name of the TXT record holding the ownership token
+    #[serde(rename = "OwnerShipTokenRecordName")]
+    #[serde(default)]
+    pub ownership_token_record_name: String,
+
+    /// Expected value of the SPF record
+    #[serde(rename = "SPFRecordValue")]
+    #[serde(default)]
+    pub spf_record_value: String,
+
+    /// Status of the SPF record at the last check
+    #[serde(rename = "SPFStatus")]
+    #[serde(default)]
+    pub spf_status: DnsRecordStatus,
+}
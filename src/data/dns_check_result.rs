@@ -0,0 +1,60 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::DnsRecordStatus;
+use serde::{Deserialize, Serialize};
+
+/// The result of a DNS check on a sending domain
+#[derive(Serialize, Deserialize, Default)]
+pub struct DnsCheckResult {
+    /// A list of errors found while checking the DKIM record
+    #[serde(rename = "DKIMErrors")]
+    #[serde(default)]
+    pub dkim_errors: Vec<String>,
+
+    /// The DKIM record currently published for the domain
+    #[serde(rename = "DKIMRecordCurrentValue")]
+    #[serde(default)]
+    pub dkim_record_current_value: String,
+
+    /// Status of the DKIM record
+    #[serde(rename = "DKIMStatus")]
+    #[serde(default)]
+    pub dkim_status: DnsRecordStatus,
+
+    /// A list of errors found while checking the SPF record
+    #[serde(rename = "SPFErrors")]
+    #[serde(default)]
+    pub spf_errors: Vec<String>,
+
+    /// The SPF records currently published for the domain
+    #[serde(rename = "SPFRecordsCurrentValues")]
+    #[serde(default)]
+    pub spf_records_current_values: Vec<String>,
+
+    /// Status of the SPF record
+    #[serde(rename = "SPFStatus")]
+    #[serde(default)]
+    pub spf_status: DnsRecordStatus,
+}
+
+impl DnsCheckResult {
+    /// Returns `true` if both SPF and DKIM records are correctly configured, `false` otherwise
+    pub fn is_valid(&self) -> bool {
+        self.dkim_status == DnsRecordStatus::Ok && self.spf_status == DnsRecordStatus::Ok
+    }
+}
@@ -18,7 +18,7 @@
 use serde::{Deserialize, Serialize};
 
 /// An email address
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
 pub struct EmailAddress {
     /// The email address
     #[serde(rename = "Email")]
@@ -57,6 +57,36 @@ impl EmailAddress {
         }
     }
 
+    /// Creates a recipient from a mailbox like `Jane Doe <jane.doe@example.com>`
+    ///
+    /// A bare address like `jane.doe@example.com` is also accepted
+    ///
+    /// # Parameters
+    ///
+    /// * `mailbox`: The mailbox to parse
+    pub fn from_mailbox(mailbox: &str) -> Self {
+        let mailbox = mailbox.trim();
+
+        match (mailbox.rfind('<'), mailbox.ends_with('>')) {
+            (Some(start), true) => Self {
+                email: mailbox[start + 1..mailbox.len() - 1].trim().to_string(),
+                name: mailbox[..start].trim().trim_matches('"').to_string(),
+            },
+            _ => Self::from_email(mailbox),
+        }
+    }
+
+    /// Formats the recipient as a mailbox like `"Jane Doe" <jane.doe@example.com>`
+    ///
+    /// Only the address is returned if the name is empty
+    pub fn to_mailbox(&self) -> String {
+        if self.name.is_empty() {
+            self.email.clone()
+        } else {
+            format!("\"{}\" <{}>", self.name.replace('"', "\\\""), self.email)
+        }
+    }
+
     /// Returns `true` if no information were entered, `false` otherwise
     pub fn is_empty(&self) -> bool {
         self.name.is_empty() && self.email.is_empty()
@@ -0,0 +1,45 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+/// The reasons why an `.eml` message can't be read or written
+#[derive(Debug)]
+pub enum EmlError {
+    /// A header line isn't like `Name: value`
+    InvalidHeader(String),
+    /// A custom header's name isn't made of printable ASCII characters other than `:`
+    InvalidHeaderName(String),
+    /// A multipart part has no boundary, the content type is given
+    MissingBoundary(String),
+    /// A base64 encoded part can't be decoded, the part's content type is given
+    InvalidBase64(String),
+}
+
+impl Display for EmlError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            EmlError::InvalidHeader(line) => write!(f, "Invalid header line: {}", line),
+            EmlError::InvalidHeaderName(name) => write!(f, "Invalid header name: {:?}", name),
+            EmlError::MissingBoundary(t) => write!(f, "Missing boundary in {} part", t),
+            EmlError::InvalidBase64(t) => write!(f, "Invalid base64 content in {} part", t),
+        }
+    }
+}
+
+impl StdError for EmlError {}
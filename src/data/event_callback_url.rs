@@ -0,0 +1,58 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{EventCallbackUrlStatus, EventCallbackUrlVersion, EventType};
+use serde::{Deserialize, Serialize};
+
+/// A webhook information
+#[derive(Serialize, Deserialize, Default)]
+pub struct EventCallbackUrl {
+    /// Unique numeric ID of the API key the webhook belongs to
+    #[serde(rename = "APIKeyID")]
+    #[serde(default)]
+    pub api_key_id: i128,
+
+    /// The type of event sent to this webhook
+    #[serde(rename = "EventType")]
+    #[serde(default)]
+    pub event_type: EventType,
+
+    /// Unique numeric ID of the webhook
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether this webhook is the backup one, called when the main one fails
+    #[serde(rename = "IsBackup")]
+    #[serde(default)]
+    pub is_backup: bool,
+
+    /// The webhook's status
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: EventCallbackUrlStatus,
+
+    /// The URL called for each event
+    #[serde(rename = "Url")]
+    #[serde(default)]
+    pub url: String,
+
+    /// The payload version
+    #[serde(rename = "Version")]
+    #[serde(default)]
+    pub version: EventCallbackUrlVersion,
+}
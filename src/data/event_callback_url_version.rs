@@ -0,0 +1,51 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The payload version of a webhook
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(try_from = "i64", into = "i64")]
+pub enum EventCallbackUrlVersion {
+    /// One event per call
+    #[default]
+    Single,
+
+    /// Events are grouped in an array
+    Grouped,
+}
+
+impl From<EventCallbackUrlVersion> for i64 {
+    fn from(value: EventCallbackUrlVersion) -> Self {
+        match value {
+            EventCallbackUrlVersion::Single => 1,
+            EventCallbackUrlVersion::Grouped => 2,
+        }
+    }
+}
+
+impl TryFrom<i64> for EventCallbackUrlVersion {
+    type Error = String;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(EventCallbackUrlVersion::Single),
+            2 => Ok(EventCallbackUrlVersion::Grouped),
+            v => Err(format!("Unknown webhook version: {}", v)),
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The type of an email event tracked by Mailjet
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum EventType {
+    /// The message has been sent
+    #[default]
+    #[serde(rename = "sent")]
+    Sent,
+
+    /// The message has been opened
+    #[serde(rename = "open")]
+    Open,
+
+    /// A link inside the message has been clicked
+    #[serde(rename = "click")]
+    Click,
+
+    /// The message bounced
+    #[serde(rename = "bounce")]
+    Bounce,
+
+    /// The message has been blocked
+    #[serde(rename = "blocked")]
+    Blocked,
+
+    /// The recipient reported the message as spam
+    #[serde(rename = "spam")]
+    Spam,
+
+    /// The recipient unsubscribed
+    #[serde(rename = "unsub")]
+    Unsub,
+}
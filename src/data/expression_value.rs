@@ -0,0 +1,117 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A value of the segmentation syntax
+#[derive(Clone, PartialEq, Debug)]
+pub enum ExpressionValue {
+    /// A text, rendered between double quotes
+    Text(String),
+    /// An integer
+    Integer(i64),
+    /// A decimal number
+    Decimal(f64),
+    /// A boolean, rendered as `true` or `false`
+    Boolean(bool),
+    /// A contact property name, rendered as is
+    Property(String),
+    /// A function call like `HoursAgo(24)`, rendered as is with its arguments
+    Function(String, Vec<ExpressionValue>),
+}
+
+impl ExpressionValue {
+    /// The date `hours` hours before the evaluation of the segment
+    ///
+    /// # Parameters
+    ///
+    /// * `hours`: The number of hours
+    pub fn hours_ago(hours: i64) -> Self {
+        ExpressionValue::Function("HoursAgo".to_string(), vec![hours.into()])
+    }
+
+    /// The date `days` days before the evaluation of the segment
+    ///
+    /// # Parameters
+    ///
+    /// * `days`: The number of days
+    pub fn days_ago(days: i64) -> Self {
+        ExpressionValue::Function("DaysAgo".to_string(), vec![days.into()])
+    }
+
+    /// The date of the evaluation of the segment
+    pub fn now() -> Self {
+        ExpressionValue::Function("Now".to_string(), Vec::new())
+    }
+}
+
+impl Display for ExpressionValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpressionValue::Text(v) => {
+                write!(f, "\"{}\"", v.replace('\\', "\\\\").replace('"', "\\\""))
+            }
+            ExpressionValue::Integer(v) => write!(f, "{}", v),
+            ExpressionValue::Decimal(v) => write!(f, "{}", v),
+            ExpressionValue::Boolean(v) => write!(f, "{}", v),
+            ExpressionValue::Property(v) => write!(f, "{}", v),
+            ExpressionValue::Function(name, args) => {
+                let args = args
+                    .iter()
+                    .map(ExpressionValue::to_string)
+                    .collect::<Vec<String>>();
+                write!(f, "{}({})", name, args.join(","))
+            }
+        }
+    }
+}
+
+impl From<&str> for ExpressionValue {
+    fn from(v: &str) -> Self {
+        ExpressionValue::Text(v.to_string())
+    }
+}
+
+impl From<String> for ExpressionValue {
+    fn from(v: String) -> Self {
+        ExpressionValue::Text(v)
+    }
+}
+
+impl From<i64> for ExpressionValue {
+    fn from(v: i64) -> Self {
+        ExpressionValue::Integer(v)
+    }
+}
+
+impl From<i32> for ExpressionValue {
+    fn from(v: i32) -> Self {
+        ExpressionValue::Integer(v.into())
+    }
+}
+
+impl From<f64> for ExpressionValue {
+    fn from(v: f64) -> Self {
+        ExpressionValue::Decimal(v)
+    }
+}
+
+impl From<bool> for ExpressionValue {
+    fn from(v: bool) -> Self {
+        ExpressionValue::Boolean(v)
+    }
+}
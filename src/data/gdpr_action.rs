@@ -0,0 +1,36 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// A privacy related action performed on contacts
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GdprAction {
+    /// A contact was added to the exclusion list
+    #[serde(rename = "exclude")]
+    #[default]
+    Exclude,
+    /// A contact was removed from the exclusion list
+    #[serde(rename = "unexclude")]
+    Unexclude,
+    /// Multiple contacts were added to the exclusion list
+    #[serde(rename = "bulk_exclude")]
+    BulkExclude,
+    /// A contact was deleted
+    #[serde(rename = "forget")]
+    Forget,
+}
@@ -0,0 +1,88 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::GdprAction;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The record of a privacy related action, meant to be kept as a proof of what was done
+#[doc = include_str!("../../doc/exclude_and_forget_contacts.md")]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct GdprAuditRecord {
+    /// The action performed
+    #[serde(rename = "Action")]
+    #[serde(default)]
+    pub action: GdprAction,
+
+    /// The email addresses of the contacts concerned
+    #[serde(rename = "Emails")]
+    #[serde(default)]
+    pub emails: Vec<String>,
+
+    /// The ids of the contacts concerned, when they are known
+    #[serde(rename = "ContactIDs")]
+    #[serde(default)]
+    pub contact_ids: Vec<i128>,
+
+    /// The id of the bulk job, for bulk actions
+    #[serde(rename = "JobID")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_id: Option<i128>,
+
+    /// The HTTP code of the last request sent to Mailjet
+    #[serde(rename = "HttpCode")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_code: Option<u32>,
+
+    /// Indicates whether Mailjet accepted the action or not
+    #[serde(rename = "Success")]
+    #[serde(default)]
+    pub success: bool,
+
+    /// Details on what happened, mostly useful when the action failed
+    #[serde(rename = "Details")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub details: String,
+
+    /// Unix timestamp of the moment the action was performed
+    #[serde(rename = "PerformedAt")]
+    #[serde(default)]
+    pub performed_at: u64,
+}
+
+impl GdprAuditRecord {
+    /// Creates a record for an action performed now
+    ///
+    /// # Parameters
+    ///
+    /// * `action`: The action performed
+    /// * `emails`: The email addresses of the contacts concerned
+    pub fn new(action: GdprAction, emails: Vec<String>) -> Self {
+        Self {
+            action,
+            emails,
+            performed_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or_default(),
+            ..Default::default()
+        }
+    }
+}
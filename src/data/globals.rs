@@ -0,0 +1,357 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{attachment::Attachment, EmailAddress, Message};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// The properties applied to every message of a send request
+///
+/// A property set on a message overrides the global one, except for `Headers` and `Variables`
+/// which are merged, the message's values winning for identical names.
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::data::{EmailAddress, Globals, Message};
+///
+/// let globals = Globals {
+///     from: EmailAddress::from_email("pilot@mailjet.com"),
+///     subject: "Your email flight plan!".to_string(),
+///     variables: [("day".to_string(), "Monday".into())].into(),
+///     ..Default::default()
+/// };
+/// let message = Message {
+///     subject: "Your last flight plan!".to_string(),
+///     variables: [("seat".to_string(), "1A".into())].into(),
+///     ..Default::default()
+/// };
+/// let effective = globals.apply(&message);
+///
+/// assert_eq!(effective.from.email, "pilot@mailjet.com");
+/// assert_eq!(effective.subject, "Your last flight plan!");
+/// assert_eq!(effective.variables.len(), 2);
+/// ```
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct Globals {
+    /// The email's sender
+    ///
+    /// Must be a valid, activated and registered sender for this account
+    #[serde(rename = "From")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "EmailAddress::is_empty")]
+    pub from: EmailAddress,
+
+    /// Specifies the sender name and email address
+    ///
+    /// Used when you want to send emails on behalf of a different email address.
+    /// Must be a valid, activated sender.
+    #[serde(rename = "Sender")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sender: Option<EmailAddress>,
+
+    /// The main recipients
+    ///
+    /// If a recipient is specified twice, it is counted only once
+    #[serde(rename = "To")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub to: Vec<EmailAddress>,
+
+    /// The copy recipients
+    ///
+    /// If a recipient is specified twice, it is counted only once
+    #[serde(rename = "Cc")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cc: Vec<EmailAddress>,
+
+    /// The hidden copy recipients
+    ///
+    /// If a recipient is specified twice, it is counted only once
+    #[serde(rename = "Bcc")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub bcc: Vec<EmailAddress>,
+
+    /// The email's subject
+    #[serde(rename = "Subject")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub subject: String,
+
+    /// The email's body in plain text form
+    ///
+    /// Developper's note: please fill this field for visualy impaired people
+    #[serde(rename = "TextPart")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub text_part: String,
+
+    /// The email's body in HTML format
+    #[serde(rename = "HTMLPart")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub html_part: String,
+
+    /// If you're not providing the body, you can use a template here
+    #[serde(rename = "TemplateID")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<i64>,
+
+    /// The Template ID or Name to use as this email content
+    ///
+    /// Overrides the HTML/Text parts if any.
+    #[serde(rename = "TemplateLanguage")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_language: Option<bool>,
+
+    /// Email Address where a carbon copy with error message is sent to
+    #[serde(rename = "TemplateErrorReporting")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_error_reporting: Option<EmailAddress>,
+
+    /// Define if the message is delivered if an error is discovered in the templating language
+    ///
+    /// By default the delivery is deactivated.
+    #[serde(rename = "TemplateErrorDeliver")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_error_deliver: Option<bool>,
+
+    /// Standard file attachments
+    #[serde(rename = "Attachments")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+
+    /// File attachments for inline use via `cid:FILENAME.EXT`
+    #[serde(rename = "InlinedAttachments")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inlined_attachments: Vec<Attachment>,
+
+    /// Manage message processing priority inside your account (API key) scheduling queue
+    ///
+    /// Default is `2`
+    #[serde(rename = "Priority")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+
+    /// Groups multiple messages in one campaign
+    #[serde(rename = "CustomCampaign")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_campaign: Option<String>,
+
+    /// Block/unblock messages to be sent multiple times inside one campaign to the same contact
+    #[serde(rename = "DeduplicateCampaign")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduplicate_campaign: Option<bool>,
+
+    /// Force or disable open tracking on this message
+    ///
+    /// Can override account preferences. Can only be used with a HTML part.
+    ///
+    /// * `0`: take the values defined on the account
+    /// * `1`: disable the tracking
+    /// * `2`: enable the tracking
+    #[serde(rename = "TrackOpens")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_opens: Option<String>,
+
+    /// Force or disable click tracking on this message
+    ///
+    /// Can override account preferences. Can only be used with a HTML part.
+    ///
+    /// * `0`: take the values defined on the account
+    /// * `1`: disable the tracking
+    /// * `2`: enable the tracking
+    #[serde(rename = "TrackClicks")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_clicks: Option<String>,
+
+    /// Attach a custom ID to the message
+    #[serde(rename = "CustomID")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+
+    /// Attach a payload to the message
+    #[serde(rename = "EventPayload")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_payload: Option<String>,
+
+    /// Add lines to the email's headers, merged with each message's headers
+    #[serde(rename = "Headers")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+
+    /// The email address for replying
+    #[serde(rename = "ReplyTo")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reply_to: Option<EmailAddress>,
+
+    /// Category for Real-time Monitoring to which the message will be attached
+    #[serde(rename = "MonitoringCategory")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub monitoring_category: Option<String>,
+
+    /// Variables used for personalization, merged with each message's variables
+    ///
+    /// Values can be strings, numbers, booleans, lists or objects, like the lists iterated by `{% for %}` loops
+    #[serde(rename = "Variables")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub variables: HashMap<String, Value>,
+
+    /// URL tags to append every URL link in the message
+    ///
+    /// The user needs to provide the query between the `?` and `#` characters in the URL.
+    /// The URLTags value needs to be URL-encoded
+    #[serde(rename = "URLTags")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url_tags: Option<String>,
+}
+
+impl Globals {
+    /// Computes the message Mailjet sends, the message's properties overriding the global ones
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message
+    pub fn apply(&self, message: &Message) -> Message {
+        let mut headers = self.headers.clone();
+        let mut variables = self.variables.clone();
+
+        headers.extend(message.headers.clone());
+        variables.extend(message.variables.clone());
+
+        Message {
+            from: Self::or(&message.from, &self.from),
+            sender: Self::or(&message.sender, &self.sender),
+            to: Self::or(&message.to, &self.to),
+            cc: Self::or(&message.cc, &self.cc),
+            bcc: Self::or(&message.bcc, &self.bcc),
+            subject: Self::or(&message.subject, &self.subject),
+            text_part: Self::or(&message.text_part, &self.text_part),
+            html_part: Self::or(&message.html_part, &self.html_part),
+            template_id: Self::or(&message.template_id, &self.template_id),
+            template_language: Self::or(&message.template_language, &self.template_language),
+            template_error_reporting: Self::or(
+                &message.template_error_reporting,
+                &self.template_error_reporting,
+            ),
+            template_error_deliver: Self::or(
+                &message.template_error_deliver,
+                &self.template_error_deliver,
+            ),
+            attachments: Self::or(&message.attachments, &self.attachments),
+            inlined_attachments: Self::or(&message.inlined_attachments, &self.inlined_attachments),
+            priority: Self::or(&message.priority, &self.priority),
+            custom_campaign: Self::or(&message.custom_campaign, &self.custom_campaign),
+            deduplicate_campaign: Self::or(
+                &message.deduplicate_campaign,
+                &self.deduplicate_campaign,
+            ),
+            track_opens: Self::or(&message.track_opens, &self.track_opens),
+            track_clicks: Self::or(&message.track_clicks, &self.track_clicks),
+            custom_id: Self::or(&message.custom_id, &self.custom_id),
+            event_payload: Self::or(&message.event_payload, &self.event_payload),
+            headers,
+            reply_to: Self::or(&message.reply_to, &self.reply_to),
+            monitoring_category: Self::or(&message.monitoring_category, &self.monitoring_category),
+            variables,
+            url_tags: Self::or(&message.url_tags, &self.url_tags),
+        }
+    }
+
+    /// Returns the message's value, or the global one if the message's value is empty
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message's value
+    /// * `global`: The global value
+    fn or<T: Clone + Default + PartialEq>(message: &T, global: &T) -> T {
+        match *message == T::default() {
+            true => global.clone(),
+            false => message.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn message_overrides_globals() {
+        let globals = Globals {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            text_part: "Hello".to_string(),
+            template_language: Some(true),
+            headers: [
+                ("X-Flight".to_string(), "MJ1".to_string()),
+                ("X-Gate".to_string(), "A".to_string()),
+            ]
+            .into(),
+            ..Default::default()
+        };
+        let message = Message {
+            to: vec![EmailAddress::from_email("passenger2@mailjet.com")],
+            template_language: Some(false),
+            headers: [("X-Gate".to_string(), "B".to_string())].into(),
+            ..Default::default()
+        };
+        let effective = globals.apply(&message);
+
+        assert_eq!(effective.from.email, "pilot@mailjet.com");
+        assert_eq!(effective.to, message.to);
+        assert_eq!(effective.text_part, "Hello");
+        assert_eq!(effective.template_language, Some(false));
+        assert_eq!(effective.headers["X-Flight"], "MJ1");
+        assert_eq!(effective.headers["X-Gate"], "B");
+    }
+
+    #[test]
+    fn serialized_like_a_message() {
+        let globals = Globals {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            serde_json::to_value(&globals).unwrap(),
+            serde_json::json!({ "From": { "Email": "pilot@mailjet.com", "Name": "" } })
+        );
+    }
+}
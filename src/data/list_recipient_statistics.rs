@@ -0,0 +1,103 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The sending statistics of a contact inside a contact list
+#[derive(Serialize, Deserialize, Default)]
+pub struct ListRecipientStatistics {
+    /// Number of messages sent to this list recipient that were blocked
+    #[serde(rename = "BlockedCount")]
+    #[serde(default)]
+    pub blocked_count: i64,
+
+    /// Number of messages sent to this list recipient that bounced
+    #[serde(rename = "BouncedCount")]
+    #[serde(default)]
+    pub bounced_count: i64,
+
+    /// Number of messages sent to this list recipient that were clicked
+    #[serde(rename = "ClickedCount")]
+    #[serde(default)]
+    pub clicked_count: i64,
+
+    /// The contact properties, only returned when `ShowExtraData` is set
+    #[serde(rename = "Data")]
+    #[serde(default)]
+    pub data: Vec<Value>,
+
+    /// Number of messages delivered to this list recipient
+    #[serde(rename = "DeliveredCount")]
+    #[serde(default)]
+    pub delivered_count: i64,
+
+    /// Number of messages sent to this list recipient that hard bounced
+    #[serde(rename = "HardBouncedCount")]
+    #[serde(default)]
+    pub hard_bounced_count: i64,
+
+    /// Timestamp of last registered activity for this list recipient
+    #[serde(rename = "LastActivityAt")]
+    #[serde(default)]
+    pub last_activity_at: String,
+
+    /// Unique numeric ID of the list recipient (the link between a contact and a list)
+    #[serde(rename = "ListRecipientID")]
+    #[serde(default)]
+    pub list_recipient_id: i128,
+
+    /// Number of messages sent to this list recipient that were opened
+    #[serde(rename = "OpenedCount")]
+    #[serde(default)]
+    pub opened_count: i64,
+
+    /// Number of messages sent to this list recipient that are waiting to be processed
+    #[serde(rename = "PreQueuedCount")]
+    #[serde(default)]
+    pub pre_queued_count: i64,
+
+    /// Number of messages sent to this list recipient that were processed
+    #[serde(rename = "ProcessedCount")]
+    #[serde(default)]
+    pub processed_count: i64,
+
+    /// Number of messages sent to this list recipient that are waiting in the send queue
+    #[serde(rename = "QueuedCount")]
+    #[serde(default)]
+    pub queued_count: i64,
+
+    /// Number of messages sent to this list recipient that soft bounced
+    #[serde(rename = "SoftBouncedCount")]
+    #[serde(default)]
+    pub soft_bounced_count: i64,
+
+    /// Number of messages sent to this list recipient that were reported as spam
+    #[serde(rename = "SpamComplaintCount")]
+    #[serde(default)]
+    pub spam_complaint_count: i64,
+
+    /// Number of messages sent to this list recipient that led to an unsubscription
+    #[serde(rename = "UnsubscribedCount")]
+    #[serde(default)]
+    pub unsubscribed_count: i64,
+
+    /// Number of times this list recipient exited a workflow
+    #[serde(rename = "WorkFlowExitedCount")]
+    #[serde(default)]
+    pub work_flow_exited_count: i64,
+}
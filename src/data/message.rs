@@ -15,12 +15,13 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
-use super::{attachment::Attachment, EmailAddress};
-use serde::{Deserialize, Serialize};
+use super::{attachment::Attachment, EmailAddress, EmlError};
+use serde::{ser::Error as _, Deserialize, Serialize};
+use serde_json::Value;
 use std::collections::HashMap;
 
 /// An email
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
 pub struct Message {
     /// The email's sender
     ///
@@ -198,17 +199,126 @@ pub struct Message {
     pub monitoring_category: Option<String>,
 
     /// Global variables used for personalization
+    ///
+    /// Values can be strings, numbers, booleans, lists or objects, like the lists iterated by `{% for %}` loops
     #[serde(rename = "Variables")]
     #[serde(default)]
     #[serde(skip_serializing_if = "HashMap::is_empty")]
-    pub variables: HashMap<String, String>,
+    pub variables: HashMap<String, Value>,
 
     /// URL tags to append every URL link in the message
     ///
     /// The user needs to provide the query between the `?` and `#` characters in the URL.
     /// The URLTags value needs to be URL-encoded
-    #[serde(rename = "MonitoringCategory")]
+    #[serde(rename = "URLTags")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url_tags: Option<String>,
 }
+
+impl Message {
+    /// Adds the fields of a structure to the variables, replacing variables with the same name
+    ///
+    /// # Parameters
+    ///
+    /// * `variables`: Any structure serialized as a JSON object, like a struct or a map
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mailjet_api_wrapper::data::Message;
+    /// use serde::Serialize;
+    ///
+    /// #[derive(Serialize)]
+    /// struct Order {
+    ///     id: u32,
+    ///     items: Vec<String>,
+    /// }
+    ///
+    /// let order = Order { id: 42, items: vec!["Ticket".to_string()] };
+    /// let message = Message::default().with_variables(&order).unwrap();
+    ///
+    /// assert_eq!(message.variables["id"], 42);
+    /// ```
+    pub fn with_variables<T: Serialize>(
+        mut self,
+        variables: &T,
+    ) -> Result<Self, serde_json::Error> {
+        match serde_json::to_value(variables)? {
+            Value::Object(variables) => self.variables.extend(variables),
+            _ => {
+                return Err(serde_json::Error::custom(
+                    "variables must be serialized as an object",
+                ))
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Exports the message as an RFC 5322 message, the content of an `.eml` file
+    ///
+    /// The text and HTML parts become alternatives, inlined attachments are related to them
+    /// with their content IDs, and the custom headers are kept. A message using a template
+    /// stored on Mailjet has no content to export. Custom headers whose names aren't RFC 5322
+    /// field names are refused.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mailjet_api_wrapper::data::{EmailAddress, Message};
+    ///
+    /// let message = Message {
+    ///     from: EmailAddress::from_email("pilot@mailjet.com"),
+    ///     to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+    ///     subject: "Your email flight plan!".to_string(),
+    ///     text_part: "Welcome aboard".to_string(),
+    ///     ..Default::default()
+    /// };
+    /// let eml = message.to_eml().unwrap();
+    ///
+    /// assert!(eml.contains("Subject: Your email flight plan!\r\n"));
+    /// ```
+    pub fn to_eml(&self) -> Result<String, EmlError> {
+        crate::mime::write_eml(self)
+    }
+
+    /// Imports an RFC 5322 message, the content of an `.eml` file
+    ///
+    /// Text and HTML parts are read from the first matching parts, every other part becomes an
+    /// attachment, inlined if it has a content ID. Headers which aren't read into fields nor
+    /// describe how the message was transported are kept as custom headers, the values of
+    /// repeated headers being joined with commas.
+    ///
+    /// # Parameters
+    ///
+    /// * `eml`: The message
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mailjet_api_wrapper::data::Message;
+    ///
+    /// let eml = b"From: pilot@mailjet.com\r\nSubject: Your email flight plan!\r\n\r\nWelcome aboard";
+    /// let message = Message::from_eml(eml).unwrap();
+    ///
+    /// assert_eq!(message.from.email, "pilot@mailjet.com");
+    /// assert_eq!(message.text_part, "Welcome aboard");
+    /// ```
+    pub fn from_eml(eml: &[u8]) -> Result<Self, EmlError> {
+        crate::mime::read_eml(eml)
+    }
+
+    /// Converts an email built with `lettre`
+    ///
+    /// The envelope's recipients missing from the headers, like the `Bcc` recipients `lettre`
+    /// removes from the headers, become `Bcc` recipients
+    ///
+    /// # Parameters
+    ///
+    /// * `email`: The email
+    #[cfg(feature = "lettre")]
+    pub fn from_lettre(email: &lettre::Message) -> Result<Self, EmlError> {
+        crate::lettre_transport::read_lettre_email(email.envelope(), &email.formatted())
+    }
+}
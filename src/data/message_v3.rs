@@ -0,0 +1,339 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{AttachmentV3, EmailAddress, Message, Recipient};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An email for Send API v3
+///
+/// A [`Message`] can be converted into this format with [`MessageV3::from`]. Some Send API v3.1
+/// properties have no v3 equivalent and are ignored by the conversion: `Sender`,
+/// `MonitoringCategory` and `URLTags`.
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct MessageV3 {
+    /// The sender's address
+    ///
+    /// Must be a valid, activated and registered sender for this account
+    #[serde(rename = "FromEmail")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub from_email: String,
+
+    /// The sender's name
+    #[serde(rename = "FromName")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub from_name: String,
+
+    /// The recipients, each one receives a separate email
+    ///
+    /// Cannot be used alongside `To`, `Cc` and `Bcc`
+    #[serde(rename = "Recipients")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub recipients: Vec<Recipient>,
+
+    /// The main recipients, as a comma separated list of mailboxes
+    #[serde(rename = "To")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub to: String,
+
+    /// The copy recipients, as a comma separated list of mailboxes
+    #[serde(rename = "Cc")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub cc: String,
+
+    /// The hidden copy recipients, as a comma separated list of mailboxes
+    #[serde(rename = "Bcc")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub bcc: String,
+
+    /// The email's subject
+    #[serde(rename = "Subject")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub subject: String,
+
+    /// The email's body in plain text form
+    #[serde(rename = "Text-part")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub text_part: String,
+
+    /// The email's body in HTML format
+    #[serde(rename = "Html-part")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub html_part: String,
+
+    /// If you're not providing the body, you can use a template here
+    #[serde(rename = "Mj-TemplateID")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_id: Option<i64>,
+
+    /// Activates the template language
+    #[serde(rename = "Mj-TemplateLanguage")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_language: Option<bool>,
+
+    /// Email address where a carbon copy with error message is sent to
+    #[serde(rename = "MJ-TemplateErrorReporting")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_error_reporting: Option<String>,
+
+    /// Define if the message is delivered if an error is discovered in the templating language
+    ///
+    /// Use `deliver` to deliver anyway, `0` to stop the delivery
+    #[serde(rename = "MJ-TemplateErrorDeliver")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template_error_deliver: Option<String>,
+
+    /// Standard file attachments
+    #[serde(rename = "Attachments")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<AttachmentV3>,
+
+    /// File attachments for inline use via `cid:FILENAME.EXT`
+    #[serde(rename = "Inline_attachments")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub inline_attachments: Vec<AttachmentV3>,
+
+    /// Manage message processing priority inside your account (API key) scheduling queue
+    #[serde(rename = "Mj-prio")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<i64>,
+
+    /// Groups multiple messages in one campaign
+    #[serde(rename = "Mj-campaign")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub campaign: Option<String>,
+
+    /// Block/unblock messages to be sent multiple times inside one campaign to the same contact
+    #[serde(rename = "Mj-deduplicatecampaign")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deduplicate_campaign: Option<bool>,
+
+    /// Force or disable open tracking on this message
+    ///
+    /// * `0`: take the values defined on the account
+    /// * `1`: disable the tracking
+    /// * `2`: enable the tracking
+    #[serde(rename = "Mj-trackopen")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_opens: Option<i64>,
+
+    /// Force or disable click tracking on this message
+    ///
+    /// * `0`: take the values defined on the account
+    /// * `1`: disable the tracking
+    /// * `2`: enable the tracking
+    #[serde(rename = "Mj-trackclick")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub track_clicks: Option<i64>,
+
+    /// Attach a custom ID to the message
+    #[serde(rename = "Mj-CustomID")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_id: Option<String>,
+
+    /// Attach a payload to the message
+    #[serde(rename = "Mj-EventPayLoad")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event_payload: Option<String>,
+
+    /// Add lines to the email's headers
+    #[serde(rename = "Headers")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub headers: HashMap<String, String>,
+
+    /// Global variables used for personalization
+    #[serde(rename = "Vars")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub vars: HashMap<String, Value>,
+}
+
+impl MessageV3 {
+    /// Converts a Send API v3.1 tracking value to its Send API v3 equivalent
+    ///
+    /// # Parameters
+    ///
+    /// * `tracking`: The tracking value, either numeric or `account_default`, `disabled`, `enabled`
+    fn convert_tracking(tracking: &str) -> Option<i64> {
+        match tracking {
+            "account_default" => Some(0),
+            "disabled" => Some(1),
+            "enabled" => Some(2),
+            _ => tracking.parse().ok(),
+        }
+    }
+
+    /// Joins addresses in a comma separated list of mailboxes
+    ///
+    /// # Parameters
+    ///
+    /// * `addresses`: The addresses to join
+    fn join_mailboxes(addresses: &[EmailAddress]) -> String {
+        addresses
+            .iter()
+            .map(EmailAddress::to_mailbox)
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+impl From<&Message> for MessageV3 {
+    /// Converts a Send API v3.1 message
+    ///
+    /// `To`, `Cc` and `Bcc` are kept as mailbox lists, so all the recipients receive a single
+    /// email like with Send API v3.1. Fill `recipients` afterwards to send a separate email to
+    /// each recipient instead.
+    fn from(message: &Message) -> Self {
+        let mut headers = message.headers.clone();
+
+        if let Some(reply_to) = &message.reply_to {
+            headers.insert("Reply-To".to_string(), reply_to.to_mailbox());
+        }
+
+        Self {
+            from_email: message.from.email.clone(),
+            from_name: message.from.name.clone(),
+            recipients: Vec::new(),
+            to: Self::join_mailboxes(&message.to),
+            cc: Self::join_mailboxes(&message.cc),
+            bcc: Self::join_mailboxes(&message.bcc),
+            subject: message.subject.clone(),
+            text_part: message.text_part.clone(),
+            html_part: message.html_part.clone(),
+            template_id: message.template_id,
+            template_language: message.template_language,
+            template_error_reporting: message
+                .template_error_reporting
+                .as_ref()
+                .map(|address| address.email.clone()),
+            template_error_deliver: message
+                .template_error_deliver
+                .map(|deliver| if deliver { "deliver" } else { "0" }.to_string()),
+            attachments: message.attachments.iter().map(AttachmentV3::from).collect(),
+            inline_attachments: message
+                .inlined_attachments
+                .iter()
+                .map(AttachmentV3::from)
+                .collect(),
+            priority: message.priority,
+            campaign: message.custom_campaign.clone(),
+            deduplicate_campaign: message.deduplicate_campaign,
+            track_opens: message
+                .track_opens
+                .as_deref()
+                .and_then(Self::convert_tracking),
+            track_clicks: message
+                .track_clicks
+                .as_deref()
+                .and_then(Self::convert_tracking),
+            custom_id: message.custom_id.clone(),
+            event_payload: message.event_payload.clone(),
+            headers,
+            vars: message.variables.clone(),
+        }
+    }
+}
+
+impl From<Message> for MessageV3 {
+    fn from(message: Message) -> Self {
+        Self::from(&message)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn convert_options() {
+        let mut message = Message {
+            from: EmailAddress::from_email_and_name("pilot@mailjet.com", "Mailjet Pilot"),
+            subject: "Your email flight plan!".to_string(),
+            template_id: Some(1),
+            custom_id: Some("flight-1".to_string()),
+            event_payload: Some("{\"seat\":\"1A\"}".to_string()),
+            track_opens: Some("enabled".to_string()),
+            ..Default::default()
+        };
+        message
+            .to
+            .push(EmailAddress::from_email("passenger1@mailjet.com"));
+        message.variables.insert("day".to_string(), "Monday".into());
+
+        let json = serde_json::to_value(MessageV3::from(&message)).unwrap();
+
+        assert_eq!(json["FromEmail"], "pilot@mailjet.com");
+        assert_eq!(json["FromName"], "Mailjet Pilot");
+        assert_eq!(json["To"], "passenger1@mailjet.com");
+        assert_eq!(json["Mj-TemplateID"], 1);
+        assert_eq!(json["Mj-CustomID"], "flight-1");
+        assert_eq!(json["Mj-EventPayLoad"], "{\"seat\":\"1A\"}");
+        assert_eq!(json["Mj-trackopen"], 2);
+        assert_eq!(json["Vars"]["day"], "Monday");
+        assert!(json.get("Recipients").is_none());
+    }
+
+    #[test]
+    fn convert_to_mailbox_lists() {
+        let mut message = Message::default();
+        message.to.push(EmailAddress::from_email_and_name(
+            "passenger1@mailjet.com",
+            "Passenger 1",
+        ));
+        message
+            .to
+            .push(EmailAddress::from_email("passenger2@mailjet.com"));
+        message
+            .bcc
+            .push(EmailAddress::from_email("copilot@mailjet.com"));
+        message.reply_to = Some(EmailAddress::from_email("tower@mailjet.com"));
+
+        let converted = MessageV3::from(message);
+
+        assert!(converted.recipients.is_empty());
+        assert_eq!(
+            converted.to,
+            "\"Passenger 1\" <passenger1@mailjet.com>, passenger2@mailjet.com"
+        );
+        assert_eq!(converted.bcc, "copilot@mailjet.com");
+        assert_eq!(converted.headers["Reply-To"], "tower@mailjet.com");
+    }
+}
@@ -0,0 +1,54 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// A metasender information
+///
+/// Metasenders are addresses allowed to send on behalf of any validated sender
+#[derive(Serialize, Deserialize, Default)]
+pub struct Metasender {
+    /// Timestamp of when the metasender was created
+    #[serde(rename = "CreatedAt")]
+    #[serde(default)]
+    pub created_at: String,
+
+    /// User-specified description of the metasender
+    #[serde(rename = "Description")]
+    #[serde(default)]
+    pub description: String,
+
+    /// The metasender email address
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// Name of the file used for validation
+    #[serde(rename = "Filename")]
+    #[serde(default)]
+    pub filename: String,
+
+    /// Unique numeric ID of the metasender
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether the metasender is enabled or not
+    #[serde(rename = "IsEnabled")]
+    #[serde(default)]
+    pub is_enabled: bool,
+}
@@ -15,18 +15,74 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
+/// An API key information
+mod api_key;
+/// An API key access information
+mod api_key_access;
 /// An email attachment
 mod attachment;
+/// An email attachment for Send API v3
+mod attachment_v3;
+/// A batch job information
+mod batch_job;
+/// The status of a batch job
+mod batch_job_status;
+/// A comparison operator of the segmentation syntax
+mod comparison_operator;
 /// A contact information
 mod contact;
+/// A contact filter (segment) information
+mod contact_filter;
+/// The status of a contact filter
+mod contact_filter_status;
 /// The contact identifier
 mod contact_identifier;
+/// The sending statistics of a contact
+mod contact_statistics;
+/// A submitted bulk contact management job
+mod contacts_job;
+/// The progress of a bulk contact management job
+mod contacts_job_status;
 /// A contact list information
 mod contacts_list;
+/// An action to apply on a contact list
+mod contacts_list_action;
 /// The contact list identifier
 mod contacts_list_identifier;
+/// The sending statistics of a contact list
+mod contacts_list_statistics;
+/// The DNS configuration of a sending domain
+mod dns;
+/// The result of a DNS check
+mod dns_check_result;
+/// The domain identifier
+mod dns_identifier;
+/// The status of an SPF or DKIM record
+mod dns_record_status;
 /// An email address
 mod email_address;
+/// The type of emails a sender address is used for
+mod email_type;
+/// The errors of .eml message reading
+mod eml_error;
+/// A webhook information
+mod event_callback_url;
+/// The status of a webhook
+mod event_callback_url_status;
+/// The payload version of a webhook
+mod event_callback_url_version;
+/// The type of an email event
+mod event_type;
+/// A value of the segmentation syntax
+mod expression_value;
+/// A privacy related action performed on contacts
+mod gdpr_action;
+/// The record of a privacy related action
+mod gdpr_audit_record;
+/// The properties applied to every message of a send request
+mod globals;
+/// The sending statistics of a contact inside a contact list
+mod list_recipient_statistics;
 /// An email
 mod message;
 /// An email information
@@ -35,23 +91,105 @@ mod message_data;
 mod message_history_data;
 /// A message infomation
 mod message_information_data;
+/// An email for Send API v3
+mod message_v3;
+/// A metasender information
+mod metasender;
+/// A parse route information
+mod parse_route;
+/// The account's profile information
+mod profile;
+/// A recipient of an email sent via Send API v3
+mod recipient;
 /// A sending error
 mod response_error;
 /// The status of an email sending
 mod response_message;
+/// An email sent via Send API v3
+mod response_sent;
 /// A successfully sent email
 mod response_success;
+/// A segmentation formula
+mod segment_expression;
+/// The reasons why a segmentation formula can't be rendered
+mod segment_expression_error;
+/// A sender address information
+mod sender;
+/// The sender identifier
+mod sender_identifier;
+/// The sending statistics of a sender address
+mod sender_statistics;
+/// The status of a sender address
+mod sender_status;
+/// An SMS information
+mod sms;
+/// The cost of an SMS
+mod sms_cost;
+/// An SMS export job information
+mod sms_export;
+/// The status of an SMS
+mod sms_status;
+/// The action to apply to contacts in a contact list
+mod subscription_action;
+/// The account's user information
+mod user;
 
+pub use api_key::ApiKey;
+pub use api_key_access::ApiKeyAccess;
 pub use attachment::Attachment;
+pub use attachment_v3::AttachmentV3;
+pub use batch_job::BatchJob;
+pub use batch_job_status::BatchJobStatus;
+pub use comparison_operator::ComparisonOperator;
 pub use contact::Contact;
+pub use contact_filter::ContactFilter;
+pub use contact_filter_status::ContactFilterStatus;
 pub use contact_identifier::ContactIdentifier;
+pub use contact_statistics::ContactStatistics;
+pub use contacts_job::ContactsJob;
+pub use contacts_job_status::ContactsJobStatus;
 pub use contacts_list::ContactsList;
+pub use contacts_list_action::ContactsListAction;
 pub use contacts_list_identifier::ContactsListIdentifier;
+pub use contacts_list_statistics::ContactsListStatistics;
+pub use dns::Dns;
+pub use dns_check_result::DnsCheckResult;
+pub use dns_identifier::DnsIdentifier;
+pub use dns_record_status::DnsRecordStatus;
 pub use email_address::EmailAddress;
+pub use email_type::EmailType;
+pub use eml_error::EmlError;
+pub use event_callback_url::EventCallbackUrl;
+pub use event_callback_url_status::EventCallbackUrlStatus;
+pub use event_callback_url_version::EventCallbackUrlVersion;
+pub use event_type::EventType;
+pub use expression_value::ExpressionValue;
+pub use gdpr_action::GdprAction;
+pub use gdpr_audit_record::GdprAuditRecord;
+pub use globals::Globals;
+pub use list_recipient_statistics::ListRecipientStatistics;
 pub use message::Message;
 pub use message_data::MessageData;
 pub use message_history_data::MessageHistoryData;
 pub use message_information_data::MessageInformationData;
+pub use message_v3::MessageV3;
+pub use metasender::Metasender;
+pub use parse_route::ParseRoute;
+pub use profile::Profile;
+pub use recipient::Recipient;
 pub use response_error::ResponseError;
 pub use response_message::ResponseMessage;
+pub use response_sent::ResponseSent;
 pub use response_success::ResponseSuccess;
+pub use segment_expression::SegmentExpression;
+pub use segment_expression_error::SegmentExpressionError;
+pub use sender::Sender;
+pub use sender_identifier::SenderIdentifier;
+pub use sender_statistics::SenderStatistics;
+pub use sender_status::SenderStatus;
+pub use sms::Sms;
+pub use sms_cost::SmsCost;
+pub use sms_export::SmsExport;
+pub use sms_status::SmsStatus;
+pub use subscription_action::SubscriptionAction;
+pub use user::User;
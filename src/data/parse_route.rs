@@ -0,0 +1,50 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::EmailType;
+use serde::{Deserialize, Serialize};
+
+/// A parse route information
+///
+/// Emails received at the route's address are posted as JSON to the route's URL
+#[derive(Serialize, Deserialize, Default)]
+pub struct ParseRoute {
+    /// Unique numeric ID of the API key the route belongs to
+    #[serde(rename = "APIKeyID")]
+    #[serde(default)]
+    pub api_key_id: i128,
+
+    /// The email address receiving the emails
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// The type of emails received by this route
+    #[serde(rename = "EmailType")]
+    #[serde(default)]
+    pub email_type: EmailType,
+
+    /// Unique numeric ID of the parse route
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// The URL where received emails are posted
+    #[serde(rename = "Url")]
+    #[serde(default)]
+    pub url: String,
+}
@@ -0,0 +1,92 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The account's profile information
+#[derive(Serialize, Deserialize, Default)]
+pub struct Profile {
+    /// The city of the company's address
+    #[serde(rename = "AddressCity")]
+    #[serde(default)]
+    pub address_city: String,
+
+    /// The country of the company's address
+    #[serde(rename = "AddressCountry")]
+    #[serde(default)]
+    pub address_country: String,
+
+    /// The postal code of the company's address
+    #[serde(rename = "AddressPostalCode")]
+    #[serde(default)]
+    pub address_postal_code: String,
+
+    /// The state of the company's address
+    #[serde(rename = "AddressState")]
+    #[serde(default)]
+    pub address_state: String,
+
+    /// The street of the company's address
+    #[serde(rename = "AddressStreet")]
+    #[serde(default)]
+    pub address_street: String,
+
+    /// The email address receiving invoices
+    #[serde(rename = "BillingEmail")]
+    #[serde(default)]
+    pub billing_email: String,
+
+    /// The company's name
+    #[serde(rename = "CompanyName")]
+    #[serde(default)]
+    pub company_name: String,
+
+    /// The phone number of the account's owner
+    #[serde(rename = "ContactPhone")]
+    #[serde(default)]
+    pub contact_phone: String,
+
+    /// The first name of the account's owner
+    #[serde(rename = "Firstname")]
+    #[serde(default)]
+    pub firstname: String,
+
+    /// Unique numeric ID of the profile
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// The last name of the account's owner
+    #[serde(rename = "Lastname")]
+    #[serde(default)]
+    pub lastname: String,
+
+    /// Unique numeric ID of the user
+    #[serde(rename = "UserID")]
+    #[serde(default)]
+    pub user_id: i128,
+
+    /// The company's VAT number
+    #[serde(rename = "VATNumber")]
+    #[serde(default)]
+    pub vat_number: String,
+
+    /// The company's website
+    #[serde(rename = "Website")]
+    #[serde(default)]
+    pub website: String,
+}
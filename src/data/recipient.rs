@@ -0,0 +1,52 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::EmailAddress;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// A recipient of an email sent via Send API v3
+#[derive(Serialize, Deserialize, Default, Clone, PartialEq, Debug)]
+pub struct Recipient {
+    /// The recipient's address
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// The recipient's name, can be empty
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub name: String,
+
+    /// Variables used for personalization, specific to this recipient
+    #[serde(rename = "Vars")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub vars: HashMap<String, Value>,
+}
+
+impl From<&EmailAddress> for Recipient {
+    fn from(address: &EmailAddress) -> Self {
+        Self {
+            email: address.email.clone(),
+            name: address.name.clone(),
+            ..Default::default()
+        }
+    }
+}
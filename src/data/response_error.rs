@@ -20,7 +20,7 @@ use serde::{Deserialize, Serialize};
 /// A sending error
 ///
 /// When you get one, then mail has not been sent
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct ResponseError {
     /// The error's unique id
     ///
@@ -21,7 +21,7 @@ use serde::{Deserialize, Serialize};
 /// The status of an email sending
 ///
 /// Each message corresponds to one email, no matter how many recipients were present
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct ResponseMessage {
     /// The status, can be `success`or `error`
     #[serde(rename = "Status")]
@@ -0,0 +1,37 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// An email sent via Send API v3
+#[derive(Serialize, Deserialize, Default)]
+pub struct ResponseSent {
+    /// The recipient's address
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// The unique message id
+    #[serde(rename = "MessageID")]
+    #[serde(default)]
+    pub message_id: i128,
+
+    /// The unique message UUID
+    #[serde(rename = "MessageUUID")]
+    #[serde(default)]
+    pub message_uuid: String,
+}
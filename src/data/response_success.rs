@@ -18,7 +18,7 @@
 use serde::{Deserialize, Serialize};
 
 /// A successfully sent email
-#[derive(Serialize, Deserialize, Default)]
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
 pub struct ResponseSuccess {
     /// The email address where this email has been sent
     #[serde(rename = "Email")]
@@ -0,0 +1,369 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{ComparisonOperator, ExpressionValue, SegmentExpressionError};
+use std::fmt::{self, Display, Formatter};
+use std::ops::Not;
+
+/// A segmentation formula, rendered in Mailjet's segmentation syntax with
+/// [`render`](SegmentExpression::render)
+///
+/// `to_string()` renders it too, but without checking it: an `And` or `Or` expression built
+/// with an empty list renders as an empty formula, which Mailjet refuses
+///
+/// ```rust
+/// use mailjet_api_wrapper::data::SegmentExpression;
+///
+/// let expression = SegmentExpression::lt("age", 35)
+///     .and(SegmentExpression::eq("gender", "female"))
+///     .and(!SegmentExpression::is_in_previous_days("last_purchase", 30));
+///
+/// assert_eq!(
+///     expression.render().unwrap(),
+///     "(age<35) AND (gender=\"female\") AND (NOT IsInPreviousDays(last_purchase,30))"
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum SegmentExpression {
+    /// Compares a contact property to a value
+    Comparison(String, ComparisonOperator, ExpressionValue),
+    /// A function returning a boolean, like `IsInPreviousDays(last_purchase,30)`
+    Function(String, Vec<ExpressionValue>),
+    /// All expressions must be true
+    And(Vec<SegmentExpression>),
+    /// At least one expression must be true
+    Or(Vec<SegmentExpression>),
+    /// The expression must be false
+    Not(Box<SegmentExpression>),
+}
+
+impl SegmentExpression {
+    /// Compares a contact property to a value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `operator`: The comparison operator
+    /// * `value`: The value to compare to
+    pub fn compare<T: Into<ExpressionValue>>(
+        property: &str,
+        operator: ComparisonOperator,
+        value: T,
+    ) -> Self {
+        SegmentExpression::Comparison(property.to_string(), operator, value.into())
+    }
+
+    /// The property is equal to the value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `value`: The value to compare to
+    pub fn eq<T: Into<ExpressionValue>>(property: &str, value: T) -> Self {
+        Self::compare(property, ComparisonOperator::Equal, value)
+    }
+
+    /// The property is not equal to the value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `value`: The value to compare to
+    pub fn ne<T: Into<ExpressionValue>>(property: &str, value: T) -> Self {
+        Self::compare(property, ComparisonOperator::NotEqual, value)
+    }
+
+    /// The property is less than the value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `value`: The value to compare to
+    pub fn lt<T: Into<ExpressionValue>>(property: &str, value: T) -> Self {
+        Self::compare(property, ComparisonOperator::LessThan, value)
+    }
+
+    /// The property is less than or equal to the value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `value`: The value to compare to
+    pub fn le<T: Into<ExpressionValue>>(property: &str, value: T) -> Self {
+        Self::compare(property, ComparisonOperator::LessThanOrEqual, value)
+    }
+
+    /// The property is greater than the value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `value`: The value to compare to
+    pub fn gt<T: Into<ExpressionValue>>(property: &str, value: T) -> Self {
+        Self::compare(property, ComparisonOperator::GreaterThan, value)
+    }
+
+    /// The property is greater than or equal to the value
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name
+    /// * `value`: The value to compare to
+    pub fn ge<T: Into<ExpressionValue>>(property: &str, value: T) -> Self {
+        Self::compare(property, ComparisonOperator::GreaterThanOrEqual, value)
+    }
+
+    /// The date property is within the last `days` days
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name, must be a date
+    /// * `days`: The number of days
+    pub fn is_in_previous_days(property: &str, days: i64) -> Self {
+        SegmentExpression::Function(
+            "IsInPreviousDays".to_string(),
+            vec![ExpressionValue::Property(property.to_string()), days.into()],
+        )
+    }
+
+    /// The date property is within the last `hours` hours
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name, must be a date
+    /// * `hours`: The number of hours
+    pub fn is_in_previous_hours(property: &str, hours: i64) -> Self {
+        SegmentExpression::Function(
+            "IsInPreviousHours".to_string(),
+            vec![
+                ExpressionValue::Property(property.to_string()),
+                hours.into(),
+            ],
+        )
+    }
+
+    /// The date property is within the next `days` days
+    ///
+    /// # Parameters
+    ///
+    /// * `property`: The contact property name, must be a date
+    /// * `days`: The number of days
+    pub fn is_in_next_days(property: &str, days: i64) -> Self {
+        SegmentExpression::Function(
+            "IsInNextDays".to_string(),
+            vec![ExpressionValue::Property(property.to_string()), days.into()],
+        )
+    }
+
+    /// Both this expression and `other` must be true
+    ///
+    /// Chained calls are flattened, `a.and(b).and(c)` renders as `a AND b AND c`
+    ///
+    /// # Parameters
+    ///
+    /// * `other`: The other expression
+    pub fn and(self, other: SegmentExpression) -> Self {
+        match self {
+            SegmentExpression::And(mut expressions) => {
+                expressions.push(other);
+                SegmentExpression::And(expressions)
+            }
+            _ => SegmentExpression::And(vec![self, other]),
+        }
+    }
+
+    /// This expression or `other` must be true
+    ///
+    /// Chained calls are flattened, `a.or(b).or(c)` renders as `a OR b OR c`
+    ///
+    /// # Parameters
+    ///
+    /// * `other`: The other expression
+    pub fn or(self, other: SegmentExpression) -> Self {
+        match self {
+            SegmentExpression::Or(mut expressions) => {
+                expressions.push(other);
+                SegmentExpression::Or(expressions)
+            }
+            _ => SegmentExpression::Or(vec![self, other]),
+        }
+    }
+
+    /// Renders the formula in Mailjet's segmentation syntax
+    ///
+    /// Fails if an `And` or `Or` expression, even nested, has no operand
+    pub fn render(&self) -> Result<String, SegmentExpressionError> {
+        self.check()?;
+        Ok(self.to_string())
+    }
+
+    /// Checks that every `And` and `Or` expression has operands
+    fn check(&self) -> Result<(), SegmentExpressionError> {
+        match self {
+            SegmentExpression::And(expressions) if expressions.is_empty() => {
+                Err(SegmentExpressionError::EmptyAnd)
+            }
+            SegmentExpression::Or(expressions) if expressions.is_empty() => {
+                Err(SegmentExpressionError::EmptyOr)
+            }
+            SegmentExpression::And(expressions) | SegmentExpression::Or(expressions) => {
+                expressions.iter().try_for_each(Self::check)
+            }
+            SegmentExpression::Not(expression) => expression.check(),
+            SegmentExpression::Comparison(..) | SegmentExpression::Function(..) => Ok(()),
+        }
+    }
+
+    /// Renders a list of expressions separated by a logical operator
+    ///
+    /// # Parameters
+    ///
+    /// * `f`: The formatter
+    /// * `expressions`: The expressions to join
+    /// * `separator`: The logical operator
+    fn fmt_joined(
+        f: &mut Formatter<'_>,
+        expressions: &[SegmentExpression],
+        separator: &str,
+    ) -> fmt::Result {
+        for (i, expression) in expressions.iter().enumerate() {
+            if i > 0 {
+                write!(f, " {} ", separator)?;
+            }
+
+            match expression {
+                SegmentExpression::Comparison(..) => write!(f, "{}", expression)?,
+                _ => write!(f, "({})", expression)?,
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Not for SegmentExpression {
+    type Output = SegmentExpression;
+
+    fn not(self) -> Self::Output {
+        SegmentExpression::Not(Box::new(self))
+    }
+}
+
+impl Display for SegmentExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SegmentExpression::Comparison(property, operator, value) => {
+                write!(f, "({}{}{})", property, operator, value)
+            }
+            SegmentExpression::Function(name, args) => {
+                write!(
+                    f,
+                    "{}",
+                    ExpressionValue::Function(name.clone(), args.clone())
+                )
+            }
+            SegmentExpression::And(expressions) => Self::fmt_joined(f, expressions, "AND"),
+            SegmentExpression::Or(expressions) => Self::fmt_joined(f, expressions, "OR"),
+            SegmentExpression::Not(expression) => match expression.as_ref() {
+                SegmentExpression::And(_) | SegmentExpression::Or(_) => {
+                    write!(f, "NOT ({})", expression)
+                }
+                _ => write!(f, "NOT {}", expression),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn render_comparisons() {
+        assert_eq!(
+            SegmentExpression::eq("gender", "female").to_string(),
+            "(gender=\"female\")"
+        );
+        assert_eq!(
+            SegmentExpression::ne("vip", true).to_string(),
+            "(vip!=true)"
+        );
+        assert_eq!(
+            SegmentExpression::le("score", 2.5).to_string(),
+            "(score<=2.5)"
+        );
+        assert_eq!(SegmentExpression::ge("age", 18).to_string(), "(age>=18)");
+        assert_eq!(
+            SegmentExpression::eq("name", "Jane \"JD\" Doe").to_string(),
+            "(name=\"Jane \\\"JD\\\" Doe\")"
+        );
+    }
+
+    #[test]
+    fn render_date_functions() {
+        assert_eq!(
+            SegmentExpression::gt("last_purchase", ExpressionValue::days_ago(7)).to_string(),
+            "(last_purchase>DaysAgo(7))"
+        );
+        assert_eq!(
+            SegmentExpression::is_in_previous_hours("signup", 24).to_string(),
+            "IsInPreviousHours(signup,24)"
+        );
+        assert_eq!(
+            SegmentExpression::is_in_next_days("birthday", 3).to_string(),
+            "IsInNextDays(birthday,3)"
+        );
+    }
+
+    #[test]
+    fn render_logical_operators() {
+        let expression = SegmentExpression::eq("country", "US")
+            .or(SegmentExpression::eq("country", "Canada"))
+            .and(SegmentExpression::lt("age", 35))
+            .and(!SegmentExpression::eq("vip", true));
+
+        assert_eq!(
+            expression.to_string(),
+            "((country=\"US\") OR (country=\"Canada\")) AND (age<35) AND (NOT (vip=true))"
+        );
+
+        let expression = !SegmentExpression::lt("age", 18).or(SegmentExpression::gt("age", 65));
+
+        assert_eq!(expression.to_string(), "NOT ((age<18) OR (age>65))");
+    }
+
+    #[test]
+    fn render_empty_lists() {
+        let empty_or = SegmentExpression::lt("age", 35).and(SegmentExpression::Or(Vec::new()));
+
+        assert_eq!(
+            SegmentExpression::And(Vec::new()).render(),
+            Err(SegmentExpressionError::EmptyAnd)
+        );
+        assert_eq!(empty_or.render(), Err(SegmentExpressionError::EmptyOr));
+        assert_eq!(
+            (!SegmentExpression::And(Vec::new())).render(),
+            Err(SegmentExpressionError::EmptyAnd)
+        );
+        assert_eq!(
+            SegmentExpression::And(Vec::new())
+                .and(SegmentExpression::lt("age", 35))
+                .render(),
+            Ok("(age<35)".to_string())
+        );
+    }
+}
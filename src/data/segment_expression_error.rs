@@ -0,0 +1,39 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+/// The reasons why a segmentation formula can't be rendered
+#[derive(Debug, PartialEq)]
+pub enum SegmentExpressionError {
+    /// An `And` expression has no operand
+    EmptyAnd,
+    /// An `Or` expression has no operand
+    EmptyOr,
+}
+
+impl Display for SegmentExpressionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SegmentExpressionError::EmptyAnd => write!(f, "AND expression without operand"),
+            SegmentExpressionError::EmptyOr => write!(f, "OR expression without operand"),
+        }
+    }
+}
+
+impl StdError for SegmentExpressionError {}
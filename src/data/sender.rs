@@ -0,0 +1,70 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{EmailType, SenderStatus};
+use serde::{Deserialize, Serialize};
+
+/// A sender address information
+#[derive(Serialize, Deserialize, Default)]
+pub struct Sender {
+    /// Timestamp of when the sender was created
+    #[serde(rename = "CreatedAt")]
+    #[serde(default)]
+    pub created_at: String,
+
+    /// Unique numeric ID of the sender's domain
+    #[serde(rename = "DNSID")]
+    #[serde(default)]
+    pub dns_id: i128,
+
+    /// The sender email address
+    ///
+    /// A value like `*@domain.com` means every address of the domain can be used
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// The type of emails sent with this sender
+    #[serde(rename = "EmailType")]
+    #[serde(default)]
+    pub email_type: EmailType,
+
+    /// Name of the file used for domain validation
+    #[serde(rename = "Filename")]
+    #[serde(default)]
+    pub filename: String,
+
+    /// Unique numeric ID of the sender
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// Indicates whether this sender is the default one for its domain or not
+    #[serde(rename = "IsDefaultSender")]
+    #[serde(default)]
+    pub is_default_sender: bool,
+
+    /// User-specified name of the sender
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    pub name: String,
+
+    /// The sender's status
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: SenderStatus,
+}
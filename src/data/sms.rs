@@ -0,0 +1,70 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{SmsCost, SmsStatus};
+use serde::{Deserialize, Serialize};
+
+/// An SMS information
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct Sms {
+    /// Unique ID of the SMS
+    #[serde(rename = "ID")]
+    #[serde(alias = "MessageId")]
+    #[serde(default)]
+    pub id: String,
+
+    /// The sender name, as displayed on the recipient's phone
+    #[serde(rename = "From")]
+    #[serde(default)]
+    pub from: String,
+
+    /// The recipient's phone number, in international format
+    #[serde(rename = "To")]
+    #[serde(default)]
+    pub to: String,
+
+    /// The SMS content, only returned at sending
+    #[serde(rename = "Text")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "String::is_empty")]
+    pub text: String,
+
+    /// The SMS status
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: SmsStatus,
+
+    /// The SMS cost
+    #[serde(rename = "Cost")]
+    #[serde(default)]
+    pub cost: SmsCost,
+
+    /// Number of SMS parts needed to send the text
+    #[serde(rename = "SmsCount")]
+    #[serde(default)]
+    pub sms_count: i64,
+
+    /// Unix timestamp of when the SMS was created
+    #[serde(rename = "CreationTS")]
+    #[serde(default)]
+    pub creation_ts: i64,
+
+    /// Unix timestamp of when the SMS was sent
+    #[serde(rename = "SentTS")]
+    #[serde(default)]
+    pub sent_ts: i64,
+}
@@ -0,0 +1,65 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::SmsStatus;
+use crate::polling::PollableJob;
+use serde::{Deserialize, Serialize};
+
+/// An SMS export job information
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct SmsExport {
+    /// Unique ID of the export job
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: String,
+
+    /// Unix timestamp of when the job was created
+    #[serde(rename = "CreationTS")]
+    #[serde(default)]
+    pub creation_ts: i64,
+
+    /// Unix timestamp of when the export file will be deleted
+    #[serde(rename = "ExpirationTS")]
+    #[serde(default)]
+    pub expiration_ts: i64,
+
+    /// The job status, named `PENDING`, `COMPLETED` or `ERROR`
+    #[serde(rename = "Status")]
+    #[serde(default)]
+    pub status: SmsStatus,
+
+    /// Only SMS sent after this Unix timestamp are exported
+    #[serde(rename = "FromTS")]
+    #[serde(default)]
+    pub from_ts: i64,
+
+    /// Only SMS sent before this Unix timestamp are exported
+    #[serde(rename = "ToTS")]
+    #[serde(default)]
+    pub to_ts: i64,
+
+    /// The URL of the CSV file, once the job is completed
+    #[serde(rename = "URL")]
+    #[serde(default)]
+    pub url: String,
+}
+
+impl PollableJob for SmsExport {
+    fn is_finished(&self) -> bool {
+        matches!(self.status.name.as_str(), "COMPLETED" | "ERROR")
+    }
+}
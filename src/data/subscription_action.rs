@@ -0,0 +1,36 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The action to apply to contacts in a contact list
+#[derive(Serialize, Deserialize, Default, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum SubscriptionAction {
+    /// Adds the contacts, and subscribes them again if they had unsubscribed
+    #[serde(rename = "addforce")]
+    AddForce,
+    /// Adds the contacts, unsubscribed ones stay unsubscribed
+    #[serde(rename = "addnoforce")]
+    #[default]
+    AddNoForce,
+    /// Removes the contacts from the list
+    #[serde(rename = "remove")]
+    Remove,
+    /// Unsubscribes the contacts from the list
+    #[serde(rename = "unsub")]
+    Unsubscribe,
+}
@@ -0,0 +1,77 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The account's user information
+#[derive(Serialize, Deserialize, Default)]
+pub struct User {
+    /// The access rights of the user
+    #[serde(rename = "ACL")]
+    #[serde(default)]
+    pub acl: String,
+
+    /// Timestamp of the user's creation
+    #[serde(rename = "CreatedAt")]
+    #[serde(default)]
+    pub created_at: String,
+
+    /// The user's email address
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// Unique numeric ID of the user
+    #[serde(rename = "ID")]
+    #[serde(default)]
+    pub id: i128,
+
+    /// The IP address of the last login
+    #[serde(rename = "LastIp")]
+    #[serde(default)]
+    pub last_ip: String,
+
+    /// Timestamp of the last login
+    #[serde(rename = "LastLoginAt")]
+    #[serde(default)]
+    pub last_login_at: String,
+
+    /// The user's locale, like `en_US`
+    #[serde(rename = "Locale")]
+    #[serde(default)]
+    pub locale: String,
+
+    /// The number of sub-account API keys the user can create
+    #[serde(rename = "MaxAllowedAPIKeys")]
+    #[serde(default)]
+    pub max_allowed_api_keys: i64,
+
+    /// The user's timezone
+    #[serde(rename = "Timezone")]
+    #[serde(default)]
+    pub timezone: String,
+
+    /// The user's name
+    #[serde(rename = "Username")]
+    #[serde(default)]
+    pub username: String,
+
+    /// Timestamp of the last rate limit warning
+    #[serde(rename = "WarnedRatelimitAt")]
+    #[serde(default)]
+    pub warned_ratelimit_at: String,
+}
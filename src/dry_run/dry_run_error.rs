@@ -0,0 +1,57 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::transport::RequestType;
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+/// The reasons why a client in dry run mode refuses a request
+#[derive(Debug)]
+pub enum DryRunError {
+    /// The request would reach the network in [`DryRunMode::NoNetwork`](super::DryRunMode)
+    NoNetwork(RequestType, String),
+    /// The request would deliver to real users in [`DryRunMode::Sandbox`](super::DryRunMode)
+    NotSandboxable(RequestType, String),
+    /// The request would modify the account in [`DryRunMode::Sandbox`](super::DryRunMode)
+    ReadOnly(RequestType, String),
+}
+
+impl Display for DryRunError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DryRunError::NoNetwork(method, url) => {
+                write!(f, "Dry run without network, {} {} not sent", method, url)
+            }
+            DryRunError::NotSandboxable(method, url) => {
+                write!(
+                    f,
+                    "Dry run in sandbox, {} {} can't be sandboxed",
+                    method, url
+                )
+            }
+            DryRunError::ReadOnly(method, url) => {
+                write!(
+                    f,
+                    "Dry run in sandbox, {} {} would modify the account",
+                    method, url
+                )
+            }
+        }
+    }
+}
+
+impl StdError for DryRunError {}
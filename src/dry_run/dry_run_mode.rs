@@ -0,0 +1,36 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// How a client protects real users from the requests it sends
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum DryRunMode {
+    /// Requests are sent as they are
+    #[default]
+    Disabled,
+
+    /// Emails are sent via Send API v3.1 with `SandboxMode` forced, nothing is delivered
+    ///
+    /// Sends that can't be sandboxed, via Send API v3 or SMS, are refused. So are the other
+    /// requests modifying the account, only reads are sent.
+    Sandbox,
+
+    /// Nothing is sent
+    ///
+    /// Send API v3.1 requests are validated and answered with a synthetic response, every other
+    /// request is refused.
+    NoNetwork,
+}
@@ -0,0 +1,36 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The reasons why a request is refused in dry run mode
+mod dry_run_error;
+/// The client-wide dry run setting
+mod dry_run_mode;
+/// The recipient policies
+mod recipient_policy;
+/// The reasons why a recipient policy refuses a message
+mod recipient_policy_error;
+/// The detection of requests refused by the client itself
+mod refusal;
+/// The local validation of messages
+mod validation;
+
+pub use dry_run_error::DryRunError;
+pub use dry_run_mode::DryRunMode;
+pub use recipient_policy::RecipientPolicy;
+pub use recipient_policy_error::RecipientPolicyError;
+pub(crate) use refusal::is_refusal;
+pub(crate) use validation::validate_message;
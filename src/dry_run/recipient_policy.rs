@@ -0,0 +1,349 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::RecipientPolicyError;
+use crate::data::{EmailAddress, Message, MessageV3, Recipient};
+use regex_lite::Regex;
+
+/// Which recipients a client may email
+///
+/// Set it with [`Mailjet::with_recipient_policy`](crate::Mailjet::with_recipient_policy), messages
+/// are then checked or rewritten before anything is posted.
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::{data::EmailAddress, dry_run::RecipientPolicy, Mailjet};
+///
+/// // Only the team can receive emails
+/// let allowlist = RecipientPolicy::allowlist()
+///     .allow_domain("example.com")
+///     .allow_pattern(r"^qa\+.*@gmail\.com$")
+///     .unwrap();
+///
+/// // Everything goes to one inbox
+/// let redirect = RecipientPolicy::redirect_to(EmailAddress::from_email("staging@example.com"));
+///
+/// let mailjet = Mailjet::from_api_keys("your_api_key", "your_api_secret").with_recipient_policy(allowlist);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub enum RecipientPolicy {
+    /// Every recipient is allowed
+    #[default]
+    AllowAll,
+
+    /// Only recipients on one of the domains, or matching one of the patterns, are allowed
+    AllowOnly {
+        /// The allowed domains, subdomains included
+        domains: Vec<String>,
+
+        /// The regular expressions matching allowed email addresses
+        patterns: Vec<Regex>,
+    },
+
+    /// Every recipient is replaced by one inbox, the original ones are kept in
+    /// [`RecipientPolicy::ORIGINAL_TO_HEADER`], [`RecipientPolicy::ORIGINAL_CC_HEADER`] and
+    /// [`RecipientPolicy::ORIGINAL_BCC_HEADER`] headers
+    RedirectTo(EmailAddress),
+}
+
+impl RecipientPolicy {
+    /// The header keeping the original `To` recipients of redirected messages
+    pub const ORIGINAL_TO_HEADER: &'static str = "X-Original-To";
+
+    /// The header keeping the original `Cc` recipients of redirected messages
+    pub const ORIGINAL_CC_HEADER: &'static str = "X-Original-Cc";
+
+    /// The header keeping the original `Bcc` recipients of redirected messages
+    pub const ORIGINAL_BCC_HEADER: &'static str = "X-Original-Bcc";
+
+    /// Creates an allowlist allowing nothing yet
+    pub fn allowlist() -> Self {
+        Self::AllowOnly {
+            domains: Vec::new(),
+            patterns: Vec::new(),
+        }
+    }
+
+    /// Creates a policy redirecting every message to one inbox
+    ///
+    /// # Parameters
+    ///
+    /// * `inbox`: The catch-all inbox
+    pub fn redirect_to(inbox: EmailAddress) -> Self {
+        Self::RedirectTo(inbox)
+    }
+
+    /// Allows a domain and its subdomains, no effect if the policy isn't an allowlist
+    ///
+    /// # Parameters
+    ///
+    /// * `domain`: The domain, like `example.com`
+    pub fn allow_domain(mut self, domain: &str) -> Self {
+        if let Self::AllowOnly { domains, .. } = &mut self {
+            domains.push(domain.trim_start_matches('@').to_lowercase());
+        }
+
+        self
+    }
+
+    /// Allows the email addresses matching a regular expression, no effect if the policy isn't
+    /// an allowlist
+    ///
+    /// # Parameters
+    ///
+    /// * `pattern`: The regular expression, like `^qa\+.*@gmail\.com$`
+    pub fn allow_pattern(mut self, pattern: &str) -> Result<Self, RecipientPolicyError> {
+        let regex =
+            Regex::new(pattern).map_err(|e| RecipientPolicyError::InvalidPattern(e.to_string()))?;
+
+        if let Self::AllowOnly { patterns, .. } = &mut self {
+            patterns.push(regex);
+        }
+
+        Ok(self)
+    }
+
+    /// Checks whether an email address may receive messages as it is
+    ///
+    /// Redirected policies allow every address, as none of them is emailed
+    ///
+    /// # Parameters
+    ///
+    /// * `email`: The email address
+    pub fn is_allowed(&self, email: &str) -> bool {
+        match self {
+            Self::AllowAll | Self::RedirectTo(_) => true,
+            Self::AllowOnly { domains, patterns } => {
+                let domain = email
+                    .rsplit_once('@')
+                    .map(|(_, domain)| domain.to_lowercase())
+                    .unwrap_or_default();
+
+                domains
+                    .iter()
+                    .any(|d| domain == *d || domain.ends_with(&format!(".{}", d)))
+                    || patterns.iter().any(|p| p.is_match(email))
+            }
+        }
+    }
+
+    /// Checks a list of email addresses
+    ///
+    /// # Parameters
+    ///
+    /// * `emails`: The email addresses
+    fn check<'a, I: IntoIterator<Item = &'a str>>(
+        &self,
+        emails: I,
+    ) -> Result<(), RecipientPolicyError> {
+        let refused: Vec<String> = emails
+            .into_iter()
+            .filter(|email| !self.is_allowed(email))
+            .map(|email| email.to_string())
+            .collect();
+
+        match refused.is_empty() {
+            true => Ok(()),
+            false => Err(RecipientPolicyError::NotAllowed(refused)),
+        }
+    }
+
+    /// Checks or rewrites the recipients of a Send API v3.1 message
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message
+    pub fn apply(&self, message: &mut Message) -> Result<(), RecipientPolicyError> {
+        match self {
+            Self::AllowAll => Ok(()),
+            Self::AllowOnly { .. } => self.check(
+                message
+                    .to
+                    .iter()
+                    .chain(&message.cc)
+                    .chain(&message.bcc)
+                    .map(|address| address.email.as_str()),
+            ),
+            Self::RedirectTo(inbox) => {
+                for (header, addresses) in [
+                    (Self::ORIGINAL_TO_HEADER, &message.to),
+                    (Self::ORIGINAL_CC_HEADER, &message.cc),
+                    (Self::ORIGINAL_BCC_HEADER, &message.bcc),
+                ] {
+                    if !addresses.is_empty() {
+                        let mailboxes: Vec<String> =
+                            addresses.iter().map(EmailAddress::to_mailbox).collect();
+
+                        message
+                            .headers
+                            .insert(header.to_string(), mailboxes.join(", "));
+                    }
+                }
+
+                message.to = vec![inbox.clone()];
+                message.cc.clear();
+                message.bcc.clear();
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Checks or rewrites the recipients of a Send API v3 message
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message
+    pub fn apply_v3(&self, message: &mut MessageV3) -> Result<(), RecipientPolicyError> {
+        let mailboxes = |list: &str| -> Vec<EmailAddress> {
+            list.split(',')
+                .filter(|mailbox| !mailbox.trim().is_empty())
+                .map(EmailAddress::from_mailbox)
+                .collect()
+        };
+
+        match self {
+            Self::AllowAll => Ok(()),
+            Self::AllowOnly { .. } => {
+                let listed: Vec<EmailAddress> = [&message.to, &message.cc, &message.bcc]
+                    .into_iter()
+                    .flat_map(|list| mailboxes(list))
+                    .collect();
+
+                self.check(
+                    message
+                        .recipients
+                        .iter()
+                        .map(|recipient| recipient.email.as_str())
+                        .chain(listed.iter().map(|address| address.email.as_str())),
+                )
+            }
+            Self::RedirectTo(inbox) => {
+                let mut to: Vec<String> = message
+                    .recipients
+                    .iter()
+                    .map(|recipient| recipient.email.clone())
+                    .collect();
+
+                to.extend(mailboxes(&message.to).iter().map(EmailAddress::to_mailbox));
+
+                for (header, list) in [
+                    (Self::ORIGINAL_TO_HEADER, to),
+                    (Self::ORIGINAL_CC_HEADER, vec![message.cc.clone()]),
+                    (Self::ORIGINAL_BCC_HEADER, vec![message.bcc.clone()]),
+                ] {
+                    let list: Vec<String> = list.into_iter().filter(|l| !l.is_empty()).collect();
+
+                    if !list.is_empty() {
+                        message.headers.insert(header.to_string(), list.join(", "));
+                    }
+                }
+
+                message.recipients = vec![Recipient::from(inbox)];
+                message.to.clear();
+                message.cc.clear();
+                message.bcc.clear();
+
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::RecipientPolicy;
+    use crate::data::{EmailAddress, Message, MessageV3};
+
+    #[test]
+    fn allowlist() {
+        let policy = RecipientPolicy::allowlist()
+            .allow_domain("example.com")
+            .allow_pattern(r"^qa\+.*@gmail\.com$")
+            .unwrap();
+
+        assert!(policy.is_allowed("john@example.com"));
+        assert!(policy.is_allowed("john@mail.EXAMPLE.com"));
+        assert!(policy.is_allowed("qa+1@gmail.com"));
+        assert!(!policy.is_allowed("john@notexample.com"));
+        assert!(!policy.is_allowed("john@gmail.com"));
+
+        let mut message = Message {
+            to: vec![EmailAddress::from_email("john@example.com")],
+            bcc: vec![EmailAddress::from_email("boss@gmail.com")],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            policy.apply(&mut message).unwrap_err().to_string(),
+            "Recipients not allowed: boss@gmail.com"
+        );
+        assert!(RecipientPolicy::allowlist().allow_pattern("(").is_err());
+    }
+
+    #[test]
+    fn redirect() {
+        let policy = RecipientPolicy::redirect_to(EmailAddress::from_email("staging@example.com"));
+        let mut message = Message {
+            to: vec![
+                EmailAddress::from_email_and_name("passenger1@mailjet.com", "Passenger 1"),
+                EmailAddress::from_email("passenger2@mailjet.com"),
+            ],
+            cc: vec![EmailAddress::from_email("copilot@mailjet.com")],
+            ..Default::default()
+        };
+
+        policy.apply(&mut message).unwrap();
+
+        assert_eq!(
+            message.to,
+            vec![EmailAddress::from_email("staging@example.com")]
+        );
+        assert!(message.cc.is_empty());
+        assert_eq!(
+            message.headers[RecipientPolicy::ORIGINAL_TO_HEADER],
+            "\"Passenger 1\" <passenger1@mailjet.com>, passenger2@mailjet.com"
+        );
+        assert_eq!(
+            message.headers[RecipientPolicy::ORIGINAL_CC_HEADER],
+            "copilot@mailjet.com"
+        );
+        assert!(!message
+            .headers
+            .contains_key(RecipientPolicy::ORIGINAL_BCC_HEADER));
+    }
+
+    #[test]
+    fn redirect_v3() {
+        let policy = RecipientPolicy::redirect_to(EmailAddress::from_email("staging@example.com"));
+        let mut message = MessageV3 {
+            to: "passenger1@mailjet.com".to_string(),
+            bcc: "boss@gmail.com".to_string(),
+            ..Default::default()
+        };
+
+        policy.apply_v3(&mut message).unwrap();
+
+        assert_eq!(message.recipients[0].email, "staging@example.com");
+        assert!(message.to.is_empty() && message.bcc.is_empty());
+        assert_eq!(
+            message.headers[RecipientPolicy::ORIGINAL_BCC_HEADER],
+            "boss@gmail.com"
+        );
+    }
+}
@@ -0,0 +1,29 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{DryRunError, RecipientPolicyError};
+use std::error::Error as StdError;
+
+/// Returns `true` if the error is a request refused by the client itself, which fails again
+/// when retried, `false` if it comes from the network or from Mailjet
+///
+/// # Parameters
+///
+/// * `error`: The error returned by the client
+pub(crate) fn is_refusal(error: &(dyn StdError + 'static)) -> bool {
+    error.is::<RecipientPolicyError>() || error.is::<DryRunError>()
+}
@@ -0,0 +1,111 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::ResponseError;
+use serde_json::Value;
+
+/// Checks a Send API v3.1 message the way Mailjet does before sending it
+///
+/// Returns the errors Mailjet would answer, empty if the message is valid
+///
+/// # Parameters
+///
+/// * `message`: The serialized message
+/// * `globals`: The serialized global properties, `Value::Null` if there are none
+pub(crate) fn validate_message(message: &Value, globals: &Value) -> Vec<ResponseError> {
+    let property = |name: &str| match &message[name] {
+        Value::Null => &globals[name],
+        value => value,
+    };
+    let mut errors = Vec::new();
+
+    if property("From")["Email"]
+        .as_str()
+        .unwrap_or_default()
+        .is_empty()
+    {
+        errors.push(error(
+            "mj-0013",
+            "\"From\" is a mandatory property.",
+            &["From"],
+        ));
+    }
+
+    if !["To", "Cc", "Bcc"]
+        .iter()
+        .any(|name| property(name).as_array().is_some_and(|a| !a.is_empty()))
+    {
+        errors.push(error(
+            "mj-0003",
+            "At least one of \"To\", \"Cc\" or \"Bcc\" must be provided.",
+            &["To", "Cc", "Bcc"],
+        ));
+    }
+
+    if ["HTMLPart", "TextPart", "TemplateID"]
+        .iter()
+        .all(|name| property(name).is_null())
+    {
+        errors.push(error(
+            "send-0003",
+            "At least \"HTMLPart\", \"TextPart\" or \"TemplateID\" must be provided.",
+            &["HTMLPart", "TextPart"],
+        ));
+    }
+
+    errors
+}
+
+/// Creates a validation error
+///
+/// # Parameters
+///
+/// * `code`: The error code
+/// * `message`: The error message
+/// * `related_to`: The properties causing the error
+fn error(code: &str, message: &str, related_to: &[&str]) -> ResponseError {
+    ResponseError {
+        error_identifier: "00000000-0000-0000-0000-000000000000".to_string(),
+        error_code: code.to_string(),
+        status_code: 400,
+        error_message: message.to_string(),
+        error_related_to: related_to.iter().map(|r| r.to_string()).collect(),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::validate_message;
+    use serde_json::json;
+
+    #[test]
+    fn missing_properties() {
+        let errors = validate_message(&json!({ "Subject": "Hello" }), &json!(null));
+        let codes: Vec<&str> = errors.iter().map(|e| e.error_code.as_str()).collect();
+
+        assert_eq!(codes, vec!["mj-0013", "mj-0003", "send-0003"]);
+    }
+
+    #[test]
+    fn properties_from_globals() {
+        let message = json!({ "To": [{ "Email": "passenger1@mailjet.com" }] });
+        let globals = json!({ "From": { "Email": "pilot@mailjet.com" }, "TextPart": "Hello" });
+
+        assert!(validate_message(&message, &globals).is_empty());
+        assert_eq!(validate_message(&message, &json!(null)).len(), 2);
+    }
+}
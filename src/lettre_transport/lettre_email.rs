@@ -0,0 +1,94 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::{EmailAddress, EmlError, Message};
+use lettre::address::Envelope;
+
+/// Reads an email formatted by `lettre` into a message
+///
+/// `lettre` removes the `Bcc` header once the envelope is built, so the envelope's recipients
+/// missing from the `To`, `Cc` and `Bcc` headers are added as `Bcc` recipients
+///
+/// # Parameters
+///
+/// * `envelope`: The email's envelope
+/// * `email`: The formatted email
+pub(crate) fn read_lettre_email(envelope: &Envelope, email: &[u8]) -> Result<Message, EmlError> {
+    let mut message = Message::from_eml(email)?;
+
+    if message.from.email.is_empty() {
+        if let Some(from) = envelope.from() {
+            message.from = EmailAddress::from_email(from.as_ref());
+        }
+    }
+
+    for recipient in envelope.to() {
+        let known = message
+            .to
+            .iter()
+            .chain(&message.cc)
+            .chain(&message.bcc)
+            .any(|a| a.email.eq_ignore_ascii_case(recipient.as_ref()));
+
+        if !known {
+            message
+                .bcc
+                .push(EmailAddress::from_email(recipient.as_ref()));
+        }
+    }
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod test {
+    use super::read_lettre_email;
+    use lettre::message::{header::ContentType, Attachment, MultiPart};
+
+    #[test]
+    fn lettre_email() {
+        let email = lettre::Message::builder()
+            .from("Mailjet Pilot <pilot@mailjet.com>".parse().unwrap())
+            .to("passenger1@mailjet.com".parse().unwrap())
+            .bcc("passenger2@mailjet.com".parse().unwrap())
+            .subject("Your email flight plan!")
+            .multipart(
+                MultiPart::mixed()
+                    .multipart(MultiPart::alternative_plain_html(
+                        "Welcome aboard".to_string(),
+                        "<h3>Welcome aboard</h3>".to_string(),
+                    ))
+                    .singlepart(
+                        Attachment::new("plan.txt".to_string())
+                            .body("Seat 1A".to_string(), ContentType::TEXT_PLAIN),
+                    ),
+            )
+            .unwrap();
+
+        let message = read_lettre_email(email.envelope(), &email.formatted()).unwrap();
+
+        assert_eq!(message.from.email, "pilot@mailjet.com");
+        assert_eq!(message.from.name, "Mailjet Pilot");
+        assert_eq!(message.to[0].email, "passenger1@mailjet.com");
+        assert_eq!(message.bcc[0].email, "passenger2@mailjet.com");
+        assert_eq!(message.subject, "Your email flight plan!");
+        assert_eq!(message.text_part, "Welcome aboard");
+        assert_eq!(message.html_part, "<h3>Welcome aboard</h3>");
+        assert_eq!(message.attachments[0].filename, "plan.txt");
+        assert_eq!(message.attachments[0].base64_content, "U2VhdCAxQQ==");
+    }
+}
@@ -0,0 +1,221 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{read_lettre_email, MailjetTransportError};
+use crate::data::ResponseMessage;
+use crate::requests::SendRequest;
+use crate::Mailjet;
+use async_trait::async_trait;
+use lettre::address::Envelope;
+use lettre::{AsyncTransport, Transport};
+use std::sync::Arc;
+
+/// Sends `lettre` emails with the Send API v3.1, in place of `lettre`'s SMTP transports
+///
+/// Each email is converted to a [`Message`](crate::data::Message) with its parts, attachments
+/// and custom headers. The envelope's recipients missing from the headers, like the `Bcc`
+/// recipients, are sent as `Bcc` recipients.
+///
+/// The client's dry run mode and recipient policy apply to every email.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use lettre::{Message, Transport};
+/// use mailjet_api_wrapper::{lettre_transport::MailjetTransport, Mailjet};
+///
+/// let email = Message::builder()
+///     .from("pilot@mailjet.com".parse().unwrap())
+///     .to("passenger1@mailjet.com".parse().unwrap())
+///     .subject("Your email flight plan!")
+///     .body("Welcome aboard".to_string())
+///     .unwrap();
+///
+/// let mailer = MailjetTransport::new(Mailjet::from_api_keys("your_api_key", "your_api_secret"));
+/// let response = mailer.send(&email).unwrap();
+/// ```
+#[derive(Clone)]
+pub struct MailjetTransport {
+    /// The client sending the emails
+    mailjet: Arc<Mailjet>,
+}
+
+impl MailjetTransport {
+    /// Creates a transport sending with a client
+    ///
+    /// # Parameters
+    ///
+    /// * `mailjet`: The client
+    pub fn new(mailjet: Mailjet) -> Self {
+        Self {
+            mailjet: Arc::new(mailjet),
+        }
+    }
+
+    /// Converts a formatted email and sends it with the Send API v3.1
+    ///
+    /// # Parameters
+    ///
+    /// * `envelope`: The email's envelope
+    /// * `email`: The formatted email
+    fn send_email(
+        &self,
+        envelope: &Envelope,
+        email: &[u8],
+    ) -> Result<ResponseMessage, MailjetTransportError> {
+        let request = SendRequest {
+            messages: vec![read_lettre_email(envelope, email)?],
+            ..Default::default()
+        };
+        let response = self
+            .mailjet
+            .send(&request)
+            .map_err(|e| MailjetTransportError::Request(e.to_string()))?;
+        let message = response
+            .object
+            .and_then(|object| object.messages.into_iter().next());
+
+        match message {
+            Some(message) if message.status == "success" => Ok(message),
+            Some(message) => Err(MailjetTransportError::Rejected(message.errors)),
+            None => Err(MailjetTransportError::UnexpectedResponse(
+                response.http_code,
+                response.raw_data,
+            )),
+        }
+    }
+}
+
+impl From<Mailjet> for MailjetTransport {
+    fn from(value: Mailjet) -> Self {
+        Self::new(value)
+    }
+}
+
+impl Transport for MailjetTransport {
+    type Ok = ResponseMessage;
+    type Error = MailjetTransportError;
+
+    fn send_raw(&self, envelope: &Envelope, email: &[u8]) -> Result<Self::Ok, Self::Error> {
+        self.send_email(envelope, email)
+    }
+}
+
+#[async_trait]
+impl AsyncTransport for MailjetTransport {
+    type Ok = ResponseMessage;
+    type Error = MailjetTransportError;
+
+    /// Sends the email on Tokio's blocking thread pool, the client's requests being blocking
+    ///
+    /// Must be awaited within a Tokio runtime, [`MailjetTransportError::NoRuntime`] is returned
+    /// otherwise
+    async fn send_raw(&self, envelope: &Envelope, email: &[u8]) -> Result<Self::Ok, Self::Error> {
+        let runtime =
+            tokio::runtime::Handle::try_current().map_err(|_| MailjetTransportError::NoRuntime)?;
+        let transport = self.clone();
+        let envelope = envelope.clone();
+        let email = email.to_vec();
+
+        runtime
+            .spawn_blocking(move || transport.send_email(&envelope, &email))
+            .await
+            .map_err(|e| MailjetTransportError::Request(e.to_string()))?
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MailjetTransport;
+    use crate::lettre_transport::MailjetTransportError;
+    use crate::testing::MockServer;
+    use lettre::{AsyncTransport, Message, Transport};
+    use std::future::Future;
+    use std::pin::pin;
+    use std::task::{Context, Poll, Waker};
+
+    /// Creates an email with a hidden copy
+    fn email(from: &str) -> Message {
+        Message::builder()
+            .from(from.parse().unwrap())
+            .to("passenger1@mailjet.com".parse().unwrap())
+            .bcc("passenger2@mailjet.com".parse().unwrap())
+            .subject("Your email flight plan!")
+            .body("Welcome aboard".to_string())
+            .unwrap()
+    }
+
+    #[test]
+    fn send() {
+        let server = MockServer::start().unwrap();
+        let mailer = MailjetTransport::new(server.client());
+
+        let response = Transport::send(&mailer, &email("pilot@mailjet.com")).unwrap();
+        let request = server.requests()[0].json();
+
+        assert_eq!(response.status, "success");
+        assert_eq!(request["Messages"][0]["Subject"], "Your email flight plan!");
+        assert_eq!(request["Messages"][0]["TextPart"], "Welcome aboard");
+        assert_eq!(
+            request["Messages"][0]["Bcc"][0]["Email"],
+            "passenger2@mailjet.com"
+        );
+    }
+
+    #[test]
+    fn rejected() {
+        let server = MockServer::start().unwrap();
+        let mailer = MailjetTransport::new(server.client());
+
+        server.fail_next(401);
+
+        let error = Transport::send(&mailer, &email("pilot@mailjet.com")).unwrap_err();
+
+        assert!(matches!(
+            error,
+            MailjetTransportError::UnexpectedResponse(Some(401), _)
+        ));
+    }
+
+    #[tokio::test]
+    async fn send_async() {
+        let server = MockServer::start().unwrap();
+        let mailer = MailjetTransport::new(server.client());
+
+        let response = AsyncTransport::send(&mailer, email("pilot@mailjet.com"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, "success");
+    }
+
+    #[test]
+    fn send_async_without_runtime() {
+        let server = MockServer::start().unwrap();
+        let mailer = MailjetTransport::new(server.client());
+        let email = email("pilot@mailjet.com");
+        let send = pin!(AsyncTransport::send(&mailer, email));
+
+        let result = send.poll(&mut Context::from_waker(Waker::noop()));
+
+        assert!(matches!(
+            result,
+            Poll::Ready(Err(MailjetTransportError::NoRuntime))
+        ));
+        assert!(server.requests().is_empty());
+    }
+}
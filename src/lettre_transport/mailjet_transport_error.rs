@@ -0,0 +1,61 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::{EmlError, ResponseError};
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+/// The reasons why the `lettre` transport couldn't send an email
+#[derive(Debug)]
+pub enum MailjetTransportError {
+    /// The formatted email can't be read as a message
+    InvalidEmail(EmlError),
+    /// The email was sent asynchronously outside of a Tokio runtime
+    NoRuntime,
+    /// The request couldn't be sent, the transport's error is given
+    Request(String),
+    /// Mailjet refused the message, its errors are given
+    Rejected(Vec<ResponseError>),
+    /// Mailjet's answer has no message status, the HTTP code and the raw response are given
+    UnexpectedResponse(Option<u32>, String),
+}
+
+impl Display for MailjetTransportError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            MailjetTransportError::InvalidEmail(e) => write!(f, "Invalid email: {}", e),
+            MailjetTransportError::NoRuntime => write!(f, "No Tokio runtime to send the email"),
+            MailjetTransportError::Request(e) => write!(f, "Request failed: {}", e),
+            MailjetTransportError::Rejected(errors) => {
+                let messages: Vec<&str> = errors.iter().map(|e| e.error_message.as_str()).collect();
+
+                write!(f, "Message rejected: {}", messages.join(", "))
+            }
+            MailjetTransportError::UnexpectedResponse(code, raw) => {
+                write!(f, "Unexpected response ({:?}): {}", code, raw)
+            }
+        }
+    }
+}
+
+impl StdError for MailjetTransportError {}
+
+impl From<EmlError> for MailjetTransportError {
+    fn from(value: EmlError) -> Self {
+        MailjetTransportError::InvalidEmail(value)
+    }
+}
@@ -22,14 +22,39 @@
 
 /// The data types
 pub mod data;
+/// The dry run modes and recipient policies protecting real users from staging environments
+pub mod dry_run;
+/// The `lettre` transport sending emails with the Send API
+#[cfg(feature = "lettre")]
+pub mod lettre_transport;
 /// The mailjet client
 mod mailjet;
+/// The MIME encoding of messages, to read and write .eml files
+mod mime;
+/// The durable outbox queue, sending messages at least once
+#[cfg(feature = "outbox")]
+pub mod outbox;
+/// The asynchronous job polling tools
+pub mod polling;
 /// The request structures
 pub mod requests;
 /// The response structures
 pub mod responses;
+/// The sending of emails through Mailjet's SMTP relay
+#[cfg(feature = "smtp")]
+pub mod smtp;
+/// The local renderer of Mailjet's template language
+pub mod template;
+/// The mock Mailjet server and the record and replay cassettes for offline tests
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
 /// The traits
 mod traits;
+/// The HTTP transports executing the client's requests
+pub mod transport;
+/// The webhook event and inbound email structures
+pub mod webhooks;
+/// The internal macros
 mod macros;
 
 pub use mailjet::Mailjet;
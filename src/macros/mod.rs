@@ -1 +1,2 @@
+/// Logging macros
 pub mod log;
@@ -15,17 +15,24 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
-use crate::data::{ContactIdentifier, ContactsListIdentifier};
+use crate::data::{
+    ApiKey, BatchJob, ContactIdentifier, ContactsJobStatus, ContactsListIdentifier, DnsIdentifier,
+    GdprAction, GdprAuditRecord, Message, MessageV3, ResponseMessage, ResponseSuccess,
+    SenderIdentifier, Sms, SmsExport,
+};
+use crate::dry_run::{validate_message, DryRunError, DryRunMode, RecipientPolicy};
+use crate::polling::{poll_until_complete, PollError, PollOptions};
 use crate::traits::UrlEncodedRequest;
 use crate::{requests::*, responses::*};
-use curl::{
-    easy::{Easy, List},
-    Error,
-};
-use std::{error::Error as StdError, io::Read};
+use crate::transport::{Credentials, CurlTransport, HttpRequest, RequestType, Transport};
+use serde_json::{Map, Value};
+use std::{error::Error as StdError, sync::Arc};
 use url_builder::URLBuilder;
 use crate::macros::log::info;
 
+/// The base URL of Mailjet's API
+const DEFAULT_BASE_URL: &str = "https://api.mailjet.com";
+
 /// The mailjet client
 pub struct Mailjet {
     /// The API key
@@ -33,21 +40,36 @@ pub struct Mailjet {
 
     /// The secret key
     pub api_secret: String,
-}
 
-/// HTTP Methods
-pub enum RequestType {
-    /// HTTP GET
-    Get,
+    /// The bearer token, used by SMS routes
+    pub bearer_token: Option<String>,
+
+    /// The API's base URL, without trailing slash
+    ///
+    /// Default is `https://api.mailjet.com`
+    pub base_url: String,
+
+    /// How the client protects real users from the requests it sends
+    ///
+    /// Default is [`DryRunMode::Disabled`]
+    pub dry_run_mode: DryRunMode,
+
+    /// Which recipients the client may email
+    ///
+    /// Default is [`RecipientPolicy::AllowAll`]
+    pub recipient_policy: RecipientPolicy,
 
-    /// HTTP POST
-    Post,
+    /// The transport executing HTTP requests
+    transport: Arc<dyn Transport>,
+}
 
-    /// HTTP PUT
-    Put,
+/// Authentication methods
+enum Authentication {
+    /// Basic authentication with API keys
+    Basic,
 
-    /// HTTP DELETE
-    Delete,
+    /// Bearer token authentication
+    Bearer,
 }
 
 impl Mailjet {
@@ -62,9 +84,112 @@ impl Mailjet {
         Self {
             api_key: key.to_string(),
             api_secret: secret.to_string(),
+            bearer_token: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            dry_run_mode: DryRunMode::Disabled,
+            recipient_policy: RecipientPolicy::AllowAll,
+            transport: Arc::new(CurlTransport),
+        }
+    }
+
+    /// Creates a new instance with a bearer token, only SMS routes are usable
+    /// You can get yours at <https://app.mailjet.com/sms>
+    ///
+    /// # Parameters
+    ///
+    /// * `token`: The bearer token
+    pub fn from_bearer_token(token: &str) -> Self {
+        Self {
+            api_key: String::new(),
+            api_secret: String::new(),
+            bearer_token: Some(token.to_string()),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            dry_run_mode: DryRunMode::Disabled,
+            recipient_policy: RecipientPolicy::AllowAll,
+            transport: Arc::new(CurlTransport),
+        }
+    }
+
+    /// Creates a new instance with API keys and a bearer token, all routes are usable
+    ///
+    /// # Parameters
+    ///
+    /// * `key`: The API key
+    /// * `secret`: The secret key
+    /// * `token`: The bearer token
+    pub fn from_api_keys_and_bearer_token(key: &str, secret: &str, token: &str) -> Self {
+        Self {
+            api_key: key.to_string(),
+            api_secret: secret.to_string(),
+            bearer_token: Some(token.to_string()),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            dry_run_mode: DryRunMode::Disabled,
+            recipient_policy: RecipientPolicy::AllowAll,
+            transport: Arc::new(CurlTransport),
         }
     }
 
+    /// Changes the API's base URL, mostly useful to target a mock server during tests
+    ///
+    /// # Parameters
+    ///
+    /// * `base_url`: The new base URL, like `http://127.0.0.1:8080`
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Changes the dry run mode, to make sure a staging environment never emails real users
+    ///
+    /// # Parameters
+    ///
+    /// * `dry_run_mode`: The new dry run mode
+    pub fn with_dry_run_mode(mut self, dry_run_mode: DryRunMode) -> Self {
+        self.dry_run_mode = dry_run_mode;
+        self
+    }
+
+    /// Changes the recipient policy, checking or rewriting recipients before emails are sent
+    ///
+    /// # Parameters
+    ///
+    /// * `recipient_policy`: The new recipient policy
+    pub fn with_recipient_policy(mut self, recipient_policy: RecipientPolicy) -> Self {
+        self.recipient_policy = recipient_policy;
+        self
+    }
+
+    /// Changes the transport executing HTTP requests, curl is used by default
+    ///
+    /// # Parameters
+    ///
+    /// * `transport`: The new transport
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: T) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    /// Creates a URL builder pointing to the API's base URL
+    fn url_builder(&self) -> URLBuilder {
+        let mut ub = URLBuilder::new();
+        let (protocol, host) = self
+            .base_url
+            .split_once("://")
+            .unwrap_or(("https", &self.base_url));
+
+        ub.set_protocol(protocol).set_host(host);
+        ub
+    }
+
+    /// Creates a URL from a path on the API
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: The path, starting with a slash
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
     /// Executes a request to mailjet
     ///
     /// # Parameters
@@ -72,69 +197,45 @@ impl Mailjet {
     /// * `url`: The URL where to request
     /// * `data`: The data to send if any
     /// * `request_type`: The HTTP request type
+    /// * `authentication`: The authentication method
     fn exec(
         &self,
         url: &str,
         data: Option<String>,
         request_type: RequestType,
-    ) -> Result<(String, Result<u32, Error>), Error> {
-        let mut curl = Easy::new();
-        let mut response: Vec<u8> = Vec::new(); // That's where the response will be written on
-
-        // Extract the data if any for lifetime reasons
-        let data_string = data.unwrap_or_default();
-
-        // Convert the data in a byte array
-        let mut raw_data = match data_string.is_empty() {
-            false => data_string.as_bytes(),
-            true => &[],
-        };
-
-        // Create the HTTP request
-        curl.url(url)?;
-        curl.username(self.api_key.as_str())?;
-        curl.password(self.api_secret.as_str())?;
-
-        // Change HTTP request
-        match request_type {
-            RequestType::Post => curl.post(true)?,
-            RequestType::Put => curl.put(true)?,
-            RequestType::Delete => curl.custom_request("DELETE")?,
-            _ => (),
+        authentication: Authentication,
+    ) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        let credentials = match (authentication, &self.bearer_token) {
+            (Authentication::Basic, _) => Credentials::Basic {
+                api_key: self.api_key.clone(),
+                api_secret: self.api_secret.clone(),
+            },
+            (Authentication::Bearer, Some(token)) => Credentials::Bearer(token.clone()),
+            (Authentication::Bearer, None) => Credentials::None,
+        };
+        let request = HttpRequest {
+            method: request_type,
+            url: url.to_string(),
+            body: data.filter(|data| !data.is_empty()),
+            credentials,
+        };
+
+        if self.dry_run_mode == DryRunMode::NoNetwork {
+            return Err(Box::new(DryRunError::NoNetwork(request_type, url.to_string())));
         }
 
-        // Change body type
-        if matches!(request_type, RequestType::Post) || matches!(request_type, RequestType::Put) {
-            let mut header_list = List::new();
-            header_list.append("Content-Type: application/json")?;
-            curl.http_headers(header_list)?;
+        if self.dry_run_mode == DryRunMode::Sandbox
+            && request_type != RequestType::Get
+            && url != self.url("/v3.1/send")
+        {
+            return Err(Box::new(DryRunError::ReadOnly(request_type, url.to_string())));
         }
 
         info!("Sending a request to Mailjet at {}", url);
 
-        {
-            // We need this for lifetime reasons
-            let mut transfer = curl.transfer();
-
-            // How we pass data to mailjet
-            if !raw_data.is_empty() {
-                transfer.read_function(|buffer| Ok(raw_data.read(buffer).unwrap_or_default()))?
-            };
-
-            // How we read mailjet's response
-            transfer.write_function(|buffer| {
-                let _ = &response.extend_from_slice(buffer);
-                Ok(buffer.len())
-            })?;
-
-            // Request execution
-            transfer.perform()?;
-        }
+        let response = self.transport.execute(&request)?;
 
-        Ok((
-            String::from_utf8_lossy(&response).to_string(),
-            curl.response_code(),
-        ))
+        Ok((response.body, response.http_code))
     }
 
     /// Executes an API POST call to a URL
@@ -142,8 +243,8 @@ impl Mailjet {
     /// # Parameters
     ///
     /// * `url`: The URL where to request
-    fn get(&self, url: &str) -> Result<(String, Result<u32, Error>), Error> {
-        self.exec(url, None, RequestType::Get)
+    fn get(&self, url: &str) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        self.exec(url, None, RequestType::Get, Authentication::Basic)
     }
 
     /// Executes an API DELETE call to a URL
@@ -151,8 +252,8 @@ impl Mailjet {
     /// # Parameters
     ///
     /// * `url`: The URL where to request
-    fn delete(&self, url: &str) -> Result<(String, Result<u32, Error>), Error> {
-        self.exec(url, None, RequestType::Delete)
+    fn delete(&self, url: &str) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        self.exec(url, None, RequestType::Delete, Authentication::Basic)
     }
 
     /// Executes an API POST call to a URL
@@ -161,8 +262,8 @@ impl Mailjet {
     ///
     /// * `url`: The URL where to request
     /// * `data`: The data to write in the request's body
-    fn post(&self, url: &str, data: &str) -> Result<(String, Result<u32, Error>), Error> {
-        self.exec(url, Some(data.to_string()), RequestType::Post)
+    fn post(&self, url: &str, data: &str) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        self.exec(url, Some(data.to_string()), RequestType::Post, Authentication::Basic)
     }
 
     /// Executes an API PUT call to a URL
@@ -171,8 +272,27 @@ impl Mailjet {
     ///
     /// * `url`: The URL where to request
     /// * `data`: The data to write in the request's body
-    fn put(&self, url: &str, data: &str) -> Result<(String, Result<u32, Error>), Error> {
-        self.exec(url, Some(data.to_string()), RequestType::Put)
+    fn put(&self, url: &str, data: &str) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        self.exec(url, Some(data.to_string()), RequestType::Put, Authentication::Basic)
+    }
+
+    /// Executes an API GET call to a URL with the bearer token
+    ///
+    /// # Parameters
+    ///
+    /// * `url`: The URL where to request
+    fn get_with_token(&self, url: &str) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        self.exec(url, None, RequestType::Get, Authentication::Bearer)
+    }
+
+    /// Executes an API POST call to a URL with the bearer token
+    ///
+    /// # Parameters
+    ///
+    /// * `url`: The URL where to request
+    /// * `data`: The data to write in the request's body
+    fn post_with_token(&self, url: &str, data: &str) -> Result<(String, Option<u32>), Box<dyn StdError>> {
+        self.exec(url, Some(data.to_string()), RequestType::Post, Authentication::Bearer)
     }
 
     /// Sends emails via Send API v3.1
@@ -181,10 +301,151 @@ impl Mailjet {
     ///
     /// * `request`: The request containing all emails
     pub fn send(&self, request: &SendRequest) -> Result<Response<SendResponse>, Box<dyn StdError>> {
-        let j = serde_json::to_string(request)?;
-        let (response, code) = self.post("https://api.mailjet.com/v3.1/send", &j)?;
+        let mut j = serde_json::to_value(request)?;
+
+        self.apply_recipient_policy(&mut j)?;
+
+        match self.dry_run_mode {
+            DryRunMode::Disabled => (),
+            DryRunMode::Sandbox => j["SandboxMode"] = Value::Bool(true),
+            DryRunMode::NoNetwork => return Ok(Self::dry_run_send(&j)),
+        }
+
+        let (response, code) = self.post(&self.url("/v3.1/send"), &j.to_string())?;
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code,response, object))
+    }
+
+    /// Applies the recipient policy to a serialized Send API v3.1 request
+    ///
+    /// Global recipients are moved into the messages first, so that they are checked or
+    /// rewritten too
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The serialized request
+    fn apply_recipient_policy(&self, request: &mut Value) -> Result<(), Box<dyn StdError>> {
+        if matches!(self.recipient_policy, RecipientPolicy::AllowAll) {
+            return Ok(());
+        }
+
+        let mut globals = Map::new();
+
+        if let Some(g) = request.get_mut("Globals").and_then(Value::as_object_mut) {
+            for kind in ["To", "Cc", "Bcc"] {
+                if let Some(recipients) = g.remove(kind) {
+                    globals.insert(kind.to_string(), recipients);
+                }
+            }
+        }
+
+        if let Some(messages) = request.get_mut("Messages").and_then(Value::as_array_mut) {
+            for message in messages {
+                for (kind, recipients) in &globals {
+                    if message.get(kind).is_none() {
+                        message[kind.as_str()] = recipients.clone();
+                    }
+                }
+
+                let mut m: Message = serde_json::from_value(message.clone())?;
+
+                self.recipient_policy.apply(&mut m)?;
+                *message = serde_json::to_value(m)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Answers a Send API v3.1 request without sending it, as Mailjet would
+    ///
+    /// Messages are validated locally, valid ones get a success status without ids
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The serialized request
+    fn dry_run_send(request: &Value) -> Response<SendResponse> {
+        info!("Dry run without network, not sending {}", request);
+
+        let globals = &request["Globals"];
+        let messages: Vec<ResponseMessage> = request["Messages"]
+            .as_array()
+            .cloned()
+            .unwrap_or_default()
+            .iter()
+            .map(|message| {
+                let errors = validate_message(message, globals);
+
+                if !errors.is_empty() {
+                    return ResponseMessage {
+                        status: "error".to_string(),
+                        errors,
+                        ..Default::default()
+                    };
+                }
+
+                let sent_to = |kind: &str| -> Vec<ResponseSuccess> {
+                    let recipients = match &message[kind] {
+                        Value::Null => &globals[kind],
+                        recipients => recipients,
+                    };
+
+                    recipients
+                        .as_array()
+                        .cloned()
+                        .unwrap_or_default()
+                        .iter()
+                        .map(|recipient| ResponseSuccess {
+                            email: recipient["Email"].as_str().unwrap_or_default().to_string(),
+                            ..Default::default()
+                        })
+                        .collect()
+                };
+
+                ResponseMessage {
+                    status: "success".to_string(),
+                    custom_id: message["CustomID"].as_str().unwrap_or_default().to_string(),
+                    to: sent_to("To"),
+                    cc: sent_to("Cc"),
+                    bcc: sent_to("Bcc"),
+                    ..Default::default()
+                }
+            })
+            .collect();
+        let failed = messages.is_empty() || messages.iter().any(|m| m.status != "success");
+        let object = SendResponse { messages };
+        let raw_data = serde_json::to_string(&object).unwrap_or_default();
+
+        Response::create_from_data(Some(if failed { 400 } else { 200 }), raw_data, Some(object))
+    }
+
+    /// Sends emails via the legacy Send API v3
+    ///
+    /// Refused in [`DryRunMode::Sandbox`], Send API v3 has no sandbox
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing all emails
+    pub fn send_v3(&self, request: &SendV3Request) -> Result<Response<SendV3Response>, Box<dyn StdError>> {
+        let mut j = serde_json::to_value(request)?;
+        let url = self.url("/v3/send");
+
+        if let Some(messages) = j.get_mut("Messages").and_then(Value::as_array_mut) {
+            for message in messages {
+                let mut m: MessageV3 = serde_json::from_value(message.clone())?;
+
+                self.recipient_policy.apply_v3(&mut m)?;
+                *message = serde_json::to_value(m)?;
+            }
+        }
+
+        if self.dry_run_mode == DryRunMode::Sandbox {
+            return Err(Box::new(DryRunError::NotSandboxable(RequestType::Post, url)));
+        }
+
+        let (response, code) = self.post(&url, &j.to_string())?;
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code, response, object))
     }
 
     /// Gets a list of messages with specific information on the type of content, tracking, sending and delivery
@@ -194,11 +455,9 @@ impl Mailjet {
     /// * `search`: The search arguments
     pub fn message(&self, search: &MessageRequest) -> Result<Response<MessageResponse>, Box<dyn StdError>> {
         // Create url
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("message");
 
@@ -208,7 +467,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Retrieves specific information on the type of content, tracking, sending and
@@ -219,11 +478,9 @@ impl Mailjet {
     /// * `message_id`: The message id
     pub fn message_from_id(&self, message_id: i128) -> Result<Response<MessageResponse>, Box<dyn StdError>> {
         // Create url
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("message")
             .add_route(&message_id.to_string());
@@ -232,7 +489,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Retrieve the event history (sending, open, click etc.) for a specific message
@@ -245,11 +502,9 @@ impl Mailjet {
         message_id: i128,
     ) -> Result<Response<MessageHistoryResponse>, Box<dyn StdError>> {
         // Create url
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("messagehistory")
             .add_route(&message_id.to_string());
@@ -258,7 +513,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Retrieve sending / size / spam information about all messages
@@ -271,11 +526,9 @@ impl Mailjet {
         search: &MessageInformationRequest,
     ) -> Result<Response<MessageInformationResponse>, Box<dyn StdError>> {
         // Create url
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("messageinformation");
 
@@ -285,7 +538,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Retrieve sending / size / spam information about a specific message ID
@@ -298,11 +551,9 @@ impl Mailjet {
         message_id: i128,
     ) -> Result<Response<MessageInformationResponse>, Box<dyn StdError>> {
         // Create url
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("messageinformation")
             .add_route(&message_id.to_string());
@@ -311,7 +562,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Add a new unique contact to your global contact list and select its exclusion status
@@ -325,9 +576,9 @@ impl Mailjet {
     ) -> Result<Response<
     ContactResponse>, Box<dyn StdError>> {
         let j = serde_json::to_string(request)?;
-        let (response, code) = self.post("https://api.mailjet.com/v3/REST/contact", &j)?;
+        let (response, code) = self.post(&self.url("/v3/REST/contact"), &j)?;
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Retrieve a list of all contacts.
@@ -341,11 +592,9 @@ impl Mailjet {
         &self,
         search: &ContactSearchRequest,
     ) -> Result<Response<ContactResponse>, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contact");
         search.add_parameters_to_url(&mut ub);
@@ -353,7 +602,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Retrieve a specific contact
@@ -367,11 +616,9 @@ impl Mailjet {
         &self,
         identifier: &ContactIdentifier,
     ) -> Result<Response<ContactResponse>, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contact")
             .add_route(&identifier.to_string());
@@ -379,7 +626,7 @@ impl Mailjet {
         let (response, code) = self.get(&ub.build())?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Update the user-given name and exclusion status of a specific contact
@@ -394,11 +641,9 @@ impl Mailjet {
         request: &ContactRequest,
     ) -> Result<Response<ContactResponse>, Box<dyn StdError>> {
         let j = serde_json::to_string(&request)?;
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contact")
             .add_route(&identifier.to_string());
@@ -406,7 +651,7 @@ impl Mailjet {
         let (response, code) = self.put(&ub.build(), &j)?;
 
         let object = serde_json::from_str(&response).ok();
-        Ok(Response::create_from_data(code.ok(),response, object))
+        Ok(Response::create_from_data(code,response, object))
     }
 
     /// Delete a contact
@@ -417,17 +662,213 @@ impl Mailjet {
     ///
     /// * `contact_id`: The contact's id
     pub fn contact_delete(&self, contact_id: i128) -> Result<Response<bool>, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v4")
+        ub.add_route("v4")
             .add_route("contacts")
             .add_route(&contact_id.to_string());
 
         let (response, code) = self.delete(&ub.build())?;
 
-        Ok(Response::create_from_data(code.clone().ok(), response, Some((200..300).contains(&code.unwrap_or_default()))))
+        Ok(Response::create_from_data(code, response, Some((200..300).contains(&code.unwrap_or_default()))))
+    }
+
+    /// Create or update multiple contacts and manage their subscription to contact lists
+    ///
+    /// The processing is asynchronous, check its status with
+    /// [`Mailjet::contact_manage_many_search_from_id`]
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The contacts and the actions to apply on contact lists
+    pub fn contact_manage_many(
+        &self,
+        request: &ManageManyContactsRequest,
+    ) -> Result<Response<ContactsJobResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let (response, code) = self.post(
+            &self.url("/v3/REST/contact/managemanycontacts"),
+            &j,
+        )?;
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve the status of a bulk contact management job
+    ///
+    /// # Parameters
+    ///
+    /// * `job_id`: The job id
+    pub fn contact_manage_many_search_from_id(
+        &self,
+        job_id: i128,
+    ) -> Result<Response<ContactsJobStatusResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contact")
+            .add_route("managemanycontacts")
+            .add_route(&job_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Add a contact to the exclusion list, it will no longer receive marketing emails
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the contact
+    pub fn exclude_contact(
+        &self,
+        identifier: &ContactIdentifier,
+    ) -> Result<GdprAuditRecord, Box<dyn StdError>> {
+        self.set_contact_exclusion(identifier, GdprAction::Exclude)
+    }
+
+    /// Remove a contact from the exclusion list
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the contact
+    pub fn unexclude_contact(
+        &self,
+        identifier: &ContactIdentifier,
+    ) -> Result<GdprAuditRecord, Box<dyn StdError>> {
+        self.set_contact_exclusion(identifier, GdprAction::Unexclude)
+    }
+
+    /// Updates the exclusion status of a contact and records it
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the contact
+    /// * `action`: Either [`GdprAction::Exclude`] or [`GdprAction::Unexclude`]
+    fn set_contact_exclusion(
+        &self,
+        identifier: &ContactIdentifier,
+        action: GdprAction,
+    ) -> Result<GdprAuditRecord, Box<dyn StdError>> {
+        let request = ContactRequest {
+            is_excluded_from_campaigns: Some(action == GdprAction::Exclude),
+            ..Default::default()
+        };
+        let response = self.contact_update(identifier, &request)?;
+        let mut record = GdprAuditRecord::new(action, Vec::new());
+
+        if let ContactIdentifier::ContactEmail(email) = identifier {
+            record.emails.push(email.clone());
+        }
+
+        if let Some(contact) = response.object.as_ref().and_then(|o| o.data.first()) {
+            record.contact_ids.push(contact.id);
+
+            if record.emails.is_empty() {
+                record.emails.push(contact.email.clone());
+            }
+        }
+
+        record.http_code = response.http_code;
+        record.success = (200..300).contains(&response.http_code.unwrap_or_default());
+
+        if record.success {
+            info!("{:?} performed on contact {}", action, identifier);
+        } else {
+            record.details = response.raw_data;
+        }
+
+        Ok(record)
+    }
+
+    /// Add multiple contacts to the exclusion list in a single bulk job
+    ///
+    /// Contacts that don't exist yet are created. The record contains the job id, use
+    /// [`Mailjet::contact_manage_many_search_from_id`] to follow its progress
+    ///
+    /// # Parameters
+    ///
+    /// * `emails`: The email addresses of the contacts
+    pub fn exclude_contacts(&self, emails: &[&str]) -> Result<GdprAuditRecord, Box<dyn StdError>> {
+        let request = ManageManyContactsRequest {
+            contacts: emails
+                .iter()
+                .map(|email| ContactRequest {
+                    email: Some(email.to_string()),
+                    is_excluded_from_campaigns: Some(true),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        };
+        let response = self.contact_manage_many(&request)?;
+        let mut record = GdprAuditRecord::new(
+            GdprAction::BulkExclude,
+            emails.iter().map(|email| email.to_string()).collect(),
+        );
+
+        record.job_id = response
+            .object
+            .as_ref()
+            .and_then(|o| o.data.first())
+            .map(|job| job.job_id);
+        record.http_code = response.http_code;
+        record.success = record.job_id.is_some();
+
+        if record.success {
+            info!("Bulk exclusion submitted for {} contacts", emails.len());
+        } else {
+            record.details = response.raw_data;
+        }
+
+        Ok(record)
+    }
+
+    /// Delete a contact and all its personal data, as requested by the right to be forgotten
+    ///
+    /// The email is first resolved to a contact id, then the contact is deleted.
+    /// This function works only if you are in a country under GDPR law
+    ///
+    /// # Parameters
+    ///
+    /// * `email`: The email address of the contact
+    pub fn forget_contact(&self, email: &str) -> Result<GdprAuditRecord, Box<dyn StdError>> {
+        let mut record = GdprAuditRecord::new(GdprAction::Forget, vec![email.to_string()]);
+        let search = self.contact_search_from_id_or_email(&ContactIdentifier::ContactEmail(
+            email.to_string(),
+        ))?;
+
+        let contact = search.object.as_ref().and_then(|o| o.data.first());
+
+        let contact_id = match (search.http_code, contact) {
+            (Some(200..=299), Some(contact)) => contact.id,
+            (Some(200..=299 | 404), _) => {
+                record.http_code = search.http_code;
+                record.details = "Contact not found".to_string();
+                return Ok(record);
+            }
+            _ => {
+                record.http_code = search.http_code;
+                record.details = format!("Contact search failed: {}", search.raw_data);
+                return Ok(record);
+            }
+        };
+
+        let response = self.contact_delete(contact_id)?;
+
+        record.contact_ids.push(contact_id);
+        record.http_code = response.http_code;
+        record.success = response.object.unwrap_or_default();
+
+        if record.success {
+            info!("Contact {} forgotten", contact_id);
+        } else {
+            record.details = response.raw_data;
+        }
+
+        Ok(record)
     }
 
     /// Create a new contact list
@@ -440,7 +881,7 @@ impl Mailjet {
         request: &ContactsListRequest,
     ) -> Result<ContactsListResponse, Box<dyn StdError>> {
         let j = serde_json::to_string(request)?;
-        let (response, _) = self.post("https://api.mailjet.com/v3/REST/contactslist", &j)?;
+        let (response, _) = self.post(&self.url("/v3/REST/contactslist"), &j)?;
         Ok(serde_json::from_str(&response)?)
     }
 
@@ -454,11 +895,9 @@ impl Mailjet {
         &self,
         search: &ContactsListSearchRequest,
     ) -> Result<ContactsListResponse, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contactslist");
 
@@ -479,11 +918,9 @@ impl Mailjet {
         &self,
         identifier: &ContactsListIdentifier,
     ) -> Result<ContactsListResponse, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contactslist")
             .add_route(&identifier.to_string());
@@ -504,12 +941,10 @@ impl Mailjet {
         identifier: &ContactsListIdentifier,
         request: &ContactsListRequest,
     ) -> Result<ContactsListResponse, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
         let j = serde_json::to_string(request)?;
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contactslist")
             .add_route(&identifier.to_string());
@@ -532,11 +967,9 @@ impl Mailjet {
         &self,
         identifier: &ContactsListIdentifier,
     ) -> Result<bool, Box<dyn StdError>> {
-        let mut ub = URLBuilder::new();
+        let mut ub = self.url_builder();
 
-        ub.set_protocol("https")
-            .set_host("api.mailjet.com")
-            .add_route("v3")
+        ub.add_route("v3")
             .add_route("REST")
             .add_route("contactslist")
             .add_route(&identifier.to_string());
@@ -545,58 +978,1469 @@ impl Mailjet {
 
         Ok((200..300).contains(&code.unwrap_or_default()))
     }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::data::{ContactIdentifier, ContactsListIdentifier};
-    use crate::requests::{
-        ContactRequest, ContactSearchRequest, ContactsListRequest, ContactsListSearchRequest,
-    };
-    use crate::{
-        data::{EmailAddress, Message},
-        requests::{MessageInformationRequest, MessageRequest, SendRequest},
-        Mailjet,
-    };
-    use rand::Rng;
 
-    #[test]
-    fn send_message() {
-        let mut send_request = SendRequest::default();
-        let to = EmailAddress::from_email("john.doe@example.com");
-        let from = EmailAddress::from_email_and_name("jane.doe@example.com", "Jane Doe");
-        let mut message = Message::default();
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+    /// Retrieve sending statistics for all contacts - number of messages delivered,
+    /// opened, clicked, bounced etc. and last activity timestamp
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn contact_statistics(
+        &self,
+        search: &ContactStatisticsRequest,
+    ) -> Result<Response<ContactStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
 
-        message.to.push(to);
-        message.from = from;
-        message.html_part = "<p>Hello world!</p>".to_string();
-        message.text_part = "Hello World!".to_string();
-        message.subject = "Test email".to_string();
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactstatistics");
 
-        send_request.sandbox_mode = Some(true);
-        send_request.messages.push(message);
+        search.add_parameters_to_url(&mut ub);
 
-        let response = mailjet.send(&send_request).unwrap().object.unwrap();
+        let (response, code) = self.get(&ub.build())?;
 
-        assert_eq!(response.messages.len(), 1);
-        assert_eq!(response.messages.first().unwrap().status, "success");
-        assert_eq!(response.messages.first().unwrap().to.len(), 1);
-        assert_eq!(
-            response.messages.first().unwrap().to.first().unwrap().email,
-            "john.doe@example.com"
-        );
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for a specific contact
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the contact
+    pub fn contact_statistics_from_id_or_email(
+        &self,
+        identifier: &ContactIdentifier,
+    ) -> Result<Response<ContactStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactstatistics")
+            .add_route(&identifier.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for all contacts inside contact lists
+    ///
+    /// Useful to find inactive subscribers of a list
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn list_recipient_statistics(
+        &self,
+        search: &ListRecipientStatisticsRequest,
+    ) -> Result<Response<ListRecipientStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("listrecipientstatistics");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for a specific list recipient
+    ///
+    /// # Parameters
+    ///
+    /// * `list_recipient_id`: The list recipient's id
+    pub fn list_recipient_statistics_from_id(
+        &self,
+        list_recipient_id: i128,
+    ) -> Result<Response<ListRecipientStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("listrecipientstatistics")
+            .add_route(&list_recipient_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for all contact lists - subscriber count, number
+    /// of messages delivered, opened, clicked etc.
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn contacts_list_statistics(
+        &self,
+        search: &ContactsListStatisticsRequest,
+    ) -> Result<Response<ContactsListStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("liststatistics");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for a specific contact list
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the address of the contacts list
+    pub fn contacts_list_statistics_from_id_or_address(
+        &self,
+        identifier: &ContactsListIdentifier,
+    ) -> Result<Response<ContactsListStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("liststatistics")
+            .add_route(&identifier.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for all sender addresses
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn sender_statistics(
+        &self,
+        search: &SenderStatisticsRequest,
+    ) -> Result<Response<SenderStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("senderstatistics");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve sending statistics for a specific sender address
+    ///
+    /// # Parameters
+    ///
+    /// * `sender_id`: The sender's id
+    pub fn sender_statistics_from_id(
+        &self,
+        sender_id: i128,
+    ) -> Result<Response<SenderStatisticsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("senderstatistics")
+            .add_route(&sender_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Create a new sender email address or domain
+    ///
+    /// The sender will be inactive until validated with [`Mailjet::sender_validate`]
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing sender data
+    pub fn sender_create(
+        &self,
+        request: &SenderRequest,
+    ) -> Result<Response<SenderResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("sender");
+
+        let (response, code) = self.post(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a list of all sender email addresses and domains, with their status
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn sender_search(
+        &self,
+        search: &SenderSearchRequest,
+    ) -> Result<Response<SenderResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("sender");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a specific sender email address or domain
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the sender
+    pub fn sender_search_from_id_or_email(
+        &self,
+        identifier: &SenderIdentifier,
+    ) -> Result<Response<SenderResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("sender")
+            .add_route(&identifier.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update the name, email type and default status of a specific sender
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the sender to update
+    /// * `request`: The updated information
+    pub fn sender_update(
+        &self,
+        identifier: &SenderIdentifier,
+        request: &SenderRequest,
+    ) -> Result<Response<SenderResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("sender")
+            .add_route(&identifier.to_string());
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Delete a sender
+    ///
+    /// The sender will continue to exist with `Deleted` status
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the sender to delete
+    pub fn sender_delete(
+        &self,
+        identifier: &SenderIdentifier,
+    ) -> Result<Response<bool>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("sender")
+            .add_route(&identifier.to_string());
+
+        let (response, code) = self.delete(&ub.build())?;
+
+        Ok(Response::create_from_data(code, response, Some((200..300).contains(&code.unwrap_or_default()))))
+    }
+
+    /// Validate a sender
+    ///
+    /// Mailjet checks the domain's DNS and sends an activation email if needed
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the email of the sender to validate
+    pub fn sender_validate(
+        &self,
+        identifier: &SenderIdentifier,
+    ) -> Result<Response<SenderValidateResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("sender")
+            .add_route(&identifier.to_string())
+            .add_route("validate");
+
+        let (response, code) = self.post(&ub.build(), "{}")?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Create a new metasender, allowed to send on behalf of any validated sender
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing metasender data
+    pub fn metasender_create(
+        &self,
+        request: &MetasenderRequest,
+    ) -> Result<Response<MetasenderResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("metasender");
+
+        let (response, code) = self.post(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a list of all metasenders
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn metasender_search(
+        &self,
+        search: &MetasenderSearchRequest,
+    ) -> Result<Response<MetasenderResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("metasender");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a specific metasender
+    ///
+    /// # Parameters
+    ///
+    /// * `metasender_id`: The metasender's id
+    pub fn metasender_search_from_id(
+        &self,
+        metasender_id: i128,
+    ) -> Result<Response<MetasenderResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("metasender")
+            .add_route(&metasender_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update the description and enabled status of a specific metasender
+    ///
+    /// # Parameters
+    ///
+    /// * `metasender_id`: The metasender's id
+    /// * `request`: The updated information
+    pub fn metasender_update(
+        &self,
+        metasender_id: i128,
+        request: &MetasenderRequest,
+    ) -> Result<Response<MetasenderResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("metasender")
+            .add_route(&metasender_id.to_string());
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve the SPF and DKIM configuration of all sending domains
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn dns_search(
+        &self,
+        search: &DnsSearchRequest,
+    ) -> Result<Response<DnsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("dns");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve the SPF and DKIM configuration of a specific sending domain
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the name of the domain
+    pub fn dns_search_from_id_or_domain(
+        &self,
+        identifier: &DnsIdentifier,
+    ) -> Result<Response<DnsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("dns")
+            .add_route(&identifier.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Run a new check of the SPF and DKIM records of a specific sending domain
+    ///
+    /// # Parameters
+    ///
+    /// * `identifier`: The id or the name of the domain to check
+    pub fn dns_check(
+        &self,
+        identifier: &DnsIdentifier,
+    ) -> Result<Response<DnsCheckResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("dns")
+            .add_route(&identifier.to_string())
+            .add_route("check");
+
+        let (response, code) = self.post(&ub.build(), "{}")?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Register a new webhook called for each event of a specific type
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing webhook data
+    pub fn event_callback_url_create(
+        &self,
+        request: &EventCallbackUrlRequest,
+    ) -> Result<Response<EventCallbackUrlResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("eventcallbackurl");
+
+        let (response, code) = self.post(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a list of all webhooks
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn event_callback_url_search(
+        &self,
+        search: &EventCallbackUrlSearchRequest,
+    ) -> Result<Response<EventCallbackUrlResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("eventcallbackurl");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a specific webhook
+    ///
+    /// # Parameters
+    ///
+    /// * `url_id`: The webhook's id
+    pub fn event_callback_url_search_from_id(
+        &self,
+        url_id: i128,
+    ) -> Result<Response<EventCallbackUrlResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("eventcallbackurl")
+            .add_route(&url_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update the URL, event type, status or payload version of a specific webhook
+    ///
+    /// # Parameters
+    ///
+    /// * `url_id`: The webhook's id
+    /// * `request`: The updated information
+    pub fn event_callback_url_update(
+        &self,
+        url_id: i128,
+        request: &EventCallbackUrlRequest,
+    ) -> Result<Response<EventCallbackUrlResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("eventcallbackurl")
+            .add_route(&url_id.to_string());
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Delete a webhook
+    ///
+    /// # Parameters
+    ///
+    /// * `url_id`: The webhook's id
+    pub fn event_callback_url_delete(
+        &self,
+        url_id: i128,
+    ) -> Result<Response<bool>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("eventcallbackurl")
+            .add_route(&url_id.to_string());
+
+        let (response, code) = self.delete(&ub.build())?;
+
+        Ok(Response::create_from_data(code, response, Some((200..300).contains(&code.unwrap_or_default()))))
+    }
+
+    /// Create a new parse route, posting emails received at an address to a URL
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing parse route data
+    pub fn parse_route_create(
+        &self,
+        request: &ParseRouteRequest,
+    ) -> Result<Response<ParseRouteResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("parseroute");
+
+        let (response, code) = self.post(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a list of all parse routes
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn parse_route_search(
+        &self,
+        search: &ParseRouteSearchRequest,
+    ) -> Result<Response<ParseRouteResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("parseroute");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a specific parse route
+    ///
+    /// # Parameters
+    ///
+    /// * `parse_route_id`: The parse route's id
+    pub fn parse_route_search_from_id(
+        &self,
+        parse_route_id: i128,
+    ) -> Result<Response<ParseRouteResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("parseroute")
+            .add_route(&parse_route_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update the URL or the address of a specific parse route
+    ///
+    /// # Parameters
+    ///
+    /// * `parse_route_id`: The parse route's id
+    /// * `request`: The updated information
+    pub fn parse_route_update(
+        &self,
+        parse_route_id: i128,
+        request: &ParseRouteRequest,
+    ) -> Result<Response<ParseRouteResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("parseroute")
+            .add_route(&parse_route_id.to_string());
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Delete a parse route
+    ///
+    /// # Parameters
+    ///
+    /// * `parse_route_id`: The parse route's id
+    pub fn parse_route_delete(
+        &self,
+        parse_route_id: i128,
+    ) -> Result<Response<bool>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("parseroute")
+            .add_route(&parse_route_id.to_string());
+
+        let (response, code) = self.delete(&ub.build())?;
+
+        Ok(Response::create_from_data(code, response, Some((200..300).contains(&code.unwrap_or_default()))))
+    }
+
+    /// Sends an SMS via SMS API v4
+    ///
+    /// Requires a bearer token, refused in [`DryRunMode::Sandbox`] as SMS API has no sandbox
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing the SMS
+    pub fn sms_send(&self, request: &SmsRequest) -> Result<Response<Sms>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let url = self.url("/v4/sms-send");
+
+        if self.dry_run_mode == DryRunMode::Sandbox {
+            return Err(Box::new(DryRunError::NotSandboxable(RequestType::Post, url)));
+        }
+
+        let (response, code) = self.post_with_token(&url, &j)?;
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a list of SMS messages with their status and cost
+    ///
+    /// Requires a bearer token
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn sms_search(
+        &self,
+        search: &SmsSearchRequest,
+    ) -> Result<Response<SmsResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v4")
+            .add_route("sms");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get_with_token(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve the number of SMS messages matching a search
+    ///
+    /// Requires a bearer token. The `limit` and `offset` arguments are ignored
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn sms_count(
+        &self,
+        search: &SmsSearchRequest,
+    ) -> Result<Response<SmsCountResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v4")
+            .add_route("sms")
+            .add_route("count");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get_with_token(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a specific SMS message
+    ///
+    /// Requires a bearer token
+    ///
+    /// # Parameters
+    ///
+    /// * `sms_id`: The SMS id
+    pub fn sms_search_from_id(&self, sms_id: &str) -> Result<Response<Sms>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v4")
+            .add_route("sms")
+            .add_route(sms_id);
+
+        let (response, code) = self.get_with_token(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Request an export of the SMS messages sent during a period as a CSV file
+    ///
+    /// Requires a bearer token. The export is asynchronous, check its status with
+    /// [`Mailjet::sms_export_search_from_id`]
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The export period
+    pub fn sms_export_create(
+        &self,
+        request: &SmsExportRequest,
+    ) -> Result<Response<SmsExport>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let (response, code) = self.post_with_token(&self.url("/v4/sms/export"), &j)?;
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve the status of an SMS export job, and the URL of the CSV file once completed
+    ///
+    /// Requires a bearer token
+    ///
+    /// # Parameters
+    ///
+    /// * `job_id`: The export job id
+    pub fn sms_export_search_from_id(
+        &self,
+        job_id: &str,
+    ) -> Result<Response<SmsExport>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v4")
+            .add_route("sms")
+            .add_route("export")
+            .add_route(job_id);
+
+        let (response, code) = self.get_with_token(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Create a new contact segmentation formula
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing contact filter data
+    pub fn contact_filter_create(
+        &self,
+        request: &ContactFilterRequest,
+    ) -> Result<Response<ContactFilterResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactfilter");
+
+        let (response, code) = self.post(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve a list of all existing segments
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn contact_filter_search(
+        &self,
+        search: &ContactFilterSearchRequest,
+    ) -> Result<Response<ContactFilterResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactfilter");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve an existing contact segmentation formula
+    ///
+    /// # Parameters
+    ///
+    /// * `contact_filter_id`: The contact filter's id
+    pub fn contact_filter_search_from_id(
+        &self,
+        contact_filter_id: i128,
+    ) -> Result<Response<ContactFilterResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactfilter")
+            .add_route(&contact_filter_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update an existing contact segmentation formula
+    ///
+    /// # Parameters
+    ///
+    /// * `contact_filter_id`: The contact filter's id
+    /// * `request`: The updated information
+    pub fn contact_filter_update(
+        &self,
+        contact_filter_id: i128,
+        request: &ContactFilterRequest,
+    ) -> Result<Response<ContactFilterResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactfilter")
+            .add_route(&contact_filter_id.to_string());
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Delete an existing contact segmentation formula
+    ///
+    /// # Parameters
+    ///
+    /// * `contact_filter_id`: The contact filter's id
+    pub fn contact_filter_delete(
+        &self,
+        contact_filter_id: i128,
+    ) -> Result<Response<bool>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("contactfilter")
+            .add_route(&contact_filter_id.to_string());
+
+        let (response, code) = self.delete(&ub.build())?;
+
+        Ok(Response::create_from_data(code, response, Some((200..300).contains(&code.unwrap_or_default()))))
+    }
+
+    /// Create a new sub-account API key
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request containing API key data
+    pub fn api_key_create(
+        &self,
+        request: &ApiKeyRequest,
+    ) -> Result<Response<ApiKeyResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("apikey");
+
+        let (response, code) = self.post(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Get all API keys and their configuration settings
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn api_key_search(
+        &self,
+        search: &ApiKeySearchRequest,
+    ) -> Result<Response<ApiKeyResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("apikey");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Get the configuration settings of a specific API key
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_id`: The API key's id
+    pub fn api_key_search_from_id(
+        &self,
+        api_key_id: i128,
+    ) -> Result<Response<ApiKeyResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("apikey")
+            .add_route(&api_key_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update an existing API key
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_id`: The API key's id
+    /// * `request`: The updated information
+    pub fn api_key_update(
+        &self,
+        api_key_id: i128,
+        request: &ApiKeyRequest,
+    ) -> Result<Response<ApiKeyResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("apikey")
+            .add_route(&api_key_id.to_string());
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Get all accesses granted to API keys
+    ///
+    /// # Parameters
+    ///
+    /// * `search`: The search arguments
+    pub fn api_key_access_search(
+        &self,
+        search: &ApiKeyAccessSearchRequest,
+    ) -> Result<Response<ApiKeyAccessResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("apikeyaccess");
+
+        search.add_parameters_to_url(&mut ub);
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Get a specific API key access
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_access_id`: The API key access's id
+    pub fn api_key_access_search_from_id(
+        &self,
+        api_key_access_id: i128,
+    ) -> Result<Response<ApiKeyAccessResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("apikeyaccess")
+            .add_route(&api_key_access_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Retrieve your profile information
+    pub fn profile_search(&self) -> Result<Response<ProfileResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("myprofile");
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update your profile information
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The updated information
+    pub fn profile_update(
+        &self,
+        request: &ProfileRequest,
+    ) -> Result<Response<ProfileResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("myprofile");
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Get general information on your user settings and activity
+    pub fn user_search(&self) -> Result<Response<UserResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("user");
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Update the settings of your user
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The updated information
+    pub fn user_update(
+        &self,
+        request: &UserRequest,
+    ) -> Result<Response<UserResponse>, Box<dyn StdError>> {
+        let j = serde_json::to_string(request)?;
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("user");
+
+        let (response, code) = self.put(&ub.build(), &j)?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Activate an API key
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_id`: The API key's id
+    pub fn api_key_activate(
+        &self,
+        api_key_id: i128,
+    ) -> Result<Response<ApiKeyResponse>, Box<dyn StdError>> {
+        let request = ApiKeyRequest {
+            is_active: Some(true),
+            ..Default::default()
+        };
+
+        self.api_key_update(api_key_id, &request)
+    }
+
+    /// Deactivate an API key, requests made with it will be refused
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_id`: The API key's id
+    pub fn api_key_deactivate(
+        &self,
+        api_key_id: i128,
+    ) -> Result<Response<ApiKeyResponse>, Box<dyn StdError>> {
+        let request = ApiKeyRequest {
+            is_active: Some(false),
+            ..Default::default()
+        };
+
+        self.api_key_update(api_key_id, &request)
+    }
+
+    /// Creates a client acting on behalf of a sub-account
+    ///
+    /// The client shares this client's base URL, dry run mode, recipient policy and transport
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key`: The sub-account's API key, as returned by API key routes
+    pub fn sub_account(&self, api_key: &ApiKey) -> Mailjet {
+        let mut client = Mailjet::from_api_keys(&api_key.api_key, &api_key.secret_key)
+            .with_base_url(&self.base_url);
+
+        client.dry_run_mode = self.dry_run_mode;
+        client.recipient_policy = self.recipient_policy.clone();
+        client.transport = self.transport.clone();
+        client
+    }
+
+    /// Creates a client acting on behalf of a sub-account from its API key id
+    ///
+    /// Returns `None` if the API key doesn't exist
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key_id`: The sub-account API key's id
+    pub fn sub_account_from_id(
+        &self,
+        api_key_id: i128,
+    ) -> Result<Option<Mailjet>, Box<dyn StdError>> {
+        let response = self.api_key_search_from_id(api_key_id)?;
+
+        Ok(response
+            .object
+            .and_then(|o| o.data.into_iter().next())
+            .map(|api_key| self.sub_account(&api_key)))
+    }
+
+    /// Retrieve a specific batch job
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_job_id`: The batch job's id
+    pub fn batch_job_search_from_id(
+        &self,
+        batch_job_id: i128,
+    ) -> Result<Response<BatchJobResponse>, Box<dyn StdError>> {
+        let mut ub = self.url_builder();
+
+        ub.add_route("v3")
+            .add_route("REST")
+            .add_route("batchjob")
+            .add_route(&batch_job_id.to_string());
+
+        let (response, code) = self.get(&ub.build())?;
+
+        let object = serde_json::from_str(&response).ok();
+        Ok(Response::create_from_data(code, response, object))
+    }
+
+    /// Wait for a batch job to finish, see [`poll_until_complete`]
+    ///
+    /// # Parameters
+    ///
+    /// * `batch_job_id`: The batch job's id
+    /// * `options`: The timeout and the delays between checks
+    pub fn batch_job_poll_until_complete(
+        &self,
+        batch_job_id: i128,
+        options: &PollOptions,
+    ) -> Result<BatchJob, Box<dyn StdError>> {
+        poll_until_complete(options, || {
+            Self::polled_job(self.batch_job_search_from_id(batch_job_id)?, |o| {
+                o.data.into_iter().next()
+            })
+        })
+    }
+
+    /// Wait for a bulk contact management job to finish, see [`poll_until_complete`]
+    ///
+    /// # Parameters
+    ///
+    /// * `job_id`: The job id
+    /// * `options`: The timeout and the delays between checks
+    pub fn contact_manage_many_poll_until_complete(
+        &self,
+        job_id: i128,
+        options: &PollOptions,
+    ) -> Result<ContactsJobStatus, Box<dyn StdError>> {
+        poll_until_complete(options, || {
+            Self::polled_job(self.contact_manage_many_search_from_id(job_id)?, |o| {
+                o.data.into_iter().next()
+            })
+        })
+    }
+
+    /// Wait for an SMS export job to finish, see [`poll_until_complete`]
+    ///
+    /// # Parameters
+    ///
+    /// * `job_id`: The export job id
+    /// * `options`: The timeout and the delays between checks
+    pub fn sms_export_poll_until_complete(
+        &self,
+        job_id: &str,
+        options: &PollOptions,
+    ) -> Result<SmsExport, Box<dyn StdError>> {
+        poll_until_complete(options, || {
+            Self::polled_job(self.sms_export_search_from_id(job_id)?, Some)
+        })
+    }
+
+    /// Extracts a polled job from a response, rate limits and server errors are reported as
+    /// [`PollError::Unavailable`] so that the job is checked again
+    ///
+    /// # Parameters
+    ///
+    /// * `response`: The response to the job's retrieval
+    /// * `job`: Extracts the job from the response's object
+    fn polled_job<T, J>(
+        response: Response<T>,
+        job: impl FnOnce(T) -> Option<J>,
+    ) -> Result<Option<J>, Box<dyn StdError>> {
+        match response.http_code {
+            None | Some(429 | 500..=599) => {
+                Err(Box::new(PollError::Unavailable(response.http_code)))
+            }
+            _ => Ok(response.object.and_then(job)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data::{
+        ContactIdentifier, ContactsListIdentifier, DnsIdentifier, EmailType,
+        EventCallbackUrlStatus, EventCallbackUrlVersion, EventType, GdprAction, Globals, MessageV3,
+        SegmentExpression, SenderIdentifier, SenderStatus,
+    };
+    use crate::dry_run::{DryRunError, DryRunMode, RecipientPolicy};
+    use crate::polling::PollOptions;
+    use crate::requests::{
+        ApiKeySearchRequest, ContactFilterRequest, ContactRequest, ContactSearchRequest,
+        ContactStatisticsRequest, ContactsListRequest, ContactsListSearchRequest, DnsSearchRequest,
+        EventCallbackUrlRequest, ListRecipientStatisticsRequest, ParseRouteRequest, SendV3Request,
+        SenderRequest, SenderSearchRequest, SenderStatisticsRequest, SmsExportRequest, SmsRequest,
+        SmsSearchRequest,
+    };
+    use crate::testing::{seed, MockServer};
+    use crate::transport::RequestType;
+    use crate::{
+        data::{EmailAddress, Message},
+        requests::{MessageInformationRequest, MessageRequest, SendRequest},
+        Mailjet,
+    };
+    use std::time::Duration;
+
+    /// Returns polling options fast enough for the mock server
+    fn fast_polling() -> PollOptions {
+        PollOptions {
+            timeout: Duration::from_secs(5),
+            initial_interval: Duration::from_millis(10),
+            max_interval: Duration::from_millis(10),
+            multiplier: 1.0,
+        }
     }
 
     #[test]
-    fn retrieve_all_messages() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
+    fn send_message() {
+        let server = MockServer::start().unwrap();
+        let mut send_request = SendRequest::default();
+        let to = EmailAddress::from_email("john.doe@example.com");
+        let from = EmailAddress::from_email_and_name("jane.doe@example.com", "Jane Doe");
+        let mut message = Message::default();
+        let mailjet = server.client();
+
+        message.to.push(to);
+        message.from = from;
+        message.html_part = "<p>Hello world!</p>".to_string();
+        message.text_part = "Hello World!".to_string();
+        message.subject = "Test email".to_string();
+
+        send_request.sandbox_mode = Some(true);
+        send_request.messages.push(message);
+
+        let response = mailjet.send(&send_request).unwrap().object.unwrap();
+
+        assert_eq!(response.messages.len(), 1);
+        assert_eq!(response.messages.first().unwrap().status, "success");
+        assert_eq!(response.messages.first().unwrap().to.len(), 1);
+        assert_eq!(
+            response.messages.first().unwrap().to.first().unwrap().email,
+            "john.doe@example.com"
         );
+    }
+
+    #[test]
+    fn send_message_without_sender() {
+        let server = MockServer::start().unwrap();
+        let mut send_request = SendRequest::default();
+        let mut message = Message::default();
+
+        message.to.push(EmailAddress::from_email("john.doe@example.com"));
+        message.text_part = "Hello World!".to_string();
+        send_request.messages.push(message);
+
+        let response = server.client().send(&send_request).unwrap();
+        let message = &response.object.unwrap().messages[0];
+
+        assert_eq!(response.http_code, Some(400));
+        assert_eq!(message.status, "error");
+        assert_eq!(message.errors[0].error_related_to, vec!["From"]);
+    }
+
+    #[test]
+    fn send_message_with_send_api_v3() {
+        let server = MockServer::start().unwrap();
+        let message = Message {
+            from: EmailAddress::from_email("jane.doe@example.com"),
+            to: vec![EmailAddress::from_email("john.doe@example.com")],
+            text_part: "Hello World!".to_string(),
+            ..Default::default()
+        };
+
+        let request = SendV3Request {
+            messages: vec![MessageV3::from(&message)],
+        };
+        let response = server.client().send_v3(&request).unwrap().object.unwrap();
+
+        assert_eq!(response.sent.len(), 1);
+        assert_eq!(response.sent[0].email, "john.doe@example.com");
+    }
+
+    #[test]
+    fn wrong_credentials_are_rejected() {
+        let server = MockServer::start().unwrap();
+        let mailjet = Mailjet::from_api_keys("wrong_key", "wrong_secret")
+            .with_base_url(&server.base_url());
+
+        let response = mailjet
+            .contact_search(&ContactSearchRequest::default())
+            .unwrap();
+
+        assert_eq!(response.http_code, Some(401));
+    }
+
+    #[test]
+    fn rate_limited_request() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+
+        server.fail_next(429);
+
+        let limited = mailjet.message_from_id(seed::MESSAGE_ID).unwrap();
+        let retried = mailjet.message_from_id(seed::MESSAGE_ID).unwrap();
+
+        assert_eq!(limited.http_code, Some(429));
+        assert_eq!(retried.http_code, Some(200));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn retrieve_all_messages() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
 
         let response = mailjet.message(&MessageRequest::default()).unwrap().object.unwrap();
 
@@ -606,43 +2450,46 @@ mod test {
 
     #[test]
     fn retrieve_message_from_id() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+
+        let response = mailjet
+            .message_from_id(seed::MESSAGE_ID)
+            .unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.data.len(), 1);
+    }
+
+    #[test]
+    fn retrieve_unknown_message() {
+        let server = MockServer::start().unwrap();
 
-        let response = mailjet
-            .message_from_id(std::env::var("MJ_MESSAGE_ID").unwrap().parse().unwrap())
-            .unwrap().object.unwrap();
+        let response = server.client().message_from_id(1).unwrap();
 
-        assert_eq!(response.count, 1);
-        assert_eq!(response.data.len(), 1);
+        assert_eq!(response.http_code, Some(404));
     }
 
     #[test]
     fn retrieve_message_history_from_id() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
 
         let response = mailjet
-            .message_history_from_id(std::env::var("MJ_MESSAGE_ID").unwrap().parse().unwrap())
+            .message_history_from_id(seed::MESSAGE_ID)
             .unwrap().object.unwrap();
 
-        assert_eq!(response.count, 1);
-        assert_eq!(response.data.len(), 1);
+        assert!(response.count > 0);
+        assert_eq!(response.count as usize, response.data.len());
     }
 
     #[test]
     fn retrieve_all_message_information() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
 
         let search = MessageInformationRequest {
-            campaign_id: Some(std::env::var("MJ_CAMPAIGN_ID").unwrap().parse().unwrap()),
+            campaign_id: Some(seed::CAMPAIGN_ID),
             ..Default::default()
         };
 
@@ -654,13 +2501,11 @@ mod test {
 
     #[test]
     fn retrieve_message_information_from_id() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
 
         let response = mailjet
-            .message_information_from_id(std::env::var("MJ_MESSAGE_ID").unwrap().parse().unwrap())
+            .message_information_from_id(seed::MESSAGE_ID)
             .unwrap().object.unwrap();
 
         assert_eq!(response.count, 1);
@@ -669,12 +2514,9 @@ mod test {
 
     #[test]
     fn contact_create_and_delete() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
-        let mut rng = rand::thread_rng();
-        let email = format!("dummy.{}.{}@gmail.com", rng.gen::<i64>(), rng.gen::<i64>());
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let email = "dummy@example.com".to_string();
         let contact = ContactRequest {
             email: Some(email.clone()),
             ..Default::default()
@@ -684,19 +2526,38 @@ mod test {
         assert_eq!(response.count, 1);
         assert_eq!(response.data[0].email, email);
 
-        // Test delete only if you are in a GDPR country
-        if &std::env::var("MJ_CAN_DELETE_CONTACT").unwrap_or_default() == "1" {
-            let id = response.data[0].id;
-            assert!(mailjet.contact_delete(id).unwrap().object.unwrap());
-        }
+        let id = response.data[0].id;
+        assert!(mailjet.contact_delete(id).unwrap().object.unwrap());
+    }
+
+    #[test]
+    fn contact_create_without_email() {
+        let server = MockServer::start().unwrap();
+        let response = server
+            .client()
+            .contact_create(&ContactRequest::default())
+            .unwrap();
+
+        assert_eq!(response.http_code, Some(400));
+        assert!(response.raw_data.contains("Email"));
+    }
+
+    #[test]
+    fn contact_create_twice() {
+        let server = MockServer::start().unwrap();
+        let contact = ContactRequest {
+            email: Some(seed::CONTACT_EMAIL.to_string()),
+            ..Default::default()
+        };
+        let response = server.client().contact_create(&contact).unwrap();
+
+        assert_eq!(response.http_code, Some(400));
     }
 
     #[test]
     fn contact_get_all() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
         let response = mailjet
             .contact_search(&ContactSearchRequest::default())
             .unwrap().object.unwrap();
@@ -706,18 +2567,14 @@ mod test {
 
     #[test]
     fn contact_get_from_id() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
         let contact1 = mailjet
-            .contact_search_from_id_or_email(&ContactIdentifier::ContactId(
-                std::env::var("MJ_CONTACT_ID").unwrap().parse().unwrap(),
-            ))
+            .contact_search_from_id_or_email(&ContactIdentifier::ContactId(seed::CONTACT_ID))
             .unwrap().object.unwrap();
         let contact2 = mailjet
             .contact_search_from_id_or_email(&ContactIdentifier::ContactEmail(
-                std::env::var("MJ_CONTACT_EMAIL").unwrap(),
+                seed::CONTACT_EMAIL.to_string(),
             ))
             .unwrap().object.unwrap();
 
@@ -729,32 +2586,27 @@ mod test {
 
     #[test]
     fn contact_update() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
-        let mut rng = rand::thread_rng();
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
         let response = mailjet
             .contact_update(
-                &ContactIdentifier::ContactEmail(std::env::var("MJ_CONTACT_EMAIL").unwrap()),
+                &ContactIdentifier::ContactEmail(seed::CONTACT_EMAIL.to_string()),
                 &ContactRequest {
-                    name: Some(format!("Camille Nevermind {}", rng.gen::<i64>())),
+                    name: Some("Camille Nevermind".to_string()),
                     ..Default::default()
                 },
             )
             .unwrap().object.unwrap();
 
         assert_eq!(response.count, 1);
+        assert_eq!(response.data[0].name, "Camille Nevermind");
     }
 
     #[test]
     fn contacts_list_create_and_delete() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
-        let mut rng = rand::thread_rng();
-        let list_name = format!("List {}", rng.gen::<i64>());
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let list_name = "Crew".to_string();
         let contact_list = ContactsListRequest {
             name: Some(list_name.clone()),
             ..Default::default()
@@ -774,10 +2626,8 @@ mod test {
 
     #[test]
     fn contact_list_retrieve_all() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
         let response = mailjet
             .contacts_list_search(&ContactsListSearchRequest::default())
             .unwrap();
@@ -787,42 +2637,35 @@ mod test {
 
     #[test]
     fn contact_list_retrieve_from_identifier() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
         let response_id = mailjet
             .contacts_list_search_from_id_or_address(&ContactsListIdentifier::ListId(
-                std::env::var("MJ_CONTACTS_LIST_ID")
-                    .unwrap()
-                    .parse()
-                    .unwrap(),
+                seed::CONTACTS_LIST_ID,
+            ))
+            .unwrap();
+        let response_address = mailjet
+            .contacts_list_search_from_id_or_address(&ContactsListIdentifier::ListAddress(
+                seed::CONTACTS_LIST_ADDRESS.to_string(),
             ))
             .unwrap();
 
         assert_eq!(response_id.count, 1);
+        assert_eq!(response_address.data[0].id, seed::CONTACTS_LIST_ID);
     }
 
     #[test]
     fn contact_list_update() {
-        let mailjet = Mailjet::from_api_keys(
-            &std::env::var("MJ_KEY").unwrap(),
-            &std::env::var("MJ_SECRET").unwrap(),
-        );
-        let mut rng = rand::thread_rng();
-        let new_name = format!("List {}", rng.gen::<i64>());
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let new_name = "Frequent flyers".to_string();
         let request = ContactsListRequest {
             name: Some(new_name.clone()),
             ..Default::default()
         };
         let response = mailjet
             .contacts_list_update(
-                &ContactsListIdentifier::ListAddress(
-                    std::env::var("MJ_CONTACTS_LIST_ID")
-                        .unwrap()
-                        .parse()
-                        .unwrap(),
-                ),
+                &ContactsListIdentifier::ListAddress(seed::CONTACTS_LIST_ADDRESS.to_string()),
                 &request,
             )
             .unwrap();
@@ -830,4 +2673,518 @@ mod test {
         assert_eq!(response.count, 1);
         assert_eq!(response.data[0].name, new_name);
     }
+
+    #[test]
+    fn contact_statistics_retrieve_all() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let response = mailjet
+            .contact_statistics(&ContactStatisticsRequest::default())
+            .unwrap().object.unwrap();
+
+        assert!(response.count > 0);
+    }
+
+    #[test]
+    fn contact_statistics_retrieve_from_id() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let id = seed::CONTACT_ID;
+        let response = mailjet
+            .contact_statistics_from_id_or_email(&ContactIdentifier::ContactId(id))
+            .unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.data[0].contact_id, id);
+    }
+
+    #[test]
+    fn list_recipient_statistics_retrieve_from_list() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let request = ListRecipientStatisticsRequest {
+            contacts_list: Some(seed::CONTACTS_LIST_ID),
+            ..Default::default()
+        };
+        let response = mailjet.list_recipient_statistics(&request).unwrap().object.unwrap();
+
+        assert!(response.count > 0);
+        assert_eq!(response.count as usize, response.data.len());
+    }
+
+    #[test]
+    fn contacts_list_statistics_retrieve_from_identifier() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let id = seed::CONTACTS_LIST_ID;
+        let response = mailjet
+            .contacts_list_statistics_from_id_or_address(&ContactsListIdentifier::ListId(id))
+            .unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.data[0].id, id);
+    }
+
+    #[test]
+    fn sender_statistics_retrieve_all() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let response = mailjet
+            .sender_statistics(&SenderStatisticsRequest::default())
+            .unwrap().object.unwrap();
+
+        assert!(response.count > 0);
+    }
+
+    #[test]
+    fn sender_create_and_delete() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let email = "sender@example.com".to_string();
+        let request = SenderRequest {
+            email: Some(email.clone()),
+            email_type: Some(EmailType::Transactional),
+            ..Default::default()
+        };
+        let response = mailjet.sender_create(&request).unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.data[0].email, email);
+        assert_eq!(response.data[0].status, SenderStatus::Inactive);
+
+        let id = response.data[0].id;
+        assert!(mailjet
+            .sender_delete(&SenderIdentifier::SenderId(id))
+            .unwrap()
+            .object
+            .unwrap());
+    }
+
+    #[test]
+    fn sender_retrieve_all() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let response = mailjet
+            .sender_search(&SenderSearchRequest::default())
+            .unwrap().object.unwrap();
+
+        assert!(response.count > 0);
+    }
+
+    #[test]
+    fn sender_validate_on_verified_domain() {
+        let server = MockServer::start().unwrap();
+        let response = server
+            .client()
+            .sender_validate(&SenderIdentifier::SenderId(seed::SENDER_ID))
+            .unwrap()
+            .object
+            .unwrap();
+
+        assert_eq!(response.validation_method, "DNS");
+        assert!(response.errors.is_empty());
+    }
+
+    #[test]
+    fn dns_retrieve_and_check() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let response = mailjet
+            .dns_search(&DnsSearchRequest::default())
+            .unwrap().object.unwrap();
+
+        assert!(response.count > 0);
+
+        let id = response.data[0].id;
+        let check = mailjet
+            .dns_check(&DnsIdentifier::DnsId(id))
+            .unwrap().object.unwrap();
+
+        assert_eq!(check.count, 1);
+    }
+
+    #[test]
+    fn event_callback_url_create_update_and_delete() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let request = EventCallbackUrlRequest {
+            event_type: Some(EventType::Spam),
+            url: Some("https://example.com/mailjet/spam".to_string()),
+            version: Some(EventCallbackUrlVersion::Grouped),
+            ..Default::default()
+        };
+        let response = mailjet.event_callback_url_create(&request).unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.data[0].event_type, EventType::Spam);
+        assert_eq!(response.data[0].version, EventCallbackUrlVersion::Grouped);
+
+        let id = response.data[0].id;
+        let request = EventCallbackUrlRequest {
+            status: Some(EventCallbackUrlStatus::Dead),
+            ..Default::default()
+        };
+        let response = mailjet.event_callback_url_update(id, &request).unwrap().object.unwrap();
+
+        assert_eq!(response.data[0].status, EventCallbackUrlStatus::Dead);
+        assert!(mailjet.event_callback_url_delete(id).unwrap().object.unwrap());
+    }
+
+    #[test]
+    fn parse_route_create_and_delete() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let request = ParseRouteRequest {
+            url: Some("https://example.com/mailjet/inbound".to_string()),
+            email_type: Some(EmailType::Transactional),
+            ..Default::default()
+        };
+        let response = mailjet.parse_route_create(&request).unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+        assert_eq!(response.data[0].url, "https://example.com/mailjet/inbound");
+        assert_eq!(response.data[0].email_type, EmailType::Transactional);
+        assert!(!response.data[0].email.is_empty());
+        assert!(mailjet
+            .parse_route_delete(response.data[0].id)
+            .unwrap()
+            .object
+            .unwrap());
+    }
+
+    #[test]
+    fn sms_count_and_retrieve_all() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let count = mailjet
+            .sms_count(&SmsSearchRequest::default())
+            .unwrap().object.unwrap();
+        let response = mailjet
+            .sms_search(&SmsSearchRequest {
+                limit: Some(10),
+                ..Default::default()
+            })
+            .unwrap().object.unwrap();
+
+        assert!(count.count > 0);
+        assert!(response.data.len() as i64 <= count.count.min(10));
+    }
+
+    #[test]
+    fn sms_send_and_retrieve() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let request = SmsRequest {
+            from: "MJPilot".to_string(),
+            to: "+33600000000".to_string(),
+            text: "Have a nice SMS flight with Mailjet!".to_string(),
+        };
+        let sms = mailjet.sms_send(&request).unwrap().object.unwrap();
+        let response = mailjet.sms_search_from_id(&sms.id).unwrap().object.unwrap();
+
+        assert_eq!(response.to, "+33600000000");
+    }
+
+    #[test]
+    fn sms_requires_bearer_token() {
+        let server = MockServer::start().unwrap();
+        let mailjet = Mailjet::from_api_keys(MockServer::API_KEY, MockServer::API_SECRET)
+            .with_base_url(&server.base_url());
+
+        let response = mailjet.sms_count(&SmsSearchRequest::default()).unwrap();
+
+        assert_eq!(response.http_code, Some(401));
+    }
+
+    #[test]
+    fn sms_export_and_wait() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let request = SmsExportRequest {
+            from_ts: 1_700_000_000,
+            to_ts: 1_700_086_400,
+        };
+        let export = mailjet.sms_export_create(&request).unwrap().object.unwrap();
+        let export = mailjet
+            .sms_export_poll_until_complete(&export.id, &fast_polling())
+            .unwrap();
+
+        assert!(!export.url.is_empty());
+    }
+
+    #[test]
+    fn contact_exclude_unexclude_and_forget() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let email = "dummy@example.com".to_string();
+        let identifier = ContactIdentifier::ContactEmail(email.clone());
+        let contact = ContactRequest {
+            email: Some(email.clone()),
+            ..Default::default()
+        };
+        mailjet.contact_create(&contact).unwrap().object.unwrap();
+
+        let record = mailjet.exclude_contact(&identifier).unwrap();
+        assert!(record.success);
+        assert_eq!(record.emails, vec![email.clone()]);
+
+        let record = mailjet.unexclude_contact(&identifier).unwrap();
+        assert!(record.success);
+        assert_eq!(record.action, GdprAction::Unexclude);
+
+        let record = mailjet.forget_contact(&email).unwrap();
+        assert!(record.success);
+        assert_eq!(record.contact_ids.len(), 1);
+
+        let record = mailjet.exclude_contact(&identifier).unwrap();
+        assert!(!record.success);
+        assert_eq!(record.http_code, Some(404));
+
+        let record = mailjet.forget_contact(&email).unwrap();
+        assert!(!record.success);
+        assert_eq!(record.details, "Contact not found");
+
+        server.fail_next(503);
+
+        let record = mailjet.forget_contact(&email).unwrap();
+        assert!(!record.success);
+        assert_eq!(record.http_code, Some(503));
+        assert!(record.details.starts_with("Contact search failed"));
+    }
+
+    #[test]
+    fn contacts_exclude_in_bulk() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let record = mailjet.exclude_contacts(&["dummy@example.com"]).unwrap();
+        let job_id = record.job_id.unwrap();
+
+        let response = mailjet
+            .contact_manage_many_search_from_id(job_id)
+            .unwrap().object.unwrap();
+
+        assert_eq!(response.count, 1);
+    }
+
+    #[test]
+    fn contact_filter_create_update_and_delete() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let request = ContactFilterRequest {
+            name: Some("Under 35".to_string()),
+            expression: Some(SegmentExpression::lt("age", 35).to_string()),
+            ..Default::default()
+        };
+        let response = mailjet.contact_filter_create(&request).unwrap().object.unwrap();
+        let id = response.data[0].id;
+
+        let update = ContactFilterRequest {
+            description: Some("Updated".to_string()),
+            ..Default::default()
+        };
+        let response = mailjet.contact_filter_update(id, &update).unwrap().object.unwrap();
+        assert_eq!(response.data[0].description, "Updated");
+
+        assert!(mailjet.contact_filter_delete(id).unwrap().object.unwrap());
+    }
+
+    #[test]
+    fn api_key_retrieve_all_and_derive_sub_account() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let response = mailjet
+            .api_key_search(&ApiKeySearchRequest::default())
+            .unwrap().object.unwrap();
+
+        assert!(!response.data.is_empty());
+
+        let client = mailjet
+            .sub_account_from_id(seed::SUB_ACCOUNT_API_KEY_ID)
+            .unwrap()
+            .unwrap();
+        let user = client.user_search().unwrap();
+
+        assert_eq!(client.api_key, "mock_sub_api_key");
+        assert_eq!(user.http_code, Some(200));
+    }
+
+    #[test]
+    fn profile_and_user_retrieve() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let profile = mailjet.profile_search().unwrap().object.unwrap();
+        let user = mailjet.user_search().unwrap().object.unwrap();
+
+        assert_eq!(profile.data[0].user_id, user.data[0].id);
+        assert_eq!(user.data[0].id, seed::USER_ID);
+    }
+
+    #[test]
+    fn contacts_exclude_in_bulk_and_wait() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client();
+        let record = mailjet.exclude_contacts(&["dummy@example.com"]).unwrap();
+
+        let job = mailjet
+            .contact_manage_many_poll_until_complete(record.job_id.unwrap(), &fast_polling())
+            .unwrap();
+
+        assert_eq!(job.status, "Completed");
+    }
+
+    #[test]
+    fn batch_job_wait() {
+        let server = MockServer::start().unwrap();
+        let job = server
+            .client()
+            .batch_job_poll_until_complete(seed::BATCH_JOB_ID, &fast_polling())
+            .unwrap();
+
+        assert_eq!(job.id, seed::BATCH_JOB_ID);
+    }
+
+    #[test]
+    fn dry_run_in_sandbox() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client().with_dry_run_mode(DryRunMode::Sandbox);
+        let message = Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            text_part: "Hello World!".to_string(),
+            ..Default::default()
+        };
+        let request = SendRequest {
+            messages: vec![message],
+            ..Default::default()
+        };
+
+        let response = mailjet.send(&request).unwrap().object.unwrap();
+
+        assert_eq!(response.messages[0].status, "success");
+        assert_eq!(server.requests()[0].json()["SandboxMode"], true);
+        assert!(mailjet
+            .send_v3(&SendV3Request::default())
+            .err()
+            .unwrap()
+            .to_string()
+            .contains("can't be sandboxed"));
+        assert!(mailjet.sms_send(&SmsRequest::default()).is_err());
+
+        let error = mailjet
+            .contact_create(&ContactRequest {
+                email: Some("dummy@example.com".to_string()),
+                ..Default::default()
+            })
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<DryRunError>(),
+            Some(DryRunError::ReadOnly(RequestType::Post, _))
+        ));
+        assert_eq!(server.requests().len(), 1);
+        assert!(mailjet.message_from_id(seed::MESSAGE_ID).unwrap().object.is_some());
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn dry_run_without_network() {
+        let server = MockServer::start().unwrap();
+        let mailjet = server.client().with_dry_run_mode(DryRunMode::NoNetwork);
+        let valid = Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            text_part: "Hello World!".to_string(),
+            custom_id: Some("flight-1".to_string()),
+            ..Default::default()
+        };
+        let invalid = Message {
+            to: vec![EmailAddress::from_email("passenger2@mailjet.com")],
+            ..Default::default()
+        };
+
+        let response = mailjet
+            .send(&SendRequest {
+                messages: vec![valid],
+                ..Default::default()
+            })
+            .unwrap();
+        let message = &response.object.unwrap().messages[0];
+
+        assert_eq!(response.http_code, Some(200));
+        assert_eq!(message.custom_id, "flight-1");
+        assert_eq!(message.to[0].email, "passenger1@mailjet.com");
+
+        let response = mailjet
+            .send(&SendRequest {
+                messages: vec![invalid],
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert_eq!(response.http_code, Some(400));
+        assert_eq!(response.object.unwrap().messages[0].errors.len(), 2);
+        assert!(mailjet
+            .contact_create(&ContactRequest {
+                email: Some("dummy@example.com".to_string()),
+                ..Default::default()
+            })
+            .is_err());
+        assert!(mailjet
+            .sub_account_from_id(seed::SUB_ACCOUNT_API_KEY_ID)
+            .is_err());
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn recipient_policy_refuses_before_posting() {
+        let server = MockServer::start().unwrap();
+        let policy = RecipientPolicy::allowlist().allow_domain("example.com");
+        let mailjet = server.client().with_recipient_policy(policy);
+        let message = Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("qa@example.com")],
+            text_part: "Hello World!".to_string(),
+            ..Default::default()
+        };
+        let request = SendRequest {
+            messages: vec![message],
+            globals: Some(Globals {
+                bcc: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let error = mailjet.send(&request).err().unwrap();
+
+        assert_eq!(error.to_string(), "Recipients not allowed: passenger1@mailjet.com");
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn recipient_policy_redirects() {
+        let server = MockServer::start().unwrap();
+        let policy = RecipientPolicy::redirect_to(EmailAddress::from_email("staging@example.com"));
+        let mailjet = server.client().with_recipient_policy(policy);
+        let message = Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            text_part: "Hello World!".to_string(),
+            ..Default::default()
+        };
+        let request = SendRequest {
+            messages: vec![message],
+            ..Default::default()
+        };
+
+        let response = mailjet.send(&request).unwrap().object.unwrap();
+        let sent = server.requests()[0].json();
+
+        assert_eq!(response.messages[0].to[0].email, "staging@example.com");
+        assert_eq!(
+            sent["Messages"][0]["Headers"][RecipientPolicy::ORIGINAL_TO_HEADER],
+            "passenger1@mailjet.com"
+        );
+    }
 }
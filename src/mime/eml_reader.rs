@@ -0,0 +1,394 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::encoding::{decode_charset, decode_quoted_printable};
+use super::headers::{
+    decode_header, header, header_parameter, parse_addresses, parse_headers, Headers,
+};
+use crate::data::{Attachment, EmailAddress, EmlError, Message};
+use base64::{engine::general_purpose::STANDARD, Engine};
+
+/// The headers read into the message's fields or describing how it was transported, which
+/// aren't kept as custom headers
+const IGNORED_HEADERS: [&str; 17] = [
+    "from",
+    "sender",
+    "reply-to",
+    "to",
+    "cc",
+    "bcc",
+    "subject",
+    "date",
+    "message-id",
+    "mime-version",
+    "received",
+    "return-path",
+    "delivered-to",
+    "dkim-signature",
+    "domainkey-signature",
+    "authentication-results",
+    "x-received",
+];
+
+/// Splits a MIME entity into its headers and its body
+///
+/// # Parameters
+///
+/// * `entity`: The entity, a whole message or a part
+fn split_entity(entity: &[u8]) -> Result<(Headers, &[u8]), EmlError> {
+    let end = (0..entity.len()).find_map(|i| match &entity[i..] {
+        [b'\n', b'\n', ..] => Some((i + 1, i + 2)),
+        [b'\n', b'\r', b'\n', ..] => Some((i + 1, i + 3)),
+        _ => None,
+    });
+    let (head, body) = match end {
+        _ if entity.starts_with(b"\r\n") => (&entity[..0], &entity[2..]),
+        _ if entity.starts_with(b"\n") => (&entity[..0], &entity[1..]),
+        Some((head_end, body_start)) => (&entity[..head_end], &entity[body_start..]),
+        None => (entity, &entity[entity.len()..]),
+    };
+
+    Ok((parse_headers(&String::from_utf8_lossy(head))?, body))
+}
+
+/// Splits the body of a multipart part into its parts, the preamble and epilogue are ignored
+///
+/// # Parameters
+///
+/// * `body`: The multipart's body
+/// * `boundary`: The multipart's boundary
+fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let delimiter = format!("--{}", boundary);
+    let mut parts = Vec::new();
+    let mut start = None;
+    let mut position = 0;
+
+    for line in body.split_inclusive(|&byte| byte == b'\n') {
+        let content = line.trim_ascii_end();
+
+        if let Some(rest) = content.strip_prefix(delimiter.as_bytes()) {
+            if rest.is_empty() || rest == b"--" {
+                if let Some(start) = start {
+                    // The line break before a delimiter belongs to the delimiter
+                    let part: &[u8] = &body[start..position];
+                    let part = part
+                        .strip_suffix(b"\r\n")
+                        .or_else(|| part.strip_suffix(b"\n"))
+                        .unwrap_or(part);
+
+                    parts.push(part);
+                }
+
+                if rest == b"--" {
+                    return parts;
+                }
+
+                start = Some(position + line.len());
+            }
+        }
+
+        position += line.len();
+    }
+
+    if let Some(start) = start {
+        parts.push(&body[start..]);
+    }
+
+    parts
+}
+
+/// Reads a MIME part into a message's parts and attachments
+///
+/// # Parameters
+///
+/// * `headers`: The part's headers
+/// * `body`: The part's encoded body
+/// * `message`: The message being read
+fn read_part(
+    headers: &[(String, String)],
+    body: &[u8],
+    message: &mut Message,
+) -> Result<(), EmlError> {
+    let content_type = header(headers, "Content-Type").unwrap_or("text/plain");
+    let mime_type = content_type
+        .split(';')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+
+    if mime_type.starts_with("multipart/") {
+        let boundary = header_parameter(content_type, "boundary")
+            .ok_or_else(|| EmlError::MissingBoundary(mime_type.clone()))?;
+
+        for part in split_multipart(body, &boundary) {
+            let (part_headers, part_body) = split_entity(part)?;
+
+            read_part(&part_headers, part_body, message)?;
+        }
+
+        return Ok(());
+    }
+
+    let encoding = header(headers, "Content-Transfer-Encoding")
+        .unwrap_or_default()
+        .trim()
+        .to_ascii_lowercase();
+    let content = match encoding.as_str() {
+        "base64" => {
+            let body: Vec<u8> = body
+                .iter()
+                .copied()
+                .filter(|byte| !byte.is_ascii_whitespace())
+                .collect();
+
+            STANDARD
+                .decode(body)
+                .map_err(|_| EmlError::InvalidBase64(mime_type.clone()))?
+        }
+        "quoted-printable" => decode_quoted_printable(body),
+        _ => body.to_vec(),
+    };
+    let disposition = header(headers, "Content-Disposition");
+    let disposition_type = disposition
+        .and_then(|d| d.split(';').next())
+        .map(|d| d.trim().to_ascii_lowercase());
+    let filename = disposition
+        .and_then(|d| header_parameter(d, "filename"))
+        .or_else(|| header_parameter(content_type, "name"))
+        .map(|filename| decode_header(&filename));
+    let content_id = header(headers, "Content-ID").map(|id| {
+        id.trim()
+            .trim_start_matches('<')
+            .trim_end_matches('>')
+            .to_string()
+    });
+    let text = || {
+        let charset = header_parameter(content_type, "charset").unwrap_or_default();
+
+        decode_charset(&content, &charset).replace("\r\n", "\n")
+    };
+
+    // Bodies are often marked as inline, only a filename tells them apart from attachments
+    let body_part =
+        filename.is_none() && matches!(disposition_type.as_deref(), None | Some("inline"));
+
+    match mime_type.as_str() {
+        "text/plain" if body_part && message.text_part.is_empty() => {
+            message.text_part = text();
+        }
+        "text/html" if body_part && message.html_part.is_empty() => {
+            message.html_part = text();
+        }
+        _ => {
+            let inline = content_id.is_some() && disposition_type.as_deref() != Some("attachment");
+            let attachment = Attachment {
+                content_type: mime_type,
+                filename: filename
+                    .or_else(|| content_id.clone())
+                    .unwrap_or_else(|| "attachment".to_string()),
+                base64_content: STANDARD.encode(&content),
+                content_id: content_id.filter(|_| inline),
+            };
+
+            match inline {
+                true => message.inlined_attachments.push(attachment),
+                false => message.attachments.push(attachment),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Reads an RFC 5322 message with MIME parts into a message
+///
+/// # Parameters
+///
+/// * `eml`: The message, the content of an `.eml` file
+pub(crate) fn read_eml(eml: &[u8]) -> Result<Message, EmlError> {
+    let eml = eml
+        .strip_suffix(b"\r\n")
+        .or_else(|| eml.strip_suffix(b"\n"))
+        .unwrap_or(eml);
+    let (headers, body) = split_entity(eml)?;
+    let addresses = |name: &str| {
+        header(&headers, name)
+            .map(parse_addresses)
+            .unwrap_or_default()
+    };
+    let first_address = |name: &str| addresses(name).into_iter().next();
+    let mut message = Message {
+        from: first_address("From").unwrap_or_else(EmailAddress::default),
+        sender: first_address("Sender"),
+        reply_to: first_address("Reply-To"),
+        to: addresses("To"),
+        cc: addresses("Cc"),
+        bcc: addresses("Bcc"),
+        subject: header(&headers, "Subject")
+            .map(decode_header)
+            .unwrap_or_default(),
+        ..Default::default()
+    };
+
+    for (name, value) in &headers {
+        let lowercase = name.to_ascii_lowercase();
+
+        if !IGNORED_HEADERS.contains(&lowercase.as_str())
+            && !lowercase.starts_with("content-")
+            && !lowercase.starts_with("arc-")
+        {
+            let value = decode_header(value);
+
+            // Repeated headers are joined, under the name they first appeared with
+            match message
+                .headers
+                .iter_mut()
+                .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            {
+                Some((_, joined)) => {
+                    joined.push_str(", ");
+                    joined.push_str(&value);
+                }
+                None => {
+                    message.headers.insert(name.clone(), value);
+                }
+            }
+        }
+    }
+
+    read_part(&headers, body, &mut message)?;
+
+    Ok(message)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let message = Message {
+            from: EmailAddress::from_email_and_name("pilot@mailjet.com", "Mailjet Pilot"),
+            to: vec![
+                EmailAddress::from_email_and_name("passenger1@mailjet.com", "Zoë"),
+                EmailAddress::from_email("passenger2@mailjet.com"),
+            ],
+            reply_to: Some(EmailAddress::from_email("tower@mailjet.com")),
+            subject: "Your email flight plan ✈".to_string(),
+            text_part: "Welcome aboard\nSee you soon".to_string(),
+            html_part: "<img src=\"cid:logo\"> Welcome aboard".to_string(),
+            attachments: vec![Attachment {
+                content_type: "text/plain".to_string(),
+                filename: "plan \\ \"v2\".txt".to_string(),
+                base64_content: "SXQncyBGcmlkYXkh".to_string(),
+                content_id: None,
+            }],
+            inlined_attachments: vec![Attachment {
+                content_type: "image/png".to_string(),
+                filename: "logo.png".to_string(),
+                base64_content: "iVBORw0KGgo=".to_string(),
+                content_id: Some("logo".to_string()),
+            }],
+            headers: [("X-Flight".to_string(), "MJ1".to_string())].into(),
+            ..Default::default()
+        };
+        let read = read_eml(message.to_eml().unwrap().as_bytes()).unwrap();
+
+        assert_eq!(read, message);
+    }
+
+    #[test]
+    fn legacy_message() {
+        let eml = "Received: from smtp.example.com\r\n\
+            From: Legacy App <app@example.com>\r\n\
+            To: undisclosed-recipients:;\r\n\
+            Bcc: jane@example.com\r\n\
+            Subject: =?ISO-8859-1?Q?R=E9servation?=\r\n\
+            Content-Type: multipart/alternative; boundary=\"b1\"\r\n\
+            \r\n\
+            This is a multipart message\r\n\
+            --b1\r\n\
+            Content-Type: text/plain; charset=iso-8859-1\r\n\
+            Content-Transfer-Encoding: quoted-printable\r\n\
+            Content-Disposition: inline\r\n\
+            \r\n\
+            R=E9servation confirm=\r\n\
+            =E9e\r\n\
+            --b1\r\n\
+            Content-Type: text/html; charset=utf-8\r\n\
+            Content-Transfer-Encoding: base64\r\n\
+            Content-Disposition: inline\r\n\
+            \r\n\
+            PHA+UsOpc2VydmF0aW9uPC9wPg==\r\n\
+            --b1--\r\n";
+        let message = read_eml(eml.as_bytes()).unwrap();
+
+        assert_eq!(message.from.name, "Legacy App");
+        assert!(message.to.is_empty());
+        assert_eq!(message.bcc[0].email, "jane@example.com");
+        assert_eq!(message.subject, "Réservation");
+        assert_eq!(message.text_part, "Réservation confirmée");
+        assert_eq!(message.html_part, "<p>Réservation</p>");
+        assert!(message.headers.is_empty());
+        assert!(message.attachments.is_empty());
+        assert!(message.inlined_attachments.is_empty());
+    }
+
+    #[test]
+    fn parameters_and_repeated_headers() {
+        let eml = "From: app@example.com\r\n\
+            X-Tag: boarding\r\n\
+            x-tag: priority\r\n\
+            Content-Type: multipart/mixed; boundary=\"b1\"\r\n\
+            \r\n\
+            --b1\r\n\
+            Content-Type: text/plain\r\n\
+            \r\n\
+            Welcome aboard\r\n\
+            --b1\r\n\
+            Content-Type: application/pdf\r\n\
+            Content-Disposition: attachment; filename=\"plan;1.pdf\"\r\n\
+            \r\n\
+            PDF\r\n\
+            --b1\r\n\
+            Content-Type: application/pdf\r\n\
+            Content-Disposition: attachment;\r\n filename*=UTF-8''r%C3%A9servation.pdf\r\n\
+            \r\n\
+            PDF\r\n\
+            --b1--\r\n";
+        let message = read_eml(eml.as_bytes()).unwrap();
+
+        assert_eq!(message.text_part, "Welcome aboard");
+        assert_eq!(message.attachments[0].filename, "plan;1.pdf");
+        assert_eq!(message.attachments[1].filename, "réservation.pdf");
+        assert_eq!(message.headers["X-Tag"], "boarding, priority");
+        assert_eq!(message.headers.len(), 1);
+    }
+
+    #[test]
+    fn errors() {
+        assert!(matches!(
+            read_eml(b"Content-Type: multipart/mixed\r\n\r\nHello"),
+            Err(EmlError::MissingBoundary(_))
+        ));
+        assert!(matches!(
+            read_eml(b"Content-Transfer-Encoding: base64\r\n\r\n!!!"),
+            Err(EmlError::InvalidBase64(_))
+        ));
+    }
+}
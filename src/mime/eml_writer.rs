@@ -0,0 +1,341 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::encoding::{encode_header, encode_quoted_printable, format_date, wrap_base64};
+use crate::data::{Attachment, EmailAddress, EmlError, Message};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A MIME part being written
+struct Part {
+    /// The part's headers, in order
+    headers: Vec<(String, String)>,
+
+    /// The part's encoded body
+    body: String,
+}
+
+impl Part {
+    /// Creates a quoted-printable text part
+    ///
+    /// # Parameters
+    ///
+    /// * `subtype`: The text's subtype, like `plain` or `html`
+    /// * `text`: The text
+    fn text(subtype: &str, text: &str) -> Self {
+        Self {
+            headers: vec![
+                (
+                    "Content-Type".to_string(),
+                    format!("text/{}; charset=utf-8", subtype),
+                ),
+                (
+                    "Content-Transfer-Encoding".to_string(),
+                    "quoted-printable".to_string(),
+                ),
+            ],
+            body: encode_quoted_printable(text),
+        }
+    }
+
+    /// Creates an attachment part
+    ///
+    /// # Parameters
+    ///
+    /// * `attachment`: The attachment, its content is already base64 encoded
+    /// * `disposition`: `attachment` or `inline`
+    fn attachment(attachment: &Attachment, disposition: &str) -> Self {
+        let filename = encode_header(&attachment.filename)
+            .replace('\\', "\\\\")
+            .replace('"', "\\\"");
+        let mut headers = vec![
+            (
+                "Content-Type".to_string(),
+                format!("{}; name=\"{}\"", attachment.content_type, filename),
+            ),
+            (
+                "Content-Transfer-Encoding".to_string(),
+                "base64".to_string(),
+            ),
+            (
+                "Content-Disposition".to_string(),
+                format!("{}; filename=\"{}\"", disposition, filename),
+            ),
+        ];
+
+        // Like Mailjet, an inlined attachment without content ID is referenced by its filename
+        let content_id = match (&attachment.content_id, disposition) {
+            (Some(content_id), _) => Some(content_id),
+            (None, "inline") => Some(&attachment.filename),
+            (None, _) => None,
+        };
+
+        if let Some(content_id) = content_id {
+            headers.push(("Content-ID".to_string(), format!("<{}>", content_id)));
+        }
+
+        Self {
+            headers,
+            body: wrap_base64(&attachment.base64_content),
+        }
+    }
+
+    /// Wraps parts in a multipart part, a single part is returned as it is
+    ///
+    /// # Parameters
+    ///
+    /// * `subtype`: The multipart's subtype, like `mixed` or `alternative`
+    /// * `parts`: The parts
+    fn multipart(subtype: &str, mut parts: Vec<Part>) -> Self {
+        if parts.len() == 1 {
+            return parts.remove(0);
+        }
+
+        // Quoted-printable and base64 contents can't contain "=_", so the boundary never
+        // appears in a part, and each subtype is used once per message
+        let boundary = format!("=_mailjet_{}", subtype);
+        let mut body = String::new();
+
+        for part in parts {
+            body.push_str(&format!("--{}\r\n{}", boundary, part.write()));
+        }
+
+        body.push_str(&format!("--{}--", boundary));
+
+        Self {
+            headers: vec![(
+                "Content-Type".to_string(),
+                format!("multipart/{}; boundary=\"{}\"", subtype, boundary),
+            )],
+            body,
+        }
+    }
+
+    /// Writes the part's headers and body
+    fn write(&self) -> String {
+        let headers: String = self
+            .headers
+            .iter()
+            .map(|(name, value)| format!("{}: {}\r\n", name, value))
+            .collect();
+
+        format!("{}\r\n{}\r\n", headers, self.body)
+    }
+}
+
+/// Formats a mailbox, encoding its name if needed
+///
+/// # Parameters
+///
+/// * `address`: The address
+fn mailbox(address: &EmailAddress) -> String {
+    match address.name.is_ascii() {
+        true => address.to_mailbox(),
+        false => format!("{} <{}>", encode_header(&address.name), address.email),
+    }
+}
+
+/// Returns whether a header name is an RFC 5322 field name, printable ASCII without `:`
+///
+/// # Parameters
+///
+/// * `name`: The header's name
+fn is_field_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .bytes()
+            .all(|byte| matches!(byte, b'!'..=b'9' | b';'..=b'~'))
+}
+
+/// Writes a message as an RFC 5322 message with MIME parts
+///
+/// Fails if a custom header's name could break the header block
+///
+/// # Parameters
+///
+/// * `message`: The message
+pub(crate) fn write_eml(message: &Message) -> Result<String, EmlError> {
+    if let Some(name) = message.headers.keys().find(|name| !is_field_name(name)) {
+        return Err(EmlError::InvalidHeaderName(name.clone()));
+    }
+
+    let mut headers = Vec::new();
+    let mut add_addresses = |name: &str, addresses: &[EmailAddress]| {
+        if !addresses.is_empty() {
+            let addresses: Vec<String> = addresses.iter().map(mailbox).collect();
+
+            headers.push((name.to_string(), addresses.join(",\r\n ")));
+        }
+    };
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default();
+
+    add_addresses("From", std::slice::from_ref(&message.from));
+    add_addresses("Sender", message.sender.as_slice());
+    add_addresses("Reply-To", message.reply_to.as_slice());
+    add_addresses("To", &message.to);
+    add_addresses("Cc", &message.cc);
+    add_addresses("Bcc", &message.bcc);
+    headers.insert(0, ("Date".to_string(), format_date(timestamp)));
+    headers.push(("Subject".to_string(), encode_header(&message.subject)));
+
+    let mut custom_headers: Vec<_> = message.headers.iter().collect();
+
+    custom_headers.sort();
+    headers.extend(
+        custom_headers
+            .into_iter()
+            .map(|(name, value)| (name.clone(), encode_header(value))),
+    );
+    headers.push(("MIME-Version".to_string(), "1.0".to_string()));
+
+    let mut alternatives = Vec::new();
+
+    if !message.text_part.is_empty() || message.html_part.is_empty() {
+        alternatives.push(Part::text("plain", &message.text_part));
+    }
+
+    if !message.html_part.is_empty() {
+        alternatives.push(Part::text("html", &message.html_part));
+    }
+
+    let mut related = vec![Part::multipart("alternative", alternatives)];
+
+    related.extend(
+        message
+            .inlined_attachments
+            .iter()
+            .map(|attachment| Part::attachment(attachment, "inline")),
+    );
+
+    let mut mixed = vec![Part::multipart("related", related)];
+
+    mixed.extend(
+        message
+            .attachments
+            .iter()
+            .map(|attachment| Part::attachment(attachment, "attachment")),
+    );
+
+    let mut body = Part::multipart("mixed", mixed);
+
+    headers.append(&mut body.headers);
+    body.headers = headers;
+    Ok(body.write())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn simple_message() {
+        let message = Message {
+            from: EmailAddress::from_email_and_name("pilot@mailjet.com", "Mailjet Pilot"),
+            to: vec![EmailAddress::from_email_and_name(
+                "passenger1@mailjet.com",
+                "Zoë",
+            )],
+            subject: "Your email flight plan!".to_string(),
+            text_part: "Welcome aboard".to_string(),
+            ..Default::default()
+        };
+        let eml = write_eml(&message).unwrap();
+
+        assert!(eml.starts_with("Date: "));
+        assert!(eml.contains("From: \"Mailjet Pilot\" <pilot@mailjet.com>\r\n"));
+        assert!(eml.contains("To: =?UTF-8?Q?Zo=C3=AB?= <passenger1@mailjet.com>\r\n"));
+        assert!(eml.contains("Subject: Your email flight plan!\r\n"));
+        assert!(eml.contains("Content-Type: text/plain; charset=utf-8\r\n"));
+        assert!(eml.ends_with("\r\n\r\nWelcome aboard\r\n"));
+        assert!(!eml.contains("multipart"));
+    }
+
+    #[test]
+    fn multipart_message() {
+        let attachment = |filename: &str, content_id: Option<&str>| Attachment {
+            content_type: "image/png".to_string(),
+            filename: filename.to_string(),
+            base64_content: "iVBORw0KGgo=".to_string(),
+            content_id: content_id.map(str::to_string),
+        };
+        let message = Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            reply_to: Some(EmailAddress::from_email("tower@mailjet.com")),
+            text_part: "Welcome aboard".to_string(),
+            html_part: "<img src=\"cid:logo\"> Welcome aboard".to_string(),
+            attachments: vec![attachment("plan.png", None)],
+            inlined_attachments: vec![attachment("logo.png", Some("logo"))],
+            headers: [("X-Flight".to_string(), "MJ1".to_string())].into(),
+            ..Default::default()
+        };
+        let eml = write_eml(&message).unwrap();
+        let position = |text: &str| eml.find(text).unwrap();
+
+        assert!(eml.contains("Reply-To: tower@mailjet.com\r\n"));
+        assert!(eml.contains("X-Flight: MJ1\r\n"));
+        assert!(eml.contains("Content-Type: multipart/mixed; boundary=\"=_mailjet_mixed\"\r\n"));
+        assert!(eml.contains(
+            "Content-Disposition: inline; filename=\"logo.png\"\r\nContent-ID: <logo>\r\n"
+        ));
+        assert!(eml.contains("Content-Disposition: attachment; filename=\"plan.png\"\r\n"));
+        assert!(position("multipart/related") < position("multipart/alternative"));
+        assert!(position("text/plain") < position("text/html"));
+        assert!(position("text/html") < position("logo.png"));
+        assert!(position("logo.png") < position("plan.png"));
+        assert!(eml.ends_with("--=_mailjet_mixed--\r\n"));
+    }
+
+    #[test]
+    fn inline_without_content_id() {
+        let message = Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            html_part: "<img src=\"cid:logo.png\"> Welcome aboard".to_string(),
+            inlined_attachments: vec![Attachment {
+                content_type: "image/png".to_string(),
+                filename: "logo.png".to_string(),
+                base64_content: "iVBORw0KGgo=".to_string(),
+                content_id: None,
+            }],
+            ..Default::default()
+        };
+        let eml = write_eml(&message).unwrap();
+
+        assert!(eml.contains(
+            "Content-Disposition: inline; filename=\"logo.png\"\r\nContent-ID: <logo.png>\r\n"
+        ));
+    }
+
+    #[test]
+    fn invalid_header_names() {
+        for name in ["X-Flight: MJ1\r\nBcc", "X-Flight\r\n", "X Flight", ""] {
+            let message = Message {
+                text_part: "Welcome aboard".to_string(),
+                headers: [(name.to_string(), "MJ1".to_string())].into(),
+                ..Default::default()
+            };
+
+            assert!(matches!(
+                write_eml(&message),
+                Err(EmlError::InvalidHeaderName(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,297 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The maximum length of an encoded line, without its line break
+const MAX_LINE_LENGTH: usize = 76;
+
+/// The maximum length of a header line allowed by RFC 5322, without its line break
+const MAX_HEADER_LINE_LENGTH: usize = 998;
+
+/// Encodes a text with the quoted-printable encoding, lines end with CRLF
+///
+/// # Parameters
+///
+/// * `text`: The text
+pub(crate) fn encode_quoted_printable(text: &str) -> String {
+    let mut output = String::new();
+
+    for (i, line) in text.split('\n').enumerate() {
+        let line = line.strip_suffix('\r').unwrap_or(line);
+        let bytes = line.as_bytes();
+        let mut length = 0;
+
+        if i > 0 {
+            output.push_str("\r\n");
+        }
+
+        for (j, &byte) in bytes.iter().enumerate() {
+            let last = j + 1 == bytes.len();
+            let encoded = match byte {
+                b' ' | b'\t' if !last => (byte as char).to_string(),
+                b'!'..=b'<' | b'>'..=b'~' => (byte as char).to_string(),
+                _ => format!("={:02X}", byte),
+            };
+
+            // Keeps room for the soft line break's '='
+            if length + encoded.len() > MAX_LINE_LENGTH - 1 {
+                output.push_str("=\r\n");
+                length = 0;
+            }
+
+            output.push_str(&encoded);
+            length += encoded.len();
+        }
+    }
+
+    output
+}
+
+/// Decodes a quoted-printable text, soft line breaks are removed
+///
+/// # Parameters
+///
+/// * `text`: The encoded text
+pub(crate) fn decode_quoted_printable(text: &[u8]) -> Vec<u8> {
+    let mut output = Vec::with_capacity(text.len());
+    let mut i = 0;
+
+    while i < text.len() {
+        match (text[i], text.get(i + 1..i + 3)) {
+            (b'=', Some(b"\r\n")) => i += 3,
+            (b'=', Some([b'\n', _])) => i += 2,
+            (b'=', Some(hex)) if hex.iter().all(u8::is_ascii_hexdigit) => {
+                let hex = std::str::from_utf8(hex).unwrap_or_default();
+
+                output.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+                i += 3;
+            }
+            (b'=', None) if text.get(i + 1) == Some(&b'\n') => i += 2,
+            (byte, _) => {
+                output.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    output
+}
+
+/// Decodes text in a charset, Latin-1 is supported along with UTF-8
+///
+/// Other charsets are read as UTF-8, invalid characters being replaced
+///
+/// # Parameters
+///
+/// * `bytes`: The encoded text
+/// * `charset`: The charset's name, like `utf-8` or `iso-8859-1`
+pub(crate) fn decode_charset(bytes: &[u8], charset: &str) -> String {
+    match charset.to_ascii_lowercase().as_str() {
+        "iso-8859-1" | "latin1" | "latin-1" => bytes.iter().map(|&byte| byte as char).collect(),
+        _ => String::from_utf8_lossy(bytes).into_owned(),
+    }
+}
+
+/// Folds a plain ASCII header value before its spaces to keep lines short
+///
+/// Returns `None` if a line is still too long, the value has too few spaces to be folded
+///
+/// # Parameters
+///
+/// * `text`: The header value
+fn fold_header(text: &str) -> Option<String> {
+    let mut output = String::new();
+    let mut length = 0;
+
+    for (i, word) in text.split(' ').enumerate() {
+        if i > 0 {
+            // Lines can't be blank, so empty words stay with the previous one
+            if length > 0 && !word.is_empty() && length + 1 + word.len() > MAX_LINE_LENGTH {
+                output.push_str("\r\n");
+                length = 0;
+            }
+
+            output.push(' ');
+            length += 1;
+        }
+
+        output.push_str(word);
+        length += word.len();
+
+        // Keeps room for the header's name on the first line
+        if length > MAX_HEADER_LINE_LENGTH - MAX_LINE_LENGTH {
+            return None;
+        }
+    }
+
+    Some(output)
+}
+
+/// Encodes a header value, folded to keep lines short
+///
+/// Values that aren't plain ASCII, or are too long to be folded at their spaces like compact
+/// JSON, are encoded as RFC 2047 encoded words
+///
+/// # Parameters
+///
+/// * `text`: The header value
+pub(crate) fn encode_header(text: &str) -> String {
+    if text.chars().all(|c| c.is_ascii() && !c.is_ascii_control()) {
+        if let Some(folded) = fold_header(text) {
+            return folded;
+        }
+    }
+
+    let mut words = Vec::new();
+    let mut word = String::new();
+
+    for c in text.chars() {
+        let mut buffer = [0; 4];
+        let encoded: String = match c {
+            ' ' => "_".to_string(),
+            'a'..='z' | 'A'..='Z' | '0'..='9' | '!' | '*' | '+' | '-' | '/' => c.to_string(),
+            _ => c
+                .encode_utf8(&mut buffer)
+                .bytes()
+                .map(|byte| format!("={:02X}", byte))
+                .collect(),
+        };
+
+        // An encoded word is at most 75 characters long, `=?UTF-8?Q?` and `?=` included
+        if word.len() + encoded.len() > 75 - 12 {
+            words.push(format!("=?UTF-8?Q?{}?=", word));
+            word.clear();
+        }
+
+        word.push_str(&encoded);
+    }
+
+    words.push(format!("=?UTF-8?Q?{}?=", word));
+    words.join("\r\n ")
+}
+
+/// Splits base64 content into lines, ignoring its existing whitespaces
+///
+/// # Parameters
+///
+/// * `content`: The base64 content
+pub(crate) fn wrap_base64(content: &str) -> String {
+    let content: String = content.chars().filter(|c| !c.is_whitespace()).collect();
+
+    content
+        .as_bytes()
+        .chunks(MAX_LINE_LENGTH)
+        .map(|line| String::from_utf8_lossy(line).into_owned())
+        .collect::<Vec<_>>()
+        .join("\r\n")
+}
+
+/// Formats a date like `Mon, 19 Oct 2026 08:30:00 +0000`
+///
+/// # Parameters
+///
+/// * `timestamp`: The number of seconds since the UNIX epoch
+pub(crate) fn format_date(timestamp: u64) -> String {
+    /// The days of the week, starting with the UNIX epoch's
+    const DAYS: [&str; 7] = ["Thu", "Fri", "Sat", "Sun", "Mon", "Tue", "Wed"];
+    /// The months, starting with March as in the civil date algorithm
+    const MONTHS: [&str; 12] = [
+        "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec", "Jan", "Feb",
+    ];
+
+    let days = timestamp / 86_400;
+    let seconds = timestamp % 86_400;
+
+    // Converts days to a civil date, see <https://howardhinnant.github.io/date_algorithms.html>
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z % 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let year = year_of_era + era * 400 + u64::from(month >= 10);
+
+    format!(
+        "{}, {} {} {} {:02}:{:02}:{:02} +0000",
+        DAYS[(days % 7) as usize],
+        day,
+        MONTHS[month as usize],
+        year,
+        seconds / 3_600,
+        seconds % 3_600 / 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::mime::headers::decode_header;
+
+    #[test]
+    fn quoted_printable() {
+        assert_eq!(
+            encode_quoted_printable("Café = coffee \nBye"),
+            "Caf=C3=A9 =3D coffee=20\r\nBye"
+        );
+
+        let long = encode_quoted_printable(&"a".repeat(100));
+
+        assert!(long.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(long.replace("=\r\n", ""), "a".repeat(100));
+    }
+
+    #[test]
+    fn quoted_printable_round_trip() {
+        let text = "Café = coffee \r\n".to_string() + &"é".repeat(50);
+        let decoded = decode_quoted_printable(encode_quoted_printable(&text).as_bytes());
+
+        assert_eq!(String::from_utf8(decoded).unwrap(), text);
+    }
+
+    #[test]
+    fn headers() {
+        assert_eq!(encode_header("Your flight plan"), "Your flight plan");
+        assert_eq!(
+            encode_header("Café au lait"),
+            "=?UTF-8?Q?Caf=C3=A9_au_lait?="
+        );
+
+        let sentence = "Your email flight plan ".repeat(10);
+        let folded = encode_header(&sentence);
+
+        assert!(folded.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(folded.replace("\r\n", ""), sentence);
+
+        let json = format!("{{\"seats\":[{}]}}", "\"1A\",".repeat(200));
+        let encoded = encode_header(&json);
+
+        assert!(encoded.lines().all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert_eq!(decode_header(&encoded), json);
+    }
+
+    #[test]
+    fn dates() {
+        assert_eq!(format_date(0), "Thu, 1 Jan 1970 00:00:00 +0000");
+        assert_eq!(format_date(951_782_400), "Tue, 29 Feb 2000 00:00:00 +0000");
+        assert_eq!(
+            format_date(1_792_398_600),
+            "Mon, 19 Oct 2026 08:30:00 +0000"
+        );
+    }
+}
@@ -0,0 +1,362 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::encoding::decode_charset;
+use crate::data::{EmailAddress, EmlError};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use regex_lite::{Captures, Regex};
+
+/// The headers of a MIME entity, in order
+pub(crate) type Headers = Vec<(String, String)>;
+
+/// Parses a header block into names and values, folded lines are joined
+///
+/// # Parameters
+///
+/// * `text`: The headers, one per line
+pub(crate) fn parse_headers(text: &str) -> Result<Headers, EmlError> {
+    let mut headers = Headers::new();
+
+    for line in text.lines() {
+        if line.starts_with([' ', '\t']) {
+            match headers.last_mut() {
+                Some((_, value)) => value.push_str(line),
+                None => return Err(EmlError::InvalidHeader(line.to_string())),
+            }
+
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once(':')
+            .filter(|(name, _)| !name.is_empty() && !name.contains(' '))
+            .ok_or_else(|| EmlError::InvalidHeader(line.to_string()))?;
+
+        headers.push((name.to_string(), value.trim_start().to_string()));
+    }
+
+    for (_, value) in headers.iter_mut() {
+        *value = value.trim_end().to_string();
+    }
+
+    Ok(headers)
+}
+
+/// Returns the first value of a header, the name is case insensitive
+///
+/// # Parameters
+///
+/// * `headers`: The headers
+/// * `name`: The header's name
+pub(crate) fn header<'a>(headers: &'a [(String, String)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(k, _)| k.eq_ignore_ascii_case(name))
+        .map(|(_, v)| v.as_str())
+}
+
+/// Splits the parameters of a header value like `attachment; filename="a;b.txt"`
+///
+/// Names are lowercased, quoted values are unquoted and unescaped
+///
+/// # Parameters
+///
+/// * `value`: The header's value
+fn parameters(value: &str) -> Vec<(String, String)> {
+    let mut segments = vec![String::new()];
+    let mut quoted = false;
+    let mut escaped = false;
+
+    for c in value.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if quoted => escaped = true,
+            '"' => quoted = !quoted,
+            ';' if !quoted => {
+                segments.push(String::new());
+                continue;
+            }
+            _ => (),
+        }
+
+        if let Some(segment) = segments.last_mut() {
+            segment.push(c);
+        }
+    }
+
+    segments
+        .iter()
+        .skip(1)
+        .filter_map(|segment| {
+            let (name, value) = segment.split_once('=')?;
+            let value = value.trim();
+            let value = match value.strip_prefix('"') {
+                Some(quoted) => {
+                    let mut unescaped = String::new();
+                    let mut chars = quoted.strip_suffix('"').unwrap_or(quoted).chars();
+
+                    while let Some(c) = chars.next() {
+                        unescaped.extend(match c {
+                            '\\' => chars.next(),
+                            _ => Some(c),
+                        });
+                    }
+
+                    unescaped
+                }
+                None => value.to_string(),
+            };
+
+            Some((name.trim().to_ascii_lowercase(), value))
+        })
+        .collect()
+}
+
+/// Decodes the `%XX` escapes of an RFC 2231 parameter value
+///
+/// # Parameters
+///
+/// * `text`: The encoded value
+fn decode_percent(text: &str) -> Vec<u8> {
+    let bytes = text.as_bytes();
+    let mut output = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let hex = bytes
+            .get(i + 1..i + 3)
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+        match (bytes[i], hex) {
+            (b'%', Some(byte)) => {
+                output.push(byte);
+                i += 3;
+            }
+            (byte, _) => {
+                output.push(byte);
+                i += 1;
+            }
+        }
+    }
+
+    output
+}
+
+/// Returns a parameter of a header value like `attachment; filename="file.txt"`
+///
+/// RFC 2231 parameters, like `filename*=UTF-8''caf%C3%A9.txt` or `filename*0=` and
+/// `filename*1=` continuations, are decoded and take precedence over the plain parameter
+///
+/// # Parameters
+///
+/// * `value`: The header's value
+/// * `parameter`: The parameter's name
+pub(crate) fn header_parameter(value: &str, parameter: &str) -> Option<String> {
+    let parameter = parameter.to_ascii_lowercase();
+    let mut plain = None;
+    // The sections of an RFC 2231 parameter: index, percent encoded, value
+    let mut sections: Vec<(u32, bool, String)> = Vec::new();
+
+    for (name, value) in parameters(value) {
+        let section = match name.strip_prefix(parameter.as_str()) {
+            Some("") => {
+                plain = Some(value);
+                continue;
+            }
+            Some("*") => Some((0, true)),
+            Some(suffix) => suffix.strip_prefix('*').and_then(|suffix| {
+                let (index, encoded) = match suffix.strip_suffix('*') {
+                    Some(index) => (index, true),
+                    None => (suffix, false),
+                };
+
+                Some((index.parse().ok()?, encoded))
+            }),
+            None => None,
+        };
+
+        if let Some((index, encoded)) = section {
+            sections.push((index, encoded, value));
+        }
+    }
+
+    sections.sort_by_key(|(index, _, _)| *index);
+
+    let mut charset = String::new();
+    let mut bytes = Vec::new();
+
+    for (i, (_, encoded, value)) in sections.iter().enumerate() {
+        match (i, encoded) {
+            // The first encoded section starts with the charset and the language
+            (0, true) => {
+                let mut fields = value.splitn(3, '\'');
+                let (first, _, text) = (fields.next(), fields.next(), fields.next());
+
+                match text {
+                    Some(text) => {
+                        charset = first.unwrap_or_default().to_string();
+                        bytes.extend(decode_percent(text));
+                    }
+                    None => bytes.extend(decode_percent(value)),
+                }
+            }
+            (_, true) => bytes.extend(decode_percent(value)),
+            (_, false) => bytes.extend(value.as_bytes()),
+        }
+    }
+
+    match sections.is_empty() {
+        true => plain,
+        false => Some(decode_charset(&bytes, &charset)),
+    }
+}
+
+/// Decodes the RFC 2047 encoded words of a header value, like `=?UTF-8?Q?Caf=C3=A9?=`
+///
+/// # Parameters
+///
+/// * `value`: The header's value
+pub(crate) fn decode_header(value: &str) -> String {
+    let word = Regex::new(r"=\?([^?]+)\?([BbQq])\?([^?]*)\?=").unwrap();
+    let between_words = Regex::new(r"\?=\s+=\?").unwrap();
+    let value = between_words.replace_all(value, "?==?");
+
+    word.replace_all(&value, |captures: &Captures| {
+        let bytes = match &captures[2] {
+            "B" | "b" => match STANDARD.decode(&captures[3]) {
+                Ok(bytes) => bytes,
+                Err(_) => return captures[0].to_string(),
+            },
+            _ => super::encoding::decode_quoted_printable(captures[3].replace('_', " ").as_bytes()),
+        };
+
+        decode_charset(&bytes, &captures[1])
+    })
+    .into_owned()
+}
+
+/// Parses an address list like `"Doe, Jane" <jane@example.com>, john@example.com`
+///
+/// # Parameters
+///
+/// * `value`: The header's value
+pub(crate) fn parse_addresses(value: &str) -> Vec<EmailAddress> {
+    let mut addresses = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut bracketed = false;
+
+    for c in value.chars().chain([',']) {
+        match c {
+            '"' => quoted = !quoted,
+            '<' if !quoted => bracketed = true,
+            '>' if !quoted => bracketed = false,
+            // Groups like `undisclosed-recipients:;` only name their addresses
+            ':' if !quoted && !bracketed => {
+                current.clear();
+                continue;
+            }
+            ',' | ';' if !quoted && !bracketed => {
+                if current.contains('@') {
+                    let mut address = EmailAddress::from_mailbox(&current);
+
+                    address.name = decode_header(&address.name);
+                    addresses.push(address);
+                }
+
+                current.clear();
+                continue;
+            }
+            _ => (),
+        }
+
+        current.push(c);
+    }
+
+    addresses
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn folded_headers() {
+        let headers =
+            parse_headers("Subject: Your email\r\n flight plan\r\nX-Flight: MJ1\r\n").unwrap();
+
+        assert_eq!(header(&headers, "subject"), Some("Your email flight plan"));
+        assert_eq!(header(&headers, "X-Flight"), Some("MJ1"));
+        assert!(parse_headers("Not a header").is_err());
+    }
+
+    #[test]
+    fn header_parameters() {
+        let disposition = "attachment; filename=\"a;b \\\"c\\\".pdf\"; size=12";
+
+        assert_eq!(
+            header_parameter(disposition, "filename"),
+            Some("a;b \"c\".pdf".to_string())
+        );
+        assert_eq!(
+            header_parameter(disposition, "Size"),
+            Some("12".to_string())
+        );
+        assert_eq!(header_parameter(disposition, "file"), None);
+        assert_eq!(
+            header_parameter(
+                "attachment; filename=plan.txt; filename*=UTF-8'fr'caf%C3%A9%20plan.txt",
+                "filename"
+            ),
+            Some("café plan.txt".to_string())
+        );
+        assert_eq!(
+            header_parameter(
+                "attachment; filename*1*=%E9.txt; filename*0*=iso-8859-1''caf",
+                "filename"
+            ),
+            Some("café.txt".to_string())
+        );
+        assert_eq!(
+            header_parameter("text/plain; name*0=\"flight \"; name*1=plan.txt", "name"),
+            Some("flight plan.txt".to_string())
+        );
+    }
+
+    #[test]
+    fn encoded_words() {
+        assert_eq!(
+            decode_header("=?UTF-8?Q?Caf=C3=A9_au?= =?UTF-8?B?IGxhaXQ=?="),
+            "Café au lait"
+        );
+        assert_eq!(decode_header("=?ISO-8859-1?Q?Zo=EB?= Doe"), "Zoë Doe");
+    }
+
+    #[test]
+    fn address_lists() {
+        let addresses = parse_addresses(
+            "\"Doe, Jane\" <jane@example.com>, john@example.com,\r\n =?UTF-8?Q?Zo=C3=AB?= <zoe@example.com>",
+        );
+
+        assert_eq!(addresses.len(), 3);
+        assert_eq!(addresses[0].name, "Doe, Jane");
+        assert_eq!(addresses[1].email, "john@example.com");
+        assert_eq!(addresses[2].name, "Zoë");
+        assert!(parse_addresses("undisclosed-recipients:;").is_empty());
+    }
+}
@@ -0,0 +1,661 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{OutboxEntry, OutboxStatus};
+use crate::data::{Message, ResponseSuccess};
+use crate::dry_run::is_refusal;
+use crate::requests::{MessageRequest, SendRequest};
+use crate::Mailjet;
+use std::error::Error as StdError;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A counter making generated custom IDs and temporary file names unique in this process
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// The result of an attempt to send a message
+enum Attempt {
+    /// Mailjet accepted the message, the recipients' message IDs are given
+    Sent(Vec<ResponseSuccess>),
+    /// Mailjet or the client refused the message, retrying won't help
+    Rejected(String),
+    /// The message couldn't be sent, it can be retried
+    Failed(String),
+    /// An interrupted attempt can't be checked yet, sending again could duplicate the message
+    Unknown(String),
+}
+
+/// A durable queue of messages, stored in a directory with one JSON file per message
+///
+/// Messages are identified by their custom ID: enqueuing a message whose custom ID is already in
+/// the outbox does nothing. A worker drains the outbox with [`Outbox::drain`] or [`Outbox::run`],
+/// records the message IDs and UUIDs given by Mailjet, and retries failures with an exponential
+/// backoff.
+///
+/// Entries are marked as being sent before each request. If the process stops before saving
+/// Mailjet's answer, the next drain looks the custom ID up in Mailjet's messages before sending
+/// it again, so each message is delivered at least once. Only one worker should drain an outbox
+/// at a time.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use mailjet_api_wrapper::data::{EmailAddress, Message};
+/// use mailjet_api_wrapper::{outbox::Outbox, Mailjet};
+///
+/// let outbox = Outbox::open("/var/spool/mailjet").unwrap();
+///
+/// outbox
+///     .enqueue(&Message {
+///         from: EmailAddress::from_email("pilot@mailjet.com"),
+///         to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+///         text_part: "Welcome aboard".to_string(),
+///         custom_id: Some("booking-42".to_string()),
+///         ..Default::default()
+///     })
+///     .unwrap();
+///
+/// let mailjet = Mailjet::from_api_keys("your_api_key", "your_api_secret");
+///
+/// for entry in outbox.drain(&mailjet).unwrap() {
+///     println!("{}: {:?}", entry.custom_id, entry.status);
+/// }
+/// ```
+pub struct Outbox {
+    /// The directory holding the entries
+    directory: PathBuf,
+
+    /// The maximum number of attempts to send a message
+    ///
+    /// Default is 8
+    pub max_attempts: u32,
+
+    /// The delay before the second attempt, doubled after each failure
+    ///
+    /// Default is 30 seconds
+    pub initial_retry_delay: Duration,
+
+    /// The maximum delay between two attempts
+    ///
+    /// Default is 1 hour
+    pub max_retry_delay: Duration,
+}
+
+impl Outbox {
+    /// Opens an outbox, creating its directory if needed
+    ///
+    /// # Parameters
+    ///
+    /// * `directory`: The directory holding the entries
+    pub fn open<P: AsRef<Path>>(directory: P) -> io::Result<Self> {
+        fs::create_dir_all(&directory)?;
+
+        Ok(Self {
+            directory: directory.as_ref().to_path_buf(),
+            max_attempts: 8,
+            initial_retry_delay: Duration::from_secs(30),
+            max_retry_delay: Duration::from_secs(3600),
+        })
+    }
+
+    /// Sets how many times a message is sent and the delay before the second attempt
+    ///
+    /// # Parameters
+    ///
+    /// * `max_attempts`: The maximum number of attempts
+    /// * `initial_retry_delay`: The delay before the second attempt, doubled after each failure
+    pub fn with_retries(mut self, max_attempts: u32, initial_retry_delay: Duration) -> Self {
+        self.max_attempts = max_attempts;
+        self.initial_retry_delay = initial_retry_delay;
+        self
+    }
+
+    /// Adds a message to the outbox and returns its custom ID
+    ///
+    /// A custom ID is generated if the message has none. If the outbox already has a message
+    /// with the same custom ID, that message is kept and nothing is added.
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message, with the globals merged into it
+    pub fn enqueue(&self, message: &Message) -> io::Result<String> {
+        let custom_id = message
+            .custom_id
+            .clone()
+            .filter(|id| !id.is_empty())
+            .unwrap_or_else(generate_custom_id);
+        let now = now();
+        let entry = OutboxEntry {
+            custom_id: custom_id.clone(),
+            message: Message {
+                custom_id: Some(custom_id.clone()),
+                ..message.clone()
+            },
+            status: OutboxStatus::Pending,
+            attempts: 0,
+            enqueued_at: now,
+            next_attempt_at: now,
+            last_error: None,
+            recipients: Vec::new(),
+        };
+        let temporary = self.write_temporary(&entry)?;
+
+        // Linking fails if the entry exists, so two processes can't enqueue the same message
+        let linked = fs::hard_link(&temporary, self.path(&custom_id));
+
+        fs::remove_file(&temporary)?;
+
+        match linked {
+            Err(e) if e.kind() != io::ErrorKind::AlreadyExists => Err(e),
+            _ => Ok(custom_id),
+        }
+    }
+
+    /// Adds all the messages of a request to the outbox and returns their custom IDs
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request, its globals are merged into each message
+    pub fn enqueue_request(&self, request: &SendRequest) -> io::Result<Vec<String>> {
+        request
+            .effective_messages()
+            .iter()
+            .map(|message| self.enqueue(message))
+            .collect()
+    }
+
+    /// Lists the entries, in enqueuing order
+    pub fn entries(&self) -> io::Result<Vec<OutboxEntry>> {
+        let mut entries = Vec::new();
+
+        for file in fs::read_dir(&self.directory)? {
+            let path = file?.path();
+
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                entries.push(read_entry(&path)?);
+            }
+        }
+
+        entries.sort_by(|a, b| (a.enqueued_at, &a.custom_id).cmp(&(b.enqueued_at, &b.custom_id)));
+
+        Ok(entries)
+    }
+
+    /// Gets an entry, `None` if the outbox has no message with this custom ID
+    ///
+    /// # Parameters
+    ///
+    /// * `custom_id`: The message's custom ID
+    pub fn entry(&self, custom_id: &str) -> io::Result<Option<OutboxEntry>> {
+        match read_entry(&self.path(custom_id)) {
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            entry => entry.map(Some),
+        }
+    }
+
+    /// Removes an entry, like a sent message which doesn't need to be kept
+    ///
+    /// # Parameters
+    ///
+    /// * `custom_id`: The message's custom ID
+    pub fn remove(&self, custom_id: &str) -> io::Result<()> {
+        fs::remove_file(self.path(custom_id))
+    }
+
+    /// Sends the messages due for an attempt and returns their updated entries
+    ///
+    /// Errors come from the file system, sending errors are recorded in the entries
+    ///
+    /// # Parameters
+    ///
+    /// * `mailjet`: The client sending the messages
+    pub fn drain(&self, mailjet: &Mailjet) -> io::Result<Vec<OutboxEntry>> {
+        let now = now();
+        let mut drained = Vec::new();
+
+        for mut entry in self.entries()? {
+            let attempt = match entry.status {
+                OutboxStatus::Pending if entry.next_attempt_at <= now => {
+                    self.attempt(mailjet, &mut entry)?
+                }
+                OutboxStatus::Sending => match find_sent(mailjet, &entry.custom_id) {
+                    Ok(Some(recipients)) => Attempt::Sent(recipients),
+                    Ok(None) => self.attempt(mailjet, &mut entry)?,
+                    Err(e) => Attempt::Unknown(e.to_string()),
+                },
+                _ => continue,
+            };
+
+            self.record(&mut entry, attempt);
+            self.save(&entry)?;
+            drained.push(entry);
+        }
+
+        Ok(drained)
+    }
+
+    /// Drains the outbox until `stop` is set, waiting `interval` between two drains
+    ///
+    /// This function blocks the current thread, run it in a thread of its own
+    ///
+    /// # Parameters
+    ///
+    /// * `mailjet`: The client sending the messages
+    /// * `interval`: The delay between two drains
+    /// * `stop`: Set it to stop the worker after the current drain
+    pub fn run(&self, mailjet: &Mailjet, interval: Duration, stop: &AtomicBool) -> io::Result<()> {
+        while !stop.load(Ordering::Relaxed) {
+            self.drain(mailjet)?;
+            thread::sleep(interval);
+        }
+
+        Ok(())
+    }
+
+    /// Marks an entry as being sent, then sends its message
+    ///
+    /// # Parameters
+    ///
+    /// * `mailjet`: The client sending the message
+    /// * `entry`: The entry
+    fn attempt(&self, mailjet: &Mailjet, entry: &mut OutboxEntry) -> io::Result<Attempt> {
+        entry.status = OutboxStatus::Sending;
+        entry.attempts += 1;
+        self.save(entry)?;
+
+        Ok(send(mailjet, &entry.message))
+    }
+
+    /// Updates an entry with the result of an attempt
+    ///
+    /// # Parameters
+    ///
+    /// * `entry`: The entry
+    /// * `attempt`: The result of the attempt
+    fn record(&self, entry: &mut OutboxEntry, attempt: Attempt) {
+        match attempt {
+            Attempt::Sent(recipients) => {
+                entry.status = OutboxStatus::Sent;
+                entry.recipients = recipients;
+                entry.last_error = None;
+            }
+            Attempt::Rejected(error) => {
+                entry.status = OutboxStatus::Failed;
+                entry.last_error = Some(error);
+            }
+            Attempt::Failed(error) if entry.attempts >= self.max_attempts => {
+                entry.status = OutboxStatus::Failed;
+                entry.last_error = Some(error);
+            }
+            Attempt::Failed(error) => {
+                let delay = self
+                    .initial_retry_delay
+                    .saturating_mul(2u32.saturating_pow(entry.attempts.saturating_sub(1)))
+                    .min(self.max_retry_delay);
+
+                entry.status = OutboxStatus::Pending;
+                entry.next_attempt_at = now() + delay.as_secs();
+                entry.last_error = Some(error);
+            }
+            Attempt::Unknown(error) => entry.last_error = Some(error),
+        }
+    }
+
+    /// Gets the path of an entry's file, named after the hexadecimal custom ID
+    ///
+    /// # Parameters
+    ///
+    /// * `custom_id`: The message's custom ID
+    fn path(&self, custom_id: &str) -> PathBuf {
+        let name: String = custom_id.bytes().map(|b| format!("{:02x}", b)).collect();
+
+        self.directory.join(format!("{}.json", name))
+    }
+
+    /// Writes an entry to a new temporary file of the outbox's directory, and returns its path
+    ///
+    /// # Parameters
+    ///
+    /// * `entry`: The entry
+    fn write_temporary(&self, entry: &OutboxEntry) -> io::Result<PathBuf> {
+        let path = self.directory.join(format!(
+            ".{}-{}.tmp",
+            process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let mut file = File::create(&path)?;
+
+        file.write_all(&serde_json::to_vec_pretty(entry)?)?;
+        file.sync_all()?;
+
+        Ok(path)
+    }
+
+    /// Saves an entry, replacing its file at once so that a crash can't leave it half written
+    ///
+    /// # Parameters
+    ///
+    /// * `entry`: The entry
+    fn save(&self, entry: &OutboxEntry) -> io::Result<()> {
+        let temporary = self.write_temporary(entry)?;
+
+        fs::rename(temporary, self.path(&entry.custom_id))
+    }
+}
+
+/// Gets the current time, in seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
+
+/// Generates a custom ID unique to this enqueuing
+fn generate_custom_id() -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+
+    format!(
+        "outbox-{}-{}-{}",
+        nanos,
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+/// Reads an entry's file
+///
+/// # Parameters
+///
+/// * `path`: The file's path
+fn read_entry(path: &Path) -> io::Result<OutboxEntry> {
+    Ok(serde_json::from_slice(&fs::read(path)?)?)
+}
+
+/// Sends a message with the Send API v3.1
+///
+/// # Parameters
+///
+/// * `mailjet`: The client
+/// * `message`: The message
+fn send(mailjet: &Mailjet, message: &Message) -> Attempt {
+    let request = SendRequest {
+        messages: vec![message.clone()],
+        ..Default::default()
+    };
+    let response = match mailjet.send(&request) {
+        Ok(response) => response,
+        Err(e) if is_refusal(e.as_ref()) => return Attempt::Rejected(e.to_string()),
+        Err(e) => return Attempt::Failed(e.to_string()),
+    };
+    let code = response.http_code.unwrap_or_default();
+
+    match response
+        .object
+        .and_then(|object| object.messages.into_iter().next())
+    {
+        Some(message) if message.status == "success" => Attempt::Sent(
+            message
+                .to
+                .into_iter()
+                .chain(message.cc)
+                .chain(message.bcc)
+                .collect(),
+        ),
+        Some(message) => {
+            let errors: Vec<&str> = message
+                .errors
+                .iter()
+                .map(|e| e.error_message.as_str())
+                .collect();
+
+            Attempt::Rejected(errors.join(", "))
+        }
+        None => Attempt::Failed(format!("HTTP {}: {}", code, response.raw_data)),
+    }
+}
+
+/// Looks a message up in Mailjet's messages, and returns its recipients' message IDs if it was
+/// sent
+///
+/// # Parameters
+///
+/// * `mailjet`: The client
+/// * `custom_id`: The message's custom ID
+fn find_sent(
+    mailjet: &Mailjet,
+    custom_id: &str,
+) -> Result<Option<Vec<ResponseSuccess>>, Box<dyn StdError>> {
+    let search = MessageRequest {
+        custom_id: Some(custom_id.to_string()),
+        show_contact_alt: Some(true),
+        ..Default::default()
+    };
+    let response = mailjet.message(&search)?;
+    let messages = match (response.http_code, response.object) {
+        (Some(200), Some(object)) => object.data,
+        (code, _) => {
+            let code = code.unwrap_or_default();
+
+            return Err(format!("HTTP {}: {}", code, response.raw_data).into());
+        }
+    };
+
+    if messages.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(
+        messages
+            .into_iter()
+            .map(|message| ResponseSuccess {
+                email: message.contact_alt,
+                message_uuid: message.uuid,
+                message_id: message.id,
+                ..Default::default()
+            })
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod test {
+    use super::Outbox;
+    use crate::data::{EmailAddress, Message};
+    use crate::dry_run::RecipientPolicy;
+    use crate::outbox::OutboxStatus;
+    use crate::requests::SendRequest;
+    use crate::testing::MockServer;
+    use std::time::Duration;
+    use std::{env, fs, process};
+
+    /// Opens an empty outbox in a temporary directory
+    fn outbox(name: &str) -> Outbox {
+        let directory = env::temp_dir().join(format!("mailjet-outbox-{}-{}", name, process::id()));
+
+        fs::remove_dir_all(&directory).ok();
+        Outbox::open(directory).unwrap()
+    }
+
+    /// Removes an outbox's directory
+    fn remove(outbox: Outbox) {
+        fs::remove_dir_all(outbox.directory).ok();
+    }
+
+    /// Creates a valid message
+    fn message(custom_id: &str) -> Message {
+        Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+            text_part: "Welcome aboard".to_string(),
+            custom_id: Some(custom_id.to_string()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn enqueue_once() {
+        let outbox = outbox("enqueue");
+
+        outbox.enqueue(&message("booking-1")).unwrap();
+        outbox.enqueue(&message("booking-1")).unwrap();
+
+        let generated = outbox
+            .enqueue(&Message {
+                custom_id: None,
+                ..message("")
+            })
+            .unwrap();
+        let entries = outbox.entries().unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].custom_id, "booking-1");
+        assert_eq!(entries[0].status, OutboxStatus::Pending);
+        assert!(generated.starts_with("outbox-"));
+        assert_eq!(
+            outbox.entry(&generated).unwrap().unwrap().message.custom_id,
+            Some(generated)
+        );
+
+        remove(outbox);
+    }
+
+    #[test]
+    fn drain() {
+        let server = MockServer::start().unwrap();
+        let outbox = outbox("drain");
+
+        outbox.enqueue(&message("booking-1")).unwrap();
+
+        let drained = outbox.drain(&server.client()).unwrap();
+
+        assert_eq!(drained[0].status, OutboxStatus::Sent);
+        assert_eq!(drained[0].recipients[0].email, "passenger1@mailjet.com");
+        assert_ne!(drained[0].recipients[0].message_id, 0);
+        assert!(outbox.drain(&server.client()).unwrap().is_empty());
+        assert_eq!(server.requests().len(), 1);
+
+        remove(outbox);
+    }
+
+    #[test]
+    fn retry_with_backoff() {
+        let server = MockServer::start().unwrap();
+        let outbox = outbox("retry").with_retries(2, Duration::ZERO);
+
+        outbox.enqueue(&message("booking-1")).unwrap();
+        outbox.enqueue(&message("booking-2")).unwrap();
+        server.fail_next(503);
+        server.fail_next(503);
+        server.fail_next(503);
+
+        let drained = outbox.drain(&server.client()).unwrap();
+
+        assert_eq!(drained[0].status, OutboxStatus::Pending);
+        assert_eq!(drained[0].attempts, 1);
+        assert!(drained[0].last_error.is_some());
+
+        let drained = outbox.drain(&server.client()).unwrap();
+
+        assert_eq!(drained[0].status, OutboxStatus::Failed);
+        assert_eq!(drained[1].status, OutboxStatus::Sent);
+        assert_eq!(drained[1].attempts, 2);
+
+        remove(outbox);
+    }
+
+    #[test]
+    fn rejected() {
+        let server = MockServer::start().unwrap();
+        let outbox = outbox("rejected");
+
+        outbox
+            .enqueue(&Message {
+                from: EmailAddress::default(),
+                ..message("booking-1")
+            })
+            .unwrap();
+
+        let drained = outbox.drain(&server.client()).unwrap();
+
+        assert_eq!(drained[0].status, OutboxStatus::Failed);
+        assert_eq!(drained[0].attempts, 1);
+
+        remove(outbox);
+    }
+
+    #[test]
+    fn refused_by_recipient_policy() {
+        let server = MockServer::start().unwrap();
+        let outbox = outbox("refused");
+        let policy = RecipientPolicy::allowlist().allow_domain("example.com");
+        let mailjet = server.client().with_recipient_policy(policy);
+
+        outbox.enqueue(&message("booking-1")).unwrap();
+
+        let drained = outbox.drain(&mailjet).unwrap();
+
+        assert_eq!(drained[0].status, OutboxStatus::Failed);
+        assert_eq!(drained[0].attempts, 1);
+        assert!(drained[0].last_error.is_some());
+        assert!(server.requests().is_empty());
+
+        remove(outbox);
+    }
+
+    #[test]
+    fn interrupted_sending_not_repeated() {
+        let server = MockServer::start().unwrap();
+        let outbox = outbox("interrupted");
+        let mailjet = server.client();
+
+        outbox.enqueue(&message("booking-1")).unwrap();
+
+        // The process stopped after sending, before saving Mailjet's answer
+        let mut entry = outbox.entry("booking-1").unwrap().unwrap();
+
+        entry.status = OutboxStatus::Sending;
+        entry.attempts = 1;
+        outbox.save(&entry).unwrap();
+        mailjet
+            .send(&SendRequest {
+                messages: vec![entry.message],
+                ..Default::default()
+            })
+            .unwrap();
+
+        let drained = outbox.drain(&mailjet).unwrap();
+
+        assert_eq!(drained[0].status, OutboxStatus::Sent);
+        assert_eq!(drained[0].recipients[0].email, "passenger1@mailjet.com");
+        assert_eq!(drained[0].attempts, 1);
+        assert!(server.requests().iter().skip(1).all(|r| r.method == "GET"));
+
+        remove(outbox);
+    }
+}
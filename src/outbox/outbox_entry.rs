@@ -0,0 +1,48 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::OutboxStatus;
+use crate::data::{Message, ResponseSuccess};
+use serde::{Deserialize, Serialize};
+
+/// A message stored in the outbox, with its delivery state
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct OutboxEntry {
+    /// The message's custom ID, identifying the entry
+    pub custom_id: String,
+
+    /// The message to send
+    pub message: Message,
+
+    /// The delivery status
+    pub status: OutboxStatus,
+
+    /// The number of attempts made to send the message
+    pub attempts: u32,
+
+    /// When the message was enqueued, in seconds since the Unix epoch
+    pub enqueued_at: u64,
+
+    /// When the message can be sent again, in seconds since the Unix epoch
+    pub next_attempt_at: u64,
+
+    /// Why the last attempt failed
+    pub last_error: Option<String>,
+
+    /// The message IDs and UUIDs given by Mailjet, one per recipient, once sent
+    pub recipients: Vec<ResponseSuccess>,
+}
@@ -0,0 +1,32 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The polling loop
+mod poll;
+/// The reasons why polling a job can fail
+mod poll_error;
+/// How to poll an asynchronous job
+mod poll_options;
+/// An asynchronous job whose progress can be polled
+mod pollable_job;
+
+pub use poll::poll_until_complete;
+#[cfg(feature = "async")]
+pub use poll::poll_until_complete_async;
+pub use poll_error::PollError;
+pub use poll_options::PollOptions;
+pub use pollable_job::PollableJob;
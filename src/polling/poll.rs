@@ -0,0 +1,294 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{PollError, PollOptions, PollableJob};
+use crate::dry_run::is_refusal;
+use std::error::Error as StdError;
+use std::ops::ControlFlow;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// The result of one check of a job
+type Check<T> = Result<Option<T>, Box<dyn StdError>>;
+
+/// The progress of a polling loop, shared by the blocking and async helpers
+struct PollState<'a> {
+    /// The timeout and the delays between checks
+    options: &'a PollOptions,
+
+    /// When the polling started
+    start: Instant,
+
+    /// The delay before the next check
+    interval: Duration,
+}
+
+impl<'a> PollState<'a> {
+    /// Starts a polling loop
+    ///
+    /// # Parameters
+    ///
+    /// * `options`: The timeout and the delays between checks
+    fn new(options: &'a PollOptions) -> Self {
+        Self {
+            options,
+            start: Instant::now(),
+            interval: options.initial_interval,
+        }
+    }
+
+    /// Handles a check, and returns either the finished job or the delay before the next check
+    ///
+    /// Failed checks are retried until the timeout, except requests refused by the client
+    /// itself. When the timeout is reached after a failed check, its error is returned.
+    ///
+    /// # Parameters
+    ///
+    /// * `check`: The result of the check
+    fn next<T: PollableJob>(
+        &mut self,
+        check: Check<T>,
+    ) -> Result<ControlFlow<T, Duration>, Box<dyn StdError>> {
+        let failure = match check {
+            Ok(Some(job)) if job.is_finished() => return Ok(ControlFlow::Break(job)),
+            Ok(Some(_)) => None,
+            Ok(None) => return Err(Box::new(PollError::NotFound)),
+            Err(e) if is_refusal(e.as_ref()) => return Err(e),
+            Err(e) => Some(e),
+        };
+        let elapsed = self.start.elapsed();
+
+        if elapsed + self.interval > self.options.timeout {
+            return Err(failure.unwrap_or_else(|| Box::new(PollError::Timeout(elapsed))));
+        }
+
+        // A multiplier below 1, infinite or NaN can't make the delay grow, it is ignored
+        let multiplier = match self.options.multiplier {
+            m if m.is_finite() && m >= 1.0 => m,
+            _ => 1.0,
+        };
+        let delay = self.interval;
+
+        self.interval = Duration::try_from_secs_f64(delay.as_secs_f64() * multiplier)
+            .unwrap_or(self.options.max_interval)
+            .min(self.options.max_interval);
+
+        Ok(ControlFlow::Continue(delay))
+    }
+}
+
+/// Retrieves a job until it is finished, waiting longer and longer between each check
+///
+/// This function blocks the current thread, like the whole client. In an async context, use
+/// [`poll_until_complete_async`] or run it with something like `tokio::task::spawn_blocking`
+///
+/// Errors returned by `fetch`, like network errors or [`PollError::Unavailable`], are retried
+/// until the timeout. Requests refused by the client's dry run mode or recipient policy aren't.
+///
+/// # Parameters
+///
+/// * `options`: The timeout and the delays between checks
+/// * `fetch`: Retrieves the current state of the job, `None` if it doesn't exist
+pub fn poll_until_complete<T, F>(options: &PollOptions, mut fetch: F) -> Result<T, Box<dyn StdError>>
+where
+    T: PollableJob,
+    F: FnMut() -> Check<T>,
+{
+    let mut state = PollState::new(options);
+
+    loop {
+        match state.next(fetch())? {
+            ControlFlow::Break(job) => return Ok(job),
+            ControlFlow::Continue(delay) => thread::sleep(delay),
+        }
+    }
+}
+
+/// Retrieves a job until it is finished without blocking, waiting longer and longer between
+/// each check with Tokio's timer
+///
+/// Behaves like [`poll_until_complete`], `fetch` returns a future instead
+///
+/// # Parameters
+///
+/// * `options`: The timeout and the delays between checks
+/// * `fetch`: Retrieves the current state of the job, `None` if it doesn't exist
+#[cfg(feature = "async")]
+pub async fn poll_until_complete_async<T, F, Fut>(
+    options: &PollOptions,
+    mut fetch: F,
+) -> Result<T, Box<dyn StdError>>
+where
+    T: PollableJob,
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Check<T>>,
+{
+    let mut state = PollState::new(options);
+
+    loop {
+        match state.next(fetch().await)? {
+            ControlFlow::Break(job) => return Ok(job),
+            ControlFlow::Continue(delay) => tokio::time::sleep(delay).await,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::dry_run::DryRunError;
+    use crate::transport::RequestType;
+    use std::time::Duration;
+
+    /// A job finished after a number of checks
+    struct CountdownJob(u32);
+
+    impl PollableJob for CountdownJob {
+        fn is_finished(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    /// Fast polling options for tests
+    fn options(timeout: u64) -> PollOptions {
+        PollOptions {
+            timeout: Duration::from_millis(timeout),
+            initial_interval: Duration::from_millis(1),
+            max_interval: Duration::from_millis(4),
+            multiplier: 2.0,
+        }
+    }
+
+    #[test]
+    fn poll_until_finished() {
+        let mut remaining = 5;
+        let mut checks = 0;
+        let job = poll_until_complete(&options(1000), || {
+            checks += 1;
+            remaining -= 1;
+            Ok(Some(CountdownJob(remaining)))
+        })
+        .unwrap();
+
+        assert!(job.is_finished());
+        assert_eq!(checks, 5);
+    }
+
+    #[test]
+    fn poll_timeout() {
+        let error = poll_until_complete(&options(20), || Ok(Some(CountdownJob(1))))
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<PollError>(),
+            Some(PollError::Timeout(_))
+        ));
+    }
+
+    #[test]
+    fn poll_not_found() {
+        let error = poll_until_complete::<CountdownJob, _>(&options(20), || Ok(None))
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<PollError>(),
+            Some(PollError::NotFound)
+        ));
+    }
+
+    #[test]
+    fn poll_retry_transient_errors() {
+        let mut checks = 0;
+        let job = poll_until_complete(&options(1000), || {
+            checks += 1;
+            match checks {
+                1 => Err(Box::new(PollError::Unavailable(Some(503))) as Box<dyn StdError>),
+                _ => Ok(Some(CountdownJob(0))),
+            }
+        })
+        .unwrap();
+
+        assert!(job.is_finished());
+        assert_eq!(checks, 2);
+    }
+
+    #[test]
+    fn poll_transient_error_timeout() {
+        let error = poll_until_complete::<CountdownJob, _>(&options(20), || {
+            Err(Box::new(PollError::Unavailable(Some(429))))
+        })
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<PollError>(),
+            Some(PollError::Unavailable(Some(429)))
+        ));
+    }
+
+    #[test]
+    fn poll_refusal_not_retried() {
+        let mut checks = 0;
+        let error = poll_until_complete::<CountdownJob, _>(&options(1000), || {
+            checks += 1;
+            Err(Box::new(DryRunError::NoNetwork(
+                RequestType::Get,
+                "https://api.mailjet.com/v3/REST/job".to_string(),
+            )))
+        })
+        .err()
+        .unwrap();
+
+        assert!(error.is::<DryRunError>());
+        assert_eq!(checks, 1);
+    }
+
+    #[test]
+    fn poll_invalid_multiplier() {
+        for multiplier in [f64::NAN, f64::INFINITY, -1.0, 0.0] {
+            let mut remaining = 3;
+            let options = PollOptions {
+                multiplier,
+                ..options(1000)
+            };
+            let job = poll_until_complete(&options, || {
+                remaining -= 1;
+                Ok(Some(CountdownJob(remaining)))
+            })
+            .unwrap();
+
+            assert!(job.is_finished());
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn poll_until_finished_async() {
+        let mut remaining = 3;
+        let job = poll_until_complete_async(&options(1000), || {
+            remaining -= 1;
+            let job = CountdownJob(remaining);
+            async move { Ok(Some(job)) }
+        })
+        .await
+        .unwrap();
+
+        assert!(job.is_finished());
+    }
+}
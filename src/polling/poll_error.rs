@@ -0,0 +1,45 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+use std::time::Duration;
+
+/// The reasons why polling a job can fail
+#[derive(Debug)]
+pub enum PollError {
+    /// The job didn't finish before the timeout
+    Timeout(Duration),
+    /// The job couldn't be retrieved
+    NotFound,
+    /// The job couldn't be retrieved for now, like with a `429` or `5xx` answer, the HTTP code
+    /// is given if there was an answer
+    Unavailable(Option<u32>),
+}
+
+impl Display for PollError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            PollError::Timeout(d) => write!(f, "Job not finished after {} seconds", d.as_secs()),
+            PollError::NotFound => write!(f, "Job not found"),
+            PollError::Unavailable(Some(code)) => write!(f, "Job unavailable (HTTP {})", code),
+            PollError::Unavailable(None) => write!(f, "Job unavailable"),
+        }
+    }
+}
+
+impl StdError for PollError {}
@@ -0,0 +1,55 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::time::Duration;
+
+/// How to poll an asynchronous job
+///
+/// The delay between two checks starts at `initial_interval` and is multiplied by `multiplier`
+/// after each check, without going over `max_interval`
+pub struct PollOptions {
+    /// The maximum time to wait for the job to finish
+    ///
+    /// Default is 5 minutes
+    pub timeout: Duration,
+
+    /// The delay before the second check
+    ///
+    /// Default is 1 second
+    pub initial_interval: Duration,
+
+    /// The maximum delay between two checks
+    ///
+    /// Default is 30 seconds
+    pub max_interval: Duration,
+
+    /// The factor applied to the delay after each check
+    ///
+    /// Values below `1.0`, infinite or NaN are treated as `1.0`. Default is `2.0`
+    pub multiplier: f64,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(300),
+            initial_interval: Duration::from_secs(1),
+            max_interval: Duration::from_secs(30),
+            multiplier: 2.0,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The API key creation/update request
+#[doc = include_str!("../../doc/manage_sub_accounts.md")]
+#[derive(Serialize, Deserialize, Default)]
+pub struct ApiKeyRequest {
+    /// User-selected name for this key
+    ///
+    /// This field is mandatory for creation
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Indicates whether the key is active or not
+    #[serde(rename = "IsActive")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_active: Option<bool>,
+
+    /// The access rights of the key, empty for full access
+    #[serde(rename = "ACL")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub acl: Option<String>,
+}
@@ -0,0 +1,139 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::traits::UrlEncodedRequest;
+use url_builder::URLBuilder;
+
+/// The contact statistics retrieving request
+#[derive(Default)]
+pub struct ContactStatisticsRequest {
+    /// When true, retrieves only statistics with at least one blocked message
+    pub blocked: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one bounced message
+    pub bounced: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one clicked message
+    pub click: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one deferred message
+    pub deferred: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one delivered message
+    pub delivered: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one hard bounced message
+    pub hard_bounced: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one opened message
+    pub opened: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one processed message
+    pub processed: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one queued message
+    pub queued: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one soft bounced message
+    pub soft_bounced: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one spam complaint
+    pub spam: Option<bool>,
+
+    /// When true, retrieves only statistics with at least one unsubscription
+    pub unsubscribed: Option<bool>,
+
+    /// Limit the response to a select number of returned objects
+    pub limit: Option<i64>,
+
+    /// Retrieve a list of objects starting from a certain offset
+    pub offset: Option<i64>,
+
+    /// Set the value of this query parameter to 1 to retrieve the overall number of objects returned by this request
+    pub count_only: Option<i64>,
+
+    /// Specify a property name for this query parameter to sort the objects in `data`
+    pub sort: Option<String>,
+}
+
+impl UrlEncodedRequest for ContactStatisticsRequest {
+    fn add_parameters_to_url(&self, url_builder: &mut URLBuilder) {
+        if let Some(v) = self.blocked {
+            url_builder.add_param("Blocked", &v.to_string());
+        }
+
+        if let Some(v) = self.bounced {
+            url_builder.add_param("Bounced", &v.to_string());
+        }
+
+        if let Some(v) = self.click {
+            url_builder.add_param("Click", &v.to_string());
+        }
+
+        if let Some(v) = self.deferred {
+            url_builder.add_param("Deferred", &v.to_string());
+        }
+
+        if let Some(v) = self.delivered {
+            url_builder.add_param("Delivered", &v.to_string());
+        }
+
+        if let Some(v) = self.hard_bounced {
+            url_builder.add_param("HardBounced", &v.to_string());
+        }
+
+        if let Some(v) = self.opened {
+            url_builder.add_param("Opened", &v.to_string());
+        }
+
+        if let Some(v) = self.processed {
+            url_builder.add_param("Processed", &v.to_string());
+        }
+
+        if let Some(v) = self.queued {
+            url_builder.add_param("Queued", &v.to_string());
+        }
+
+        if let Some(v) = self.soft_bounced {
+            url_builder.add_param("SoftBounced", &v.to_string());
+        }
+
+        if let Some(v) = self.spam {
+            url_builder.add_param("Spam", &v.to_string());
+        }
+
+        if let Some(v) = self.unsubscribed {
+            url_builder.add_param("Unsubscribed", &v.to_string());
+        }
+
+        if let Some(v) = self.limit {
+            url_builder.add_param("Limit", &v.to_string());
+        }
+
+        if let Some(v) = self.offset {
+            url_builder.add_param("Offset", &v.to_string());
+        }
+
+        if let Some(v) = self.count_only {
+            url_builder.add_param("countOnly", &v.to_string());
+        }
+
+        if let Some(v) = &self.sort {
+            url_builder.add_param("Sort", v);
+        }
+    }
+}
@@ -15,25 +15,100 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
+/// The API key access retrieving request
+mod api_key_access_search_request;
+/// The API key creation/update request
+mod api_key_request;
+/// The API key retrieving request
+mod api_key_search_request;
+/// The contact filter creation/update request
+mod contact_filter_request;
+/// The contact filter retrieving request
+mod contact_filter_search_request;
 /// The contact creation/update request
 mod contact_request;
 /// The contact retrieving request
 mod contact_search_request;
+/// The contact statistics retrieving request
+mod contact_statistics_request;
 /// The contact list creation/update request
 mod contacts_list_request;
 /// The contact list retrieving request
 mod contacts_list_search_request;
+/// The contact list statistics retrieving request
+mod contacts_list_statistics_request;
+/// The sending domain retrieving request
+mod dns_search_request;
+/// The webhook creation/update request
+mod event_callback_url_request;
+/// The webhook retrieving request
+mod event_callback_url_search_request;
+/// The list recipient statistics retrieving request
+mod list_recipient_statistics_request;
+/// The bulk contact management request
+mod manage_many_contacts_request;
 /// The message information searching request
 mod message_information_request;
 /// The message searching request
 mod message_request;
+/// The metasender creation/update request
+mod metasender_request;
+/// The metasender retrieving request
+mod metasender_search_request;
+/// The parse route creation/update request
+mod parse_route_request;
+/// The parse route retrieving request
+mod parse_route_search_request;
+/// The profile update request
+mod profile_request;
 /// The email sending request
 mod send_request;
+/// The email sending request for Send API v3
+mod send_v3_request;
+/// The sender creation/update request
+mod sender_request;
+/// The sender retrieving request
+mod sender_search_request;
+/// The sender statistics retrieving request
+mod sender_statistics_request;
+/// The SMS export request
+mod sms_export_request;
+/// The SMS sending request
+mod sms_request;
+/// The SMS retrieving request
+mod sms_search_request;
+/// The user update request
+mod user_request;
 
+pub use api_key_access_search_request::ApiKeyAccessSearchRequest;
+pub use api_key_request::ApiKeyRequest;
+pub use api_key_search_request::ApiKeySearchRequest;
+pub use contact_filter_request::ContactFilterRequest;
+pub use contact_filter_search_request::ContactFilterSearchRequest;
 pub use contact_request::ContactRequest;
 pub use contact_search_request::ContactSearchRequest;
+pub use contact_statistics_request::ContactStatisticsRequest;
 pub use contacts_list_request::ContactsListRequest;
 pub use contacts_list_search_request::ContactsListSearchRequest;
+pub use contacts_list_statistics_request::ContactsListStatisticsRequest;
+pub use dns_search_request::DnsSearchRequest;
+pub use event_callback_url_request::EventCallbackUrlRequest;
+pub use event_callback_url_search_request::EventCallbackUrlSearchRequest;
+pub use list_recipient_statistics_request::ListRecipientStatisticsRequest;
+pub use manage_many_contacts_request::ManageManyContactsRequest;
 pub use message_information_request::MessageInformationRequest;
 pub use message_request::MessageRequest;
+pub use metasender_request::MetasenderRequest;
+pub use metasender_search_request::MetasenderSearchRequest;
+pub use parse_route_request::ParseRouteRequest;
+pub use parse_route_search_request::ParseRouteSearchRequest;
+pub use profile_request::ProfileRequest;
 pub use send_request::SendRequest;
+pub use send_v3_request::SendV3Request;
+pub use sender_request::SenderRequest;
+pub use sender_search_request::SenderSearchRequest;
+pub use sender_statistics_request::SenderStatisticsRequest;
+pub use sms_export_request::SmsExportRequest;
+pub use sms_request::SmsRequest;
+pub use sms_search_request::SmsSearchRequest;
+pub use user_request::UserRequest;
@@ -0,0 +1,46 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::EmailType;
+use serde::{Deserialize, Serialize};
+
+/// The parse route creation/update request
+#[doc = include_str!("../../doc/receive_inbound_emails.md")]
+#[derive(Serialize, Deserialize, Default)]
+pub struct ParseRouteRequest {
+    /// The URL where received emails are posted
+    ///
+    /// This field is mandatory for creation
+    #[serde(rename = "Url")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The email address receiving the emails
+    ///
+    /// When empty, Mailjet generates an address on its `parse-in1.mailjet.com` domain
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// The type of emails received by this route
+    #[serde(rename = "EmailType")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<EmailType>,
+}
@@ -0,0 +1,94 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The profile update request
+#[derive(Serialize, Deserialize, Default)]
+pub struct ProfileRequest {
+    /// The city of the company's address
+    #[serde(rename = "AddressCity")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_city: Option<String>,
+
+    /// The country of the company's address
+    #[serde(rename = "AddressCountry")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_country: Option<String>,
+
+    /// The postal code of the company's address
+    #[serde(rename = "AddressPostalCode")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_postal_code: Option<String>,
+
+    /// The state of the company's address
+    #[serde(rename = "AddressState")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_state: Option<String>,
+
+    /// The street of the company's address
+    #[serde(rename = "AddressStreet")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address_street: Option<String>,
+
+    /// The email address receiving invoices
+    #[serde(rename = "BillingEmail")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub billing_email: Option<String>,
+
+    /// The company's name
+    #[serde(rename = "CompanyName")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+
+    /// The phone number of the account's owner
+    #[serde(rename = "ContactPhone")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub contact_phone: Option<String>,
+
+    /// The first name of the account's owner
+    #[serde(rename = "Firstname")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub firstname: Option<String>,
+
+    /// The last name of the account's owner
+    #[serde(rename = "Lastname")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lastname: Option<String>,
+
+    /// The company's VAT number
+    #[serde(rename = "VATNumber")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vat_number: Option<String>,
+
+    /// The company's website
+    #[serde(rename = "Website")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub website: Option<String>,
+}
@@ -15,9 +15,9 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
-use crate::data::Message;
+use crate::data::{Globals, Message};
+use crate::template::MessagePreview;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 
 /// The email sending request
 #[doc = include_str!("../../doc/send_basic_email.md")]
@@ -50,7 +50,7 @@ pub struct SendRequest {
     #[serde(rename = "Globals")]
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub globals: Option<Value>,
+    pub globals: Option<Globals>,
 
     /// Activates the sandbox mode where no email is sent
     #[serde(rename = "SandboxMode")]
@@ -58,3 +58,51 @@ pub struct SendRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sandbox_mode: Option<bool>,
 }
+
+impl SendRequest {
+    /// Sets the properties applied to all messages from any structure, like a `serde_json::json!` object
+    ///
+    /// # Parameters
+    ///
+    /// * `globals`: Any structure serialized with the same fields as a message
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use mailjet_api_wrapper::requests::SendRequest;
+    /// use serde_json::json;
+    ///
+    /// let globals = json!({ "TemplateLanguage": true, "Variables": { "day": "Monday", "seats": [1, 2] } });
+    /// let send_request = SendRequest::default().with_globals(&globals).unwrap();
+    ///
+    /// assert_eq!(send_request.globals.unwrap().variables["seats"][1], 2);
+    /// ```
+    pub fn with_globals<T: Serialize>(mut self, globals: &T) -> Result<Self, serde_json::Error> {
+        self.globals = Some(serde_json::from_value(serde_json::to_value(globals)?)?);
+
+        Ok(self)
+    }
+
+    /// Computes the messages Mailjet sends, with the globals merged into each message
+    ///
+    /// Useful to preview, compare or log exactly what each recipient receives
+    pub fn effective_messages(&self) -> Vec<Message> {
+        self.messages
+            .iter()
+            .map(|message| match &self.globals {
+                Some(globals) => globals.apply(message),
+                None => message.clone(),
+            })
+            .collect()
+    }
+
+    /// Renders every message locally, as Mailjet would with the template language enabled
+    ///
+    /// Useful to check templates and variables in CI before sending anything
+    pub fn preview(&self) -> Vec<MessagePreview> {
+        self.messages
+            .iter()
+            .map(|message| MessagePreview::new(message, self.globals.as_ref()))
+            .collect()
+    }
+}
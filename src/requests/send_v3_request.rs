@@ -0,0 +1,33 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::MessageV3;
+use serde::{Deserialize, Serialize};
+
+/// The email sending request for Send API v3
+///
+/// Only use this if your integration depends on Send API v3 semantics, [`SendRequest`](super::SendRequest)
+/// should be preferred otherwise
+#[doc = include_str!("../../doc/send_via_send_api_v3.md")]
+#[derive(Serialize, Deserialize, Default)]
+pub struct SendV3Request {
+    /// The list of all emails to send
+    #[serde(rename = "Messages")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<MessageV3>,
+}
@@ -0,0 +1,51 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::EmailType;
+use serde::{Deserialize, Serialize};
+
+/// The sender creation/update request
+#[doc = include_str!("../../doc/create_a_sender.md")]
+#[derive(Serialize, Deserialize, Default)]
+pub struct SenderRequest {
+    /// The sender email address
+    ///
+    /// Use `*@domain.com` to validate every address of the domain at once.
+    /// This field is mandatory for creation and can't be updated
+    #[serde(rename = "Email")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+
+    /// User-specified name of the sender
+    #[serde(rename = "Name")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// The type of emails sent with this sender
+    #[serde(rename = "EmailType")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email_type: Option<EmailType>,
+
+    /// When true, this sender becomes the default one for its domain
+    #[serde(rename = "IsDefaultSender")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub is_default_sender: Option<bool>,
+}
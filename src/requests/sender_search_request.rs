@@ -0,0 +1,97 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::traits::UrlEncodedRequest;
+use url_builder::URLBuilder;
+
+/// The sender retrieving request
+#[derive(Default)]
+pub struct SenderSearchRequest {
+    /// Retrieves only senders of the domain with this ID
+    pub dns_id: Option<i128>,
+
+    /// Retrieves only senders of this domain
+    pub domain: Option<String>,
+
+    /// Retrieves only the sender with this email address
+    pub email: Option<String>,
+
+    /// When true, domain-wide senders (`*@domain.com`) are included in the response
+    pub is_domain_sender_included: Option<bool>,
+
+    /// Retrieves only senders with this local part (the part before the `@`)
+    pub local_part: Option<String>,
+
+    /// When true, deleted senders are included in the response
+    pub show_deleted: Option<bool>,
+
+    /// Limit the response to a select number of returned objects
+    pub limit: Option<i64>,
+
+    /// Retrieve a list of objects starting from a certain offset
+    pub offset: Option<i64>,
+
+    /// Set the value of this query parameter to 1 to retrieve the overall number of objects returned by this request
+    pub count_only: Option<i64>,
+
+    /// Specify a property name for this query parameter to sort the objects in `data`
+    pub sort: Option<String>,
+}
+
+impl UrlEncodedRequest for SenderSearchRequest {
+    fn add_parameters_to_url(&self, url_builder: &mut URLBuilder) {
+        if let Some(v) = self.dns_id {
+            url_builder.add_param("DnsID", &v.to_string());
+        }
+
+        if let Some(v) = &self.domain {
+            url_builder.add_param("Domain", v);
+        }
+
+        if let Some(v) = &self.email {
+            url_builder.add_param("Email", v);
+        }
+
+        if let Some(v) = self.is_domain_sender_included {
+            url_builder.add_param("IsDomainSenderIncluded", &v.to_string());
+        }
+
+        if let Some(v) = &self.local_part {
+            url_builder.add_param("LocalPart", v);
+        }
+
+        if let Some(v) = self.show_deleted {
+            url_builder.add_param("ShowDeleted", &v.to_string());
+        }
+
+        if let Some(v) = self.limit {
+            url_builder.add_param("Limit", &v.to_string());
+        }
+
+        if let Some(v) = self.offset {
+            url_builder.add_param("Offset", &v.to_string());
+        }
+
+        if let Some(v) = self.count_only {
+            url_builder.add_param("countOnly", &v.to_string());
+        }
+
+        if let Some(v) = &self.sort {
+            url_builder.add_param("Sort", v);
+        }
+    }
+}
@@ -0,0 +1,34 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The SMS export request
+#[derive(Serialize, Deserialize, Default)]
+pub struct SmsExportRequest {
+    /// Only SMS sent after this Unix timestamp are exported
+    #[serde(rename = "FromTS")]
+    #[serde(default)]
+    pub from_ts: i64,
+
+    /// Only SMS sent before this Unix timestamp are exported
+    ///
+    /// The period can't be longer than 1 year
+    #[serde(rename = "ToTS")]
+    #[serde(default)]
+    pub to_ts: i64,
+}
@@ -0,0 +1,40 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The SMS sending request
+#[doc = include_str!("../../doc/send_an_sms.md")]
+#[derive(Serialize, Deserialize, Default)]
+pub struct SmsRequest {
+    /// The sender name, between 3 and 11 alphanumeric characters
+    #[serde(rename = "From")]
+    #[serde(default)]
+    pub from: String,
+
+    /// The recipient's phone number, in international format like `+33600000000`
+    #[serde(rename = "To")]
+    #[serde(default)]
+    pub to: String,
+
+    /// The SMS content
+    ///
+    /// Texts longer than 160 characters are sent in multiple parts
+    #[serde(rename = "Text")]
+    #[serde(default)]
+    pub text: String,
+}
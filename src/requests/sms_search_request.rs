@@ -0,0 +1,69 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::traits::UrlEncodedRequest;
+use url_builder::URLBuilder;
+
+/// The SMS retrieving request
+#[derive(Default)]
+pub struct SmsSearchRequest {
+    /// Retrieves only SMS sent after this Unix timestamp
+    pub from_ts: Option<i64>,
+
+    /// Retrieves only SMS sent before this Unix timestamp
+    pub to_ts: Option<i64>,
+
+    /// Retrieves only SMS sent to this phone number
+    pub to: Option<String>,
+
+    /// Retrieves only SMS with this status code
+    pub status_code: Option<i64>,
+
+    /// Limit the response to a select number of returned objects
+    pub limit: Option<i64>,
+
+    /// Retrieve a list of objects starting from a certain offset
+    pub offset: Option<i64>,
+}
+
+impl UrlEncodedRequest for SmsSearchRequest {
+    fn add_parameters_to_url(&self, url_builder: &mut URLBuilder) {
+        if let Some(v) = self.from_ts {
+            url_builder.add_param("FromTS", &v.to_string());
+        }
+
+        if let Some(v) = self.to_ts {
+            url_builder.add_param("ToTS", &v.to_string());
+        }
+
+        if let Some(v) = &self.to {
+            url_builder.add_param("To", v);
+        }
+
+        if let Some(v) = self.status_code {
+            url_builder.add_param("StatusCode", &v.to_string());
+        }
+
+        if let Some(v) = self.limit {
+            url_builder.add_param("Limit", &v.to_string());
+        }
+
+        if let Some(v) = self.offset {
+            url_builder.add_param("Offset", &v.to_string());
+        }
+    }
+}
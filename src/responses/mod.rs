@@ -15,12 +15,23 @@
 // along with this program; if not, write to the Free Software Foundation,
 // Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
 
-use crate::data::{Contact, ContactsList, MessageData, MessageHistoryData, MessageInformationData};
+use crate::data::{
+    ApiKey, ApiKeyAccess, BatchJob, Contact, ContactFilter, ContactStatistics, ContactsJob,
+    ContactsJobStatus, ContactsList, ContactsListStatistics, Dns, DnsCheckResult,
+    EventCallbackUrl, ListRecipientStatistics, MessageData, MessageHistoryData,
+    MessageInformationData, Metasender, ParseRoute, Profile, Sender, SenderStatistics, Sms, User,
+};
 
 /// The response base for multiple routes
 pub mod generic_response;
 /// The response to email sending
 mod send_response;
+/// The response to email sending via Send API v3
+mod send_v3_response;
+/// The response to a sender validation
+mod sender_validate_response;
+/// The response to SMS counting
+mod sms_count_response;
 /// A response from Mailjet
 pub mod response;
 
@@ -34,6 +45,47 @@ pub type MessageResponse = generic_response::GenericResponse<MessageData>;
 pub type ContactResponse = generic_response::GenericResponse<Contact>;
 /// The response to contact list retrieving
 pub type ContactsListResponse = generic_response::GenericResponse<ContactsList>;
+/// The response to contact statistics retrieving
+pub type ContactStatisticsResponse = generic_response::GenericResponse<ContactStatistics>;
+/// The response to list recipient statistics retrieving
+pub type ListRecipientStatisticsResponse = generic_response::GenericResponse<ListRecipientStatistics>;
+/// The response to contact list statistics retrieving
+pub type ContactsListStatisticsResponse = generic_response::GenericResponse<ContactsListStatistics>;
+/// The response to sender statistics retrieving
+pub type SenderStatisticsResponse = generic_response::GenericResponse<SenderStatistics>;
+/// The response to sender retrieving
+pub type SenderResponse = generic_response::GenericResponse<Sender>;
+/// The response to metasender retrieving
+pub type MetasenderResponse = generic_response::GenericResponse<Metasender>;
+/// The response to sending domain retrieving
+pub type DnsResponse = generic_response::GenericResponse<Dns>;
+/// The response to a sending domain check
+pub type DnsCheckResponse = generic_response::GenericResponse<DnsCheckResult>;
+/// The response to webhook retrieving
+pub type EventCallbackUrlResponse = generic_response::GenericResponse<EventCallbackUrl>;
+/// The response to parse route retrieving
+pub type ParseRouteResponse = generic_response::GenericResponse<ParseRoute>;
+/// The response to a bulk contact management submission
+pub type ContactsJobResponse = generic_response::GenericResponse<ContactsJob>;
+/// The response to bulk contact management job retrieving
+pub type ContactsJobStatusResponse = generic_response::GenericResponse<ContactsJobStatus>;
+/// The response to contact filter retrieving
+pub type ContactFilterResponse = generic_response::GenericResponse<ContactFilter>;
+/// The response to API key retrieving
+pub type ApiKeyResponse = generic_response::GenericResponse<ApiKey>;
+/// The response to API key access retrieving
+pub type ApiKeyAccessResponse = generic_response::GenericResponse<ApiKeyAccess>;
+/// The response to profile retrieving
+pub type ProfileResponse = generic_response::GenericResponse<Profile>;
+/// The response to user retrieving
+pub type UserResponse = generic_response::GenericResponse<User>;
+/// The response to batch job retrieving
+pub type BatchJobResponse = generic_response::GenericResponse<BatchJob>;
+/// The response to SMS retrieving
+pub type SmsResponse = generic_response::GenericResponse<Sms>;
 
 pub use send_response::SendResponse;
+pub use send_v3_response::SendV3Response;
+pub use sender_validate_response::SenderValidateResponse;
+pub use sms_count_response::SmsCountResponse;
 pub use response::Response;
@@ -0,0 +1,38 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// The response to a sender validation
+#[derive(Serialize, Deserialize, Default)]
+pub struct SenderValidateResponse {
+    /// The errors of each validation method, indexed by method name
+    #[serde(rename = "Errors")]
+    #[serde(default)]
+    pub errors: HashMap<String, String>,
+
+    /// The validation method that succeeded, if any
+    #[serde(rename = "ValidationMethod")]
+    #[serde(default)]
+    pub validation_method: String,
+
+    /// A global error preventing the validation
+    #[serde(rename = "GlobalError")]
+    #[serde(default)]
+    pub global_error: String,
+}
@@ -0,0 +1,334 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{FailoverMessage, SendPath, SmtpSender};
+use crate::data::{ResponseError, ResponseMessage};
+use crate::dry_run::{is_refusal, DryRunMode};
+use crate::macros::log::info;
+use crate::requests::SendRequest;
+use crate::Mailjet;
+use std::error::Error as StdError;
+use std::thread;
+use std::time::Duration;
+
+/// What the Send API did with a message
+#[derive(Clone)]
+enum HttpOutcome {
+    /// The message was sent or refused, the SMTP relay mustn't be tried
+    Answered(ResponseMessage),
+    /// The message wasn't sent because the Send API is unavailable
+    Unavailable(ResponseMessage),
+}
+
+/// Sends emails with the Send API v3.1, and through the SMTP relay when the API is unavailable
+///
+/// The Send API is retried on transport errors and `5xx` answers, then the messages it failed to
+/// send are sent through the SMTP relay. Requests refused by Mailjet, like invalid messages, are
+/// never resent. The SMTP relay is never used in dry run mode nor in sandbox mode, and the
+/// client's recipient policy applies to both paths.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use mailjet_api_wrapper::{requests::SendRequest, smtp::{FailoverSender, SmtpSender}, Mailjet};
+///
+/// let mailjet = Mailjet::from_api_keys("your_api_key", "your_api_secret");
+/// let smtp = SmtpSender::from_api_keys("your_api_key", "your_api_secret").unwrap();
+/// let sender = FailoverSender::new(mailjet, smtp);
+///
+/// for message in sender.send(&SendRequest::default()).unwrap_or_default() {
+///     println!("{:?}: {}", message.path, message.response.status);
+/// }
+/// ```
+pub struct FailoverSender {
+    /// The Send API client
+    pub mailjet: Mailjet,
+
+    /// The SMTP relay sender
+    pub smtp: SmtpSender,
+
+    /// The number of times the Send API is retried before failing over
+    ///
+    /// Default is `2`
+    pub retries: u32,
+
+    /// The delay before the first retry, doubled before each next one
+    ///
+    /// Default is 1 second
+    pub retry_delay: Duration,
+}
+
+impl FailoverSender {
+    /// Creates a failover sender
+    ///
+    /// # Parameters
+    ///
+    /// * `mailjet`: The Send API client
+    /// * `smtp`: The SMTP relay sender, usually with the same API keys
+    pub fn new(mailjet: Mailjet, smtp: SmtpSender) -> Self {
+        Self {
+            mailjet,
+            smtp,
+            retries: 2,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+
+    /// Changes how the Send API is retried
+    ///
+    /// # Parameters
+    ///
+    /// * `retries`: The number of retries before failing over
+    /// * `retry_delay`: The delay before the first retry, doubled before each next one
+    pub fn with_retries(mut self, retries: u32, retry_delay: Duration) -> Self {
+        self.retries = retries;
+        self.retry_delay = retry_delay;
+        self
+    }
+
+    /// Sends the messages of a request, and reports the way each one was sent
+    ///
+    /// Returns an error if the request is refused before being sent, like by the recipient
+    /// policy, or if the Send API fails and the SMTP relay can't be used.
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request
+    pub fn send(&self, request: &SendRequest) -> Result<Vec<FailoverMessage>, Box<dyn StdError>> {
+        let failover =
+            self.mailjet.dry_run_mode == DryRunMode::Disabled && request.sandbox_mode != Some(true);
+        let http = match self.send_http(request) {
+            Err(e) if !failover || is_refusal(e.as_ref()) => return Err(e),
+            Err(e) => vec![
+                HttpOutcome::Unavailable(Self::failed(&e.to_string(), 0));
+                request.messages.len()
+            ],
+            Ok(http) => http,
+        };
+        let mut messages = Vec::new();
+
+        for (mut message, http) in request.effective_messages().into_iter().zip(http) {
+            messages.push(match http {
+                HttpOutcome::Unavailable(_) if failover => {
+                    info!("Send API unavailable, sending through the SMTP relay");
+                    self.mailjet.recipient_policy.apply(&mut message)?;
+
+                    FailoverMessage {
+                        path: SendPath::Smtp,
+                        response: self.smtp.send_response_message(&message),
+                    }
+                }
+                HttpOutcome::Answered(response) | HttpOutcome::Unavailable(response) => {
+                    FailoverMessage {
+                        path: SendPath::Http,
+                        response,
+                    }
+                }
+            });
+        }
+
+        Ok(messages)
+    }
+
+    /// Sends a request with the Send API, retrying on transport errors and `5xx` answers
+    ///
+    /// Returns what the Send API did with each message
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request
+    fn send_http(&self, request: &SendRequest) -> Result<Vec<HttpOutcome>, Box<dyn StdError>> {
+        let mut delay = self.retry_delay;
+        let mut attempt = 0;
+
+        loop {
+            let response = match self.mailjet.send(request) {
+                Err(e) if attempt < self.retries && !is_refusal(e.as_ref()) => None,
+                Err(e) => return Err(e),
+                Ok(response)
+                    if attempt < self.retries && Self::is_server_error(response.http_code) =>
+                {
+                    None
+                }
+                Ok(response) => Some(response),
+            };
+
+            if let Some(response) = response {
+                let code = response.http_code.unwrap_or_default();
+                let messages = response.object.map(|o| o.messages).unwrap_or_default();
+
+                if Self::is_server_error(Some(code)) {
+                    let unavailable = Self::failed(&response.raw_data, code);
+
+                    return Ok(vec![
+                        HttpOutcome::Unavailable(unavailable);
+                        request.messages.len()
+                    ]);
+                }
+
+                if messages.len() != request.messages.len() {
+                    let refused = Self::failed(&response.raw_data, code);
+
+                    return Ok(vec![HttpOutcome::Answered(refused); request.messages.len()]);
+                }
+
+                return Ok(messages
+                    .into_iter()
+                    .map(
+                        |message| match message.errors.iter().any(|e| e.status_code >= 500) {
+                            true => HttpOutcome::Unavailable(message),
+                            false => HttpOutcome::Answered(message),
+                        },
+                    )
+                    .collect());
+            }
+
+            attempt += 1;
+            info!("Send API unavailable, retrying in {:?}", delay);
+            thread::sleep(delay);
+            delay = delay.saturating_mul(2);
+        }
+    }
+
+    /// Creates the response of a message the Send API didn't send, for a whole failed request
+    ///
+    /// # Parameters
+    ///
+    /// * `reason`: The error or the body of the Send API's answer
+    /// * `status_code`: The HTTP code, `0` if there was no answer
+    fn failed(reason: &str, status_code: u32) -> ResponseMessage {
+        ResponseMessage {
+            status: "error".to_string(),
+            errors: vec![ResponseError {
+                status_code: status_code.into(),
+                error_message: reason.to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// Returns `true` if the HTTP code is a server error
+    ///
+    /// # Parameters
+    ///
+    /// * `http_code`: The HTTP code, if there was an answer
+    fn is_server_error(http_code: Option<u32>) -> bool {
+        http_code.is_some_and(|code| code >= 500)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::FailoverSender;
+    use crate::data::{EmailAddress, Message};
+    use crate::dry_run::DryRunMode;
+    use crate::requests::SendRequest;
+    use crate::smtp::SendPath;
+    use crate::testing::{MockServer, SmtpSink};
+    use crate::Mailjet;
+    use std::time::Duration;
+
+    /// Creates a request with one valid message
+    fn request() -> SendRequest {
+        SendRequest {
+            messages: vec![Message {
+                from: EmailAddress::from_email("pilot@mailjet.com"),
+                to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+                text_part: "Welcome aboard".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        }
+    }
+
+    /// Creates a failover sender retrying once without delay
+    fn sender(mailjet: Mailjet, sink: &SmtpSink) -> FailoverSender {
+        FailoverSender::new(mailjet, sink.sender()).with_retries(1, Duration::ZERO)
+    }
+
+    #[test]
+    fn http_when_available() {
+        let server = MockServer::start().unwrap();
+        let sink = SmtpSink::start().unwrap();
+
+        let messages = sender(server.client(), &sink).send(&request()).unwrap();
+
+        assert_eq!(messages[0].path, SendPath::Http);
+        assert_eq!(messages[0].response.status, "success");
+        assert!(sink.mails().is_empty());
+    }
+
+    #[test]
+    fn smtp_after_server_errors() {
+        let server = MockServer::start().unwrap();
+        let sink = SmtpSink::start().unwrap();
+
+        server.fail_next(503);
+        server.fail_next(502);
+
+        let messages = sender(server.client(), &sink).send(&request()).unwrap();
+
+        assert_eq!(server.requests().len(), 2);
+        assert_eq!(messages[0].path, SendPath::Smtp);
+        assert_eq!(messages[0].response.status, "success");
+        assert_eq!(sink.mails()[0].rcpt_to, vec!["passenger1@mailjet.com"]);
+    }
+
+    #[test]
+    fn smtp_after_transport_errors() {
+        let sink = SmtpSink::start().unwrap();
+        let mailjet = Mailjet::from_api_keys(MockServer::API_KEY, MockServer::API_SECRET)
+            .with_base_url("http://127.0.0.1:1");
+
+        let messages = sender(mailjet, &sink).send(&request()).unwrap();
+
+        assert_eq!(messages[0].path, SendPath::Smtp);
+        assert_eq!(sink.mails().len(), 1);
+    }
+
+    #[test]
+    fn no_failover_for_refused_requests() {
+        let server = MockServer::start().unwrap();
+        let sink = SmtpSink::start().unwrap();
+        let mut request = request();
+
+        request.messages[0].to.clear();
+
+        let messages = sender(server.client(), &sink).send(&request).unwrap();
+
+        assert_eq!(messages[0].path, SendPath::Http);
+        assert_eq!(messages[0].response.status, "error");
+        assert!(sink.mails().is_empty());
+    }
+
+    #[test]
+    fn no_failover_in_sandbox_mode() {
+        let server = MockServer::start().unwrap();
+        let sink = SmtpSink::start().unwrap();
+        let mailjet = server.client().with_dry_run_mode(DryRunMode::Sandbox);
+
+        server.fail_next(503);
+        server.fail_next(503);
+
+        let messages = sender(mailjet, &sink).send(&request()).unwrap();
+
+        assert_eq!(messages[0].path, SendPath::Http);
+        assert_eq!(messages[0].response.errors[0].status_code, 503);
+        assert!(sink.mails().is_empty());
+    }
+}
@@ -0,0 +1,35 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The result of sending one message with the failover sender
+mod failover_message;
+/// The sender failing over from the Send API to the SMTP relay
+mod failover_sender;
+/// The conversion of Mailjet's options to SMTP relay headers
+mod relay_headers;
+/// The way a message was sent by the failover sender
+mod send_path;
+/// The reasons why a request can't be sent through the SMTP relay
+mod smtp_error;
+/// The SMTP relay sender
+mod smtp_sender;
+
+pub use failover_message::FailoverMessage;
+pub use failover_sender::FailoverSender;
+pub use send_path::SendPath;
+pub use smtp_error::SmtpError;
+pub use smtp_sender::SmtpSender;
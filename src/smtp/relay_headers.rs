@@ -0,0 +1,117 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::data::Message;
+
+/// Converts the Mailjet specific options of a message to the headers read by the SMTP relay
+///
+/// Custom headers of the message with the same names are kept as they are
+///
+/// # Parameters
+///
+/// * `message`: The message
+pub(crate) fn add_relay_headers(message: &mut Message) {
+    let tracking = |tracking: &Option<String>| match tracking.as_deref() {
+        Some("enabled" | "2") => Some("1".to_string()),
+        Some("disabled" | "1") => Some("0".to_string()),
+        _ => None,
+    };
+    let headers = [
+        (
+            "X-MJ-TemplateID",
+            message.template_id.map(|id| id.to_string()),
+        ),
+        (
+            "X-MJ-TemplateLanguage",
+            message
+                .template_language
+                .filter(|enabled| *enabled)
+                .map(|_| "1".to_string()),
+        ),
+        (
+            "X-MJ-TemplateErrorReporting",
+            message
+                .template_error_reporting
+                .as_ref()
+                .map(|a| a.email.clone()),
+        ),
+        (
+            "X-MJ-TemplateErrorDeliver",
+            message
+                .template_error_deliver
+                .filter(|deliver| *deliver)
+                .map(|_| "deliver".to_string()),
+        ),
+        (
+            "X-MJ-Vars",
+            Some(&message.variables)
+                .filter(|variables| !variables.is_empty())
+                .and_then(|variables| serde_json::to_string(variables).ok()),
+        ),
+        ("X-MJ-CustomID", message.custom_id.clone()),
+        ("X-MJ-EventPayload", message.event_payload.clone()),
+        ("X-Mailjet-Campaign", message.custom_campaign.clone()),
+        (
+            "X-Mailjet-DeduplicateCampaign",
+            message
+                .deduplicate_campaign
+                .map(|deduplicate| u8::from(deduplicate).to_string()),
+        ),
+        ("X-Mailjet-TrackOpen", tracking(&message.track_opens)),
+        ("X-Mailjet-TrackClick", tracking(&message.track_clicks)),
+        ("X-Mailjet-Prio", message.priority.map(|p| p.to_string())),
+    ];
+
+    for (name, value) in headers {
+        if let Some(value) = value {
+            message.headers.entry(name.to_string()).or_insert(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::add_relay_headers;
+    use crate::data::Message;
+
+    #[test]
+    fn relay_headers() {
+        let mut message = Message {
+            template_id: Some(1),
+            template_language: Some(true),
+            custom_id: Some("flight-1".to_string()),
+            event_payload: Some("{\"seat\":\"1A\"}".to_string()),
+            custom_campaign: Some("Boarding".to_string()),
+            track_opens: Some("disabled".to_string()),
+            track_clicks: Some("account_default".to_string()),
+            variables: [("day".to_string(), "Monday".into())].into(),
+            headers: [("X-MJ-CustomID".to_string(), "custom".to_string())].into(),
+            ..Default::default()
+        };
+
+        add_relay_headers(&mut message);
+
+        assert_eq!(message.headers["X-MJ-TemplateID"], "1");
+        assert_eq!(message.headers["X-MJ-TemplateLanguage"], "1");
+        assert_eq!(message.headers["X-MJ-CustomID"], "custom");
+        assert_eq!(message.headers["X-MJ-EventPayload"], "{\"seat\":\"1A\"}");
+        assert_eq!(message.headers["X-Mailjet-Campaign"], "Boarding");
+        assert_eq!(message.headers["X-Mailjet-TrackOpen"], "0");
+        assert_eq!(message.headers["X-MJ-Vars"], "{\"day\":\"Monday\"}");
+        assert!(!message.headers.contains_key("X-Mailjet-TrackClick"));
+    }
+}
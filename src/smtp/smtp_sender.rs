@@ -0,0 +1,303 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::relay_headers::add_relay_headers;
+use super::SmtpError;
+use crate::data::{EmailAddress, Message, ResponseError, ResponseMessage, ResponseSuccess};
+use crate::dry_run::validate_message;
+use crate::requests::SendRequest;
+use crate::responses::{Response, SendResponse};
+use lettre::address::{Address, Envelope};
+use lettre::transport::smtp::{authentication::Credentials, SmtpTransport};
+use lettre::Transport as _;
+use serde_json::Value;
+use std::error::Error as StdError;
+
+/// Sends emails through Mailjet's SMTP relay, with the same requests as the Send API v3.1
+///
+/// Mailjet specific options like `custom_id`, `event_payload` or `template_id` are converted to
+/// the `X-MJ-*` and `X-Mailjet-*` headers read by the relay.
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::{requests::SendRequest, smtp::SmtpSender};
+///
+/// let sender = SmtpSender::from_api_keys("your_api_key", "your_api_secret").unwrap();
+/// let response = sender.send(&SendRequest::default()).unwrap_or_default();
+/// ```
+#[derive(Clone)]
+pub struct SmtpSender {
+    /// The SMTP connection settings
+    transport: SmtpTransport,
+}
+
+impl SmtpSender {
+    /// The host of Mailjet's SMTP relay
+    pub const HOST: &'static str = "in-v3.mailjet.com";
+
+    /// The port of Mailjet's SMTP relay, STARTTLS is required
+    pub const PORT: u16 = 587;
+
+    /// Creates a sender using Mailjet's SMTP relay
+    ///
+    /// # Parameters
+    ///
+    /// * `api_key`: The API key, used as the SMTP user name
+    /// * `api_secret`: The API secret, used as the SMTP password
+    pub fn from_api_keys(api_key: &str, api_secret: &str) -> Result<Self, Box<dyn StdError>> {
+        let transport = SmtpTransport::starttls_relay(Self::HOST)?
+            .port(Self::PORT)
+            .credentials(Credentials::new(
+                api_key.to_string(),
+                api_secret.to_string(),
+            ))
+            .build();
+
+        Ok(Self { transport })
+    }
+
+    /// Creates a sender using an SMTP server without encryption, like a local SMTP sink in tests
+    ///
+    /// The credentials are sent in clear text, never use this with Mailjet's relay
+    ///
+    /// # Parameters
+    ///
+    /// * `host`: The server's host
+    /// * `port`: The server's port
+    /// * `api_key`: The SMTP user name
+    /// * `api_secret`: The SMTP password
+    pub fn unencrypted(host: &str, port: u16, api_key: &str, api_secret: &str) -> Self {
+        let transport = SmtpTransport::builder_dangerous(host)
+            .port(port)
+            .credentials(Credentials::new(
+                api_key.to_string(),
+                api_secret.to_string(),
+            ))
+            .build();
+
+        Self { transport }
+    }
+
+    /// Sends the messages of a request, each with the globals merged into it
+    ///
+    /// The response mirrors the Send API v3.1 response: each message gets a status, invalid or
+    /// refused messages get errors. It has no HTTP code, and no message ids as the relay doesn't
+    /// return them.
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request, the sandbox mode isn't supported
+    pub fn send(&self, request: &SendRequest) -> Result<Response<SendResponse>, Box<dyn StdError>> {
+        if request.sandbox_mode == Some(true) {
+            return Err(Box::new(SmtpError::SandboxNotSupported));
+        }
+
+        let messages = request
+            .effective_messages()
+            .iter()
+            .map(|message| self.send_response_message(message))
+            .collect();
+        let object = SendResponse { messages };
+        let raw_data = serde_json::to_string(&object)?;
+
+        Ok(Response::create_from_data(None, raw_data, Some(object)))
+    }
+
+    /// Sends one message, its `Bcc` recipients only appear in the SMTP envelope
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message
+    pub fn send_message(&self, message: &Message) -> Result<(), Box<dyn StdError>> {
+        let address = |address: &EmailAddress| {
+            address
+                .email
+                .parse::<Address>()
+                .map_err(|_| SmtpError::InvalidAddress(address.email.clone()))
+        };
+        let recipients = message
+            .to
+            .iter()
+            .chain(&message.cc)
+            .chain(&message.bcc)
+            .map(address)
+            .collect::<Result<Vec<_>, _>>()?;
+        let envelope = Envelope::new(Some(address(&message.from)?), recipients)?;
+        let mut message = message.clone();
+
+        message.bcc.clear();
+        add_relay_headers(&mut message);
+        self.transport
+            .send_raw(&envelope, message.to_eml()?.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Sends one message and describes the result like the Send API v3.1 does
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message, with the globals merged into it
+    pub(crate) fn send_response_message(&self, message: &Message) -> ResponseMessage {
+        let custom_id = message.custom_id.clone().unwrap_or_default();
+        let mut errors = serde_json::to_value(message)
+            .map(|m| validate_message(&m, &Value::Null))
+            .unwrap_or_default();
+
+        if errors.is_empty() {
+            if let Err(e) = self.send_message(message) {
+                errors.push(ResponseError {
+                    error_code: "smtp".to_string(),
+                    error_message: e.to_string(),
+                    ..Default::default()
+                });
+            }
+        }
+
+        if !errors.is_empty() {
+            return ResponseMessage {
+                status: "error".to_string(),
+                errors,
+                custom_id,
+                ..Default::default()
+            };
+        }
+
+        let sent_to = |addresses: &[EmailAddress]| {
+            addresses
+                .iter()
+                .map(|a| ResponseSuccess {
+                    email: a.email.clone(),
+                    ..Default::default()
+                })
+                .collect()
+        };
+
+        ResponseMessage {
+            status: "success".to_string(),
+            custom_id,
+            to: sent_to(&message.to),
+            cc: sent_to(&message.cc),
+            bcc: sent_to(&message.bcc),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::data::{EmailAddress, Globals, Message};
+    use crate::requests::SendRequest;
+    use crate::smtp::SmtpError;
+    use crate::testing::{MockServer, SmtpSink};
+
+    /// Creates a valid message
+    fn message(to: &str) -> Message {
+        Message {
+            from: EmailAddress::from_email("pilot@mailjet.com"),
+            to: vec![EmailAddress::from_email(to)],
+            text_part: "Welcome aboard".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn send_through_relay() {
+        let sink = SmtpSink::start().unwrap();
+        let request = SendRequest {
+            messages: vec![Message {
+                custom_id: Some("flight-1".to_string()),
+                ..message("passenger1@mailjet.com")
+            }],
+            globals: Some(Globals {
+                bcc: vec![EmailAddress::from_email("tower@mailjet.com")],
+                template_id: Some(1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        let response = sink.sender().send(&request).unwrap();
+        let messages = response.object.unwrap().messages;
+        let mails = sink.mails();
+
+        assert_eq!(messages[0].status, "success");
+        assert_eq!(messages[0].custom_id, "flight-1");
+        assert_eq!(messages[0].bcc[0].email, "tower@mailjet.com");
+        assert_eq!(mails.len(), 1);
+        assert_eq!(mails[0].username.as_deref(), Some(MockServer::API_KEY));
+        assert_eq!(mails[0].mail_from, "pilot@mailjet.com");
+        assert_eq!(
+            mails[0].rcpt_to,
+            vec!["passenger1@mailjet.com", "tower@mailjet.com"]
+        );
+        assert_eq!(mails[0].header("X-MJ-CustomID"), Some("flight-1"));
+        assert_eq!(mails[0].header("X-MJ-TemplateID"), Some("1"));
+        assert_eq!(mails[0].header("Bcc"), None);
+    }
+
+    #[test]
+    fn refused_and_invalid_messages() {
+        let sink = SmtpSink::start().unwrap();
+        let request = SendRequest {
+            messages: vec![
+                message("passenger1@mailjet.com"),
+                Message {
+                    to: Vec::new(),
+                    ..message("passenger2@mailjet.com")
+                },
+                message("passenger3@mailjet.com"),
+            ],
+            ..Default::default()
+        };
+
+        sink.fail_next(550);
+
+        let messages = sink
+            .sender()
+            .send(&request)
+            .unwrap()
+            .object
+            .unwrap()
+            .messages;
+
+        assert_eq!(messages[0].status, "error");
+        assert_eq!(messages[0].errors[0].error_code, "smtp");
+        assert_eq!(messages[1].errors[0].error_code, "mj-0003");
+        assert_eq!(messages[2].status, "success");
+        assert_eq!(sink.mails().len(), 1);
+    }
+
+    #[test]
+    fn sandbox_not_supported() {
+        let sink = SmtpSink::start().unwrap();
+        let request = SendRequest {
+            messages: vec![message("passenger1@mailjet.com")],
+            sandbox_mode: Some(true),
+            ..Default::default()
+        };
+
+        let error = sink.sender().send(&request).err().unwrap();
+
+        assert!(matches!(
+            error.downcast_ref::<SmtpError>(),
+            Some(SmtpError::SandboxNotSupported)
+        ));
+        assert!(sink.mails().is_empty());
+    }
+}
@@ -0,0 +1,483 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde_json::{Map, Value};
+
+/// A comparison or logical operator
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Operator {
+    /// `==`
+    Equal,
+    /// `!=`
+    NotEqual,
+    /// `<`
+    Lower,
+    /// `<=`
+    LowerOrEqual,
+    /// `>`
+    Greater,
+    /// `>=`
+    GreaterOrEqual,
+    /// `and` or `&&`
+    And,
+    /// `or` or `||`
+    Or,
+}
+
+/// Where a variable's value comes from
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub(crate) enum Source {
+    /// `var:`, the message's variables
+    Variables,
+    /// `data:`, the contact's properties, unknown locally
+    ContactData,
+    /// A `{% for %}` loop item
+    Loop,
+}
+
+/// An expression of the template language
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum Expression {
+    /// A string, number or boolean
+    Literal(Value),
+    /// A variable, like `var:user.name:"default"`
+    Variable {
+        /// The variable as written, without default value
+        name: String,
+        /// Where the value comes from
+        source: Source,
+        /// The path to the value, like `["user", "name"]`
+        path: Vec<String>,
+        /// The value when the variable is undefined
+        default: Option<Value>,
+    },
+    /// A negation
+    Not(Box<Expression>),
+    /// A comparison or a logical operation
+    Binary(Box<Expression>, Operator, Box<Expression>),
+}
+
+/// A token of an expression
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    /// A word, like `var:name` or `and`
+    Word(String),
+    /// A quoted string
+    Text(String),
+    /// A number
+    Number(f64),
+    /// An operator or a parenthesis, like `>=`
+    Symbol(String),
+}
+
+/// The state of an expression being evaluated
+pub(crate) struct Scope<'a> {
+    /// The message's variables
+    pub(crate) variables: &'a Map<String, Value>,
+    /// The current loop items, innermost last
+    pub(crate) locals: Vec<(String, Value)>,
+    /// The variables used without value nor default value
+    pub(crate) undefined: Vec<String>,
+}
+
+impl Expression {
+    /// Parses an expression
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The expression, like `var:age >= 18 and not var:blocked`
+    pub(crate) fn parse(source: &str) -> Result<Self, String> {
+        let tokens = Self::tokenize(source)?;
+        let mut index = 0;
+        let expression = Self::parse_or(&tokens, &mut index)?;
+
+        match tokens.get(index) {
+            None => Ok(expression),
+            Some(token) => Err(format!("unexpected {:?}", token)),
+        }
+    }
+
+    /// Splits an expression into tokens
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The expression
+    fn tokenize(source: &str) -> Result<Vec<Token>, String> {
+        let chars: Vec<char> = source.chars().collect();
+        let mut tokens = Vec::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i];
+
+            if c.is_whitespace() {
+                i += 1;
+            } else if c == '"' || c == '\'' {
+                let end = chars[i + 1..]
+                    .iter()
+                    .position(|&d| d == c)
+                    .ok_or("unterminated string")?;
+
+                tokens.push(Token::Text(chars[i + 1..i + 1 + end].iter().collect()));
+                i += end + 2;
+            } else if c.is_ascii_digit()
+                || (c == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit))
+            {
+                let start = i;
+
+                i += 1;
+                while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+                    i += 1;
+                }
+
+                let number: String = chars[start..i].iter().collect();
+
+                tokens.push(Token::Number(
+                    number
+                        .parse()
+                        .map_err(|_| format!("invalid number {}", number))?,
+                ));
+            } else if c.is_alphanumeric() || c == '_' {
+                let start = i;
+
+                while i < chars.len() && (chars[i].is_alphanumeric() || "_.:-".contains(chars[i])) {
+                    i += 1;
+                }
+
+                tokens.push(Token::Word(chars[start..i].iter().collect()));
+            } else {
+                let pair: String = chars[i..(i + 2).min(chars.len())].iter().collect();
+
+                if ["==", "!=", "<=", ">=", "&&", "||"].contains(&pair.as_str()) {
+                    tokens.push(Token::Symbol(pair));
+                    i += 2;
+                } else if "<>!()".contains(c) {
+                    tokens.push(Token::Symbol(c.to_string()));
+                    i += 1;
+                } else {
+                    return Err(format!("unexpected character '{}'", c));
+                }
+            }
+        }
+
+        Ok(tokens)
+    }
+
+    /// Checks whether the next token is one of the given words or symbols, and consumes it
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens`: The tokens
+    /// * `index`: The position of the next token
+    /// * `expected`: The accepted words or symbols
+    fn consume(tokens: &[Token], index: &mut usize, expected: &[&str]) -> bool {
+        let matches = match tokens.get(*index) {
+            Some(Token::Word(w)) | Some(Token::Symbol(w)) => expected.contains(&w.as_str()),
+            _ => false,
+        };
+
+        if matches {
+            *index += 1;
+        }
+
+        matches
+    }
+
+    /// Parses `a or b`
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens`: The tokens
+    /// * `index`: The position of the next token
+    fn parse_or(tokens: &[Token], index: &mut usize) -> Result<Self, String> {
+        let mut left = Self::parse_and(tokens, index)?;
+
+        while Self::consume(tokens, index, &["or", "||"]) {
+            left = Self::Binary(
+                Box::new(left),
+                Operator::Or,
+                Box::new(Self::parse_and(tokens, index)?),
+            );
+        }
+
+        Ok(left)
+    }
+
+    /// Parses `a and b`
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens`: The tokens
+    /// * `index`: The position of the next token
+    fn parse_and(tokens: &[Token], index: &mut usize) -> Result<Self, String> {
+        let mut left = Self::parse_not(tokens, index)?;
+
+        while Self::consume(tokens, index, &["and", "&&"]) {
+            left = Self::Binary(
+                Box::new(left),
+                Operator::And,
+                Box::new(Self::parse_not(tokens, index)?),
+            );
+        }
+
+        Ok(left)
+    }
+
+    /// Parses `not a`
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens`: The tokens
+    /// * `index`: The position of the next token
+    fn parse_not(tokens: &[Token], index: &mut usize) -> Result<Self, String> {
+        match Self::consume(tokens, index, &["not", "!"]) {
+            true => Ok(Self::Not(Box::new(Self::parse_not(tokens, index)?))),
+            false => Self::parse_comparison(tokens, index),
+        }
+    }
+
+    /// Parses `a >= b`
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens`: The tokens
+    /// * `index`: The position of the next token
+    fn parse_comparison(tokens: &[Token], index: &mut usize) -> Result<Self, String> {
+        let left = Self::parse_primary(tokens, index)?;
+        let operator = match tokens.get(*index) {
+            Some(Token::Symbol(s)) => match s.as_str() {
+                "==" => Some(Operator::Equal),
+                "!=" => Some(Operator::NotEqual),
+                "<" => Some(Operator::Lower),
+                "<=" => Some(Operator::LowerOrEqual),
+                ">" => Some(Operator::Greater),
+                ">=" => Some(Operator::GreaterOrEqual),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        match operator {
+            Some(operator) => {
+                *index += 1;
+                Ok(Self::Binary(
+                    Box::new(left),
+                    operator,
+                    Box::new(Self::parse_primary(tokens, index)?),
+                ))
+            }
+            None => Ok(left),
+        }
+    }
+
+    /// Parses a literal, a variable or a parenthesized expression
+    ///
+    /// # Parameters
+    ///
+    /// * `tokens`: The tokens
+    /// * `index`: The position of the next token
+    fn parse_primary(tokens: &[Token], index: &mut usize) -> Result<Self, String> {
+        let token = tokens.get(*index).ok_or("missing value")?.clone();
+
+        *index += 1;
+
+        match token {
+            Token::Text(text) => Ok(Self::Literal(Value::String(text))),
+            Token::Number(number) => Ok(Self::Literal(Self::number(number))),
+            Token::Symbol(s) if s == "(" => {
+                let expression = Self::parse_or(tokens, index)?;
+
+                match Self::consume(tokens, index, &[")"]) {
+                    true => Ok(expression),
+                    false => Err("missing ')'".to_string()),
+                }
+            }
+            Token::Symbol(s) => Err(format!("unexpected '{}'", s)),
+            Token::Word(word) => match word.as_str() {
+                "true" => Ok(Self::Literal(Value::Bool(true))),
+                "false" => Ok(Self::Literal(Value::Bool(false))),
+                "null" => Ok(Self::Literal(Value::Null)),
+                _ => Self::parse_variable(&word, tokens, index),
+            },
+        }
+    }
+
+    /// Parses a variable, like `var:name`, `var:name:"default"` or `item.name`
+    ///
+    /// # Parameters
+    ///
+    /// * `word`: The variable's word
+    /// * `tokens`: The tokens, a quoted default value may follow the word
+    /// * `index`: The position of the next token
+    fn parse_variable(word: &str, tokens: &[Token], index: &mut usize) -> Result<Self, String> {
+        let mut parts = word.splitn(3, ':');
+        let first = parts.next().unwrap_or_default();
+        let (source, path, default) = match (first, parts.next()) {
+            ("var", Some(path)) => (Source::Variables, path, parts.next()),
+            ("data", Some(path)) => (Source::ContactData, path, parts.next()),
+            (_, None) => (Source::Loop, first, None),
+            _ => return Err(format!("unknown variable {}", word)),
+        };
+        let default = match default {
+            Some("") => match tokens.get(*index) {
+                Some(Token::Text(text)) => {
+                    *index += 1;
+                    Some(Value::String(text.clone()))
+                }
+                Some(Token::Number(number)) => {
+                    *index += 1;
+                    Some(Self::number(*number))
+                }
+                _ => return Err(format!("missing default value of {}", word)),
+            },
+            Some(default) => {
+                Some(serde_json::from_str(default).unwrap_or(Value::String(default.to_string())))
+            }
+            None => None,
+        };
+
+        if path.is_empty() || path.split('.').any(str::is_empty) {
+            return Err(format!("invalid variable {}", word));
+        }
+
+        Ok(Self::Variable {
+            name: word
+                .trim_end_matches(':')
+                .split(':')
+                .take(2)
+                .collect::<Vec<_>>()
+                .join(":"),
+            source,
+            path: path.split('.').map(str::to_string).collect(),
+            default,
+        })
+    }
+
+    /// Converts a parsed number to JSON, integers stay integers
+    ///
+    /// # Parameters
+    ///
+    /// * `number`: The number
+    fn number(number: f64) -> Value {
+        match number.fract() == 0.0 && number.abs() < i64::MAX as f64 {
+            true => Value::from(number as i64),
+            false => Value::from(number),
+        }
+    }
+
+    /// Computes the expression's value
+    ///
+    /// Undefined variables are evaluated as `null` and recorded in the scope
+    ///
+    /// # Parameters
+    ///
+    /// * `scope`: The variables
+    pub(crate) fn evaluate(&self, scope: &mut Scope) -> Value {
+        match self {
+            Self::Literal(value) => value.clone(),
+            Self::Variable {
+                name,
+                source,
+                path,
+                default,
+            } => {
+                let root = match source {
+                    Source::Variables => scope.variables.get(&path[0]).cloned(),
+                    Source::ContactData => None,
+                    Source::Loop => scope
+                        .locals
+                        .iter()
+                        .rev()
+                        .find(|(local, _)| *local == path[0])
+                        .map(|(_, value)| value.clone()),
+                };
+                let value = path[1..].iter().fold(root, |value, key| match value? {
+                    Value::Object(mut object) => object.remove(key),
+                    Value::Array(mut array) => {
+                        let i: usize = key.parse().ok()?;
+                        (i < array.len()).then(|| array.swap_remove(i))
+                    }
+                    _ => None,
+                });
+
+                match (value, default) {
+                    (Some(value), _) => value,
+                    (None, Some(default)) => default.clone(),
+                    (None, None) if *source == Source::ContactData => Value::Null,
+                    (None, None) => {
+                        scope.undefined.push(name.clone());
+                        Value::Null
+                    }
+                }
+            }
+            Self::Not(expression) => Value::Bool(!Self::is_truthy(&expression.evaluate(scope))),
+            Self::Binary(left, Operator::And, right) => Value::Bool(
+                Self::is_truthy(&left.evaluate(scope)) && Self::is_truthy(&right.evaluate(scope)),
+            ),
+            Self::Binary(left, Operator::Or, right) => Value::Bool(
+                Self::is_truthy(&left.evaluate(scope)) || Self::is_truthy(&right.evaluate(scope)),
+            ),
+            Self::Binary(left, operator, right) => {
+                let (left, right) = (left.evaluate(scope), right.evaluate(scope));
+
+                Value::Bool(Self::compare(&left, *operator, &right))
+            }
+        }
+    }
+
+    /// Compares two values, numbers and strings are ordered, other values only support equality
+    ///
+    /// # Parameters
+    ///
+    /// * `left`: The left value
+    /// * `operator`: The comparison operator
+    /// * `right`: The right value
+    fn compare(left: &Value, operator: Operator, right: &Value) -> bool {
+        let ordering = match (left, right) {
+            (Value::Number(a), Value::Number(b)) => a.as_f64().partial_cmp(&b.as_f64()),
+            (Value::String(a), Value::String(b)) => Some(a.cmp(b)),
+            (a, b) if a == b => Some(std::cmp::Ordering::Equal),
+            _ => None,
+        };
+
+        match operator {
+            Operator::Equal => ordering.is_some_and(|o| o.is_eq()),
+            Operator::NotEqual => !ordering.is_some_and(|o| o.is_eq()),
+            Operator::Lower => ordering.is_some_and(|o| o.is_lt()),
+            Operator::LowerOrEqual => ordering.is_some_and(|o| o.is_le()),
+            Operator::Greater => ordering.is_some_and(|o| o.is_gt()),
+            Operator::GreaterOrEqual => ordering.is_some_and(|o| o.is_ge()),
+            Operator::And | Operator::Or => false,
+        }
+    }
+
+    /// Checks whether a value counts as true in a condition
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The value
+    pub(crate) fn is_truthy(value: &Value) -> bool {
+        match value {
+            Value::Null => false,
+            Value::Bool(b) => *b,
+            Value::Number(n) => n.as_f64().is_some_and(|n| n != 0.0),
+            Value::String(s) => !s.is_empty(),
+            Value::Array(a) => !a.is_empty(),
+            Value::Object(o) => !o.is_empty(),
+        }
+    }
+}
@@ -0,0 +1,112 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{Template, TemplateError};
+use crate::data::{Globals, Message};
+use serde_json::{Map, Value};
+
+/// A message as Mailjet would render it, with its template errors
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::data::{Globals, Message};
+/// use mailjet_api_wrapper::template::MessagePreview;
+///
+/// let message = Message {
+///     subject: "Hello {{var:name}}".to_string(),
+///     text_part: "{% for item in var:items %}- {{item}}\n{% endfor %}".to_string(),
+///     template_language: Some(true),
+///     variables: [("items".to_string(), "ignored".into())].into(),
+///     ..Default::default()
+/// };
+/// let globals = Globals {
+///     variables: [("name".to_string(), "Jane".into())].into(),
+///     ..Default::default()
+/// };
+/// let preview = MessagePreview::new(&message, Some(&globals));
+///
+/// assert_eq!(preview.subject, "Hello Jane");
+/// assert_eq!(preview.errors[0].0, "TextPart");
+/// ```
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct MessagePreview {
+    /// The rendered subject
+    pub subject: String,
+
+    /// The rendered text part
+    pub text_part: String,
+
+    /// The rendered HTML part
+    pub html_part: String,
+
+    /// The template errors, with the part they are in: `Subject`, `TextPart` or `HTMLPart`
+    pub errors: Vec<(String, TemplateError)>,
+}
+
+impl MessagePreview {
+    /// Renders a message, the parts are left untouched if the template language isn't enabled
+    ///
+    /// # Parameters
+    ///
+    /// * `message`: The message
+    /// * `globals`: The send request's globals, overridden by the message's properties
+    pub fn new(message: &Message, globals: Option<&Globals>) -> Self {
+        let message = match globals {
+            Some(globals) => globals.apply(message),
+            None => message.clone(),
+        };
+
+        if !message.template_language.unwrap_or_default() {
+            return Self {
+                subject: message.subject,
+                text_part: message.text_part,
+                html_part: message.html_part,
+                errors: Vec::new(),
+            };
+        }
+
+        let variables: Map<String, Value> = message.variables.clone().into_iter().collect();
+        let mut errors = Vec::new();
+        let mut render = |part: &str, source: &str| {
+            let (output, part_errors) = Self::render(source, &variables);
+
+            errors.extend(part_errors.into_iter().map(|e| (part.to_string(), e)));
+            output
+        };
+
+        Self {
+            subject: render("Subject", &message.subject),
+            text_part: render("TextPart", &message.text_part),
+            html_part: render("HTMLPart", &message.html_part),
+            errors,
+        }
+    }
+
+    /// Renders a part, a part with a syntax error is left untouched
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The part
+    /// * `variables`: The variables' values
+    fn render(source: &str, variables: &Map<String, Value>) -> (String, Vec<TemplateError>) {
+        match Template::parse(source) {
+            Ok(template) => template.render_with_errors(variables),
+            Err(e) => (source.to_string(), vec![e]),
+        }
+    }
+}
@@ -0,0 +1,31 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::expression::Expression;
+
+/// A part of a parsed template
+#[derive(Clone, PartialEq, Debug)]
+pub(crate) enum Node {
+    /// Text copied as it is
+    Text(String),
+    /// `{{ expression }}`, with its position in the template
+    Output(Expression, usize),
+    /// `{% if %}`, `{% elseif %}` and `{% else %}` branches, each with its position
+    If(Vec<(Expression, usize, Vec<Node>)>, Vec<Node>),
+    /// `{% for item in list %}`, with its position
+    For(String, Expression, usize, Vec<Node>),
+}
@@ -0,0 +1,564 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::expression::{Expression, Scope};
+use super::node::Node;
+use super::TemplateError;
+use serde_json::{Map, Value};
+
+/// A tag of a template being parsed
+enum Tag<'a> {
+    /// Text between tags
+    Text(&'a str),
+    /// `{{ ... }}` content, with its position
+    Output(&'a str, usize),
+    /// `{% ... %}` content, with its position
+    Statement(&'a str, usize),
+}
+
+/// Parsed nodes, and the statement closing them if any, like `("endif", 42)`
+type Block<'a> = (Vec<Node>, Option<(&'a str, usize)>);
+
+/// A template using Mailjet's templating language, parsed
+///
+/// Supports `{{var:name}}` and `{{var:name:"default"}}` variables, `{{data:name}}` contact
+/// properties (rendered with their default value, as contacts are unknown locally),
+/// `{% if %}` / `{% elseif %}` / `{% else %}` / `{% endif %}` conditions and
+/// `{% for item in var:items %}` / `{% endfor %}` loops.
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::template::Template;
+/// use serde_json::json;
+///
+/// let template = Template::parse(
+///     "Hello {{var:name:\"passenger\"}}{% if var:vip %}, welcome aboard{% endif %}!",
+/// )
+/// .unwrap();
+/// let variables = json!({ "vip": true });
+///
+/// assert_eq!(
+///     template.render(variables.as_object().unwrap()).unwrap(),
+///     "Hello passenger, welcome aboard!"
+/// );
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub struct Template {
+    /// The template as written, to locate errors
+    source: String,
+
+    /// The parsed template
+    nodes: Vec<Node>,
+}
+
+impl Template {
+    /// Parses a template
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template, like an email's HTML part
+    pub fn parse(source: &str) -> Result<Self, TemplateError> {
+        let tags = Self::split(source)?;
+        let mut index = 0;
+        let (nodes, end) = Self::parse_block(source, &tags, &mut index)?;
+
+        if let Some((keyword, position)) = end {
+            return Err(Self::syntax_error(
+                source,
+                position,
+                &format!("unexpected {{% {} %}}", keyword),
+            ));
+        }
+
+        Ok(Self {
+            source: source.to_string(),
+            nodes,
+        })
+    }
+
+    /// Renders the template
+    ///
+    /// # Parameters
+    ///
+    /// * `variables`: The variables' values
+    pub fn render(&self, variables: &Map<String, Value>) -> Result<String, Vec<TemplateError>> {
+        let (output, errors) = self.render_with_errors(variables);
+
+        match errors.is_empty() {
+            true => Ok(output),
+            false => Err(errors),
+        }
+    }
+
+    /// Renders the template as well as possible, undefined variables are rendered empty
+    ///
+    /// # Parameters
+    ///
+    /// * `variables`: The variables' values
+    pub(crate) fn render_with_errors(
+        &self,
+        variables: &Map<String, Value>,
+    ) -> (String, Vec<TemplateError>) {
+        let mut scope = Scope {
+            variables,
+            locals: Vec::new(),
+            undefined: Vec::new(),
+        };
+        let mut output = String::new();
+        let mut errors = Vec::new();
+
+        self.render_nodes(&self.nodes, &mut scope, &mut output, &mut errors);
+
+        (output, errors)
+    }
+
+    /// Renders nodes
+    ///
+    /// # Parameters
+    ///
+    /// * `nodes`: The nodes to render
+    /// * `scope`: The variables
+    /// * `output`: Where to write
+    /// * `errors`: Where to report errors
+    fn render_nodes(
+        &self,
+        nodes: &[Node],
+        scope: &mut Scope,
+        output: &mut String,
+        errors: &mut Vec<TemplateError>,
+    ) {
+        for node in nodes {
+            match node {
+                Node::Text(text) => output.push_str(text),
+                Node::Output(expression, position) => {
+                    let value = self.evaluate(expression, *position, scope, errors);
+
+                    output.push_str(&Self::to_text(&value));
+                }
+                Node::If(branches, otherwise) => {
+                    let branch = branches.iter().find(|(condition, position, _)| {
+                        Expression::is_truthy(&self.evaluate(condition, *position, scope, errors))
+                    });
+
+                    match branch {
+                        Some((_, _, nodes)) => self.render_nodes(nodes, scope, output, errors),
+                        None => self.render_nodes(otherwise, scope, output, errors),
+                    }
+                }
+                Node::For(item, list, position, nodes) => {
+                    let items = match self.evaluate(list, *position, scope, errors) {
+                        Value::Array(items) => items,
+                        Value::Null => Vec::new(),
+                        _ => {
+                            let (line, column) = Self::locate(&self.source, *position);
+
+                            errors.push(TemplateError::NotIterable {
+                                line,
+                                column,
+                                name: Self::name_of(list),
+                            });
+                            Vec::new()
+                        }
+                    };
+
+                    for value in items {
+                        scope.locals.push((item.clone(), value));
+                        self.render_nodes(nodes, scope, output, errors);
+                        scope.locals.pop();
+                    }
+                }
+            }
+        }
+    }
+
+    /// Evaluates an expression, and reports its undefined variables
+    ///
+    /// # Parameters
+    ///
+    /// * `expression`: The expression
+    /// * `position`: The expression's position in the template
+    /// * `scope`: The variables
+    /// * `errors`: Where to report errors
+    fn evaluate(
+        &self,
+        expression: &Expression,
+        position: usize,
+        scope: &mut Scope,
+        errors: &mut Vec<TemplateError>,
+    ) -> Value {
+        let value = expression.evaluate(scope);
+        let (line, column) = Self::locate(&self.source, position);
+
+        for name in scope.undefined.drain(..) {
+            errors.push(TemplateError::UndefinedVariable { line, column, name });
+        }
+
+        value
+    }
+
+    /// Converts a value to the text it is rendered as
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The value
+    fn to_text(value: &Value) -> String {
+        match value {
+            Value::Null => String::new(),
+            Value::String(s) => s.clone(),
+            value => value.to_string(),
+        }
+    }
+
+    /// Returns the name of an expression for error messages
+    ///
+    /// # Parameters
+    ///
+    /// * `expression`: The expression
+    fn name_of(expression: &Expression) -> String {
+        match expression {
+            Expression::Variable { name, .. } => name.clone(),
+            expression => format!("{:?}", expression),
+        }
+    }
+
+    /// Splits a template into text, outputs and statements
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    fn split(source: &str) -> Result<Vec<Tag<'_>>, TemplateError> {
+        let mut tags = Vec::new();
+        let mut position = 0;
+
+        while let Some(offset) = source[position..].find('{') {
+            let start = position + offset;
+            let close = match source[start..].get(..2) {
+                Some("{{") => "}}",
+                Some("{%") => "%}",
+                _ => {
+                    tags.push(Tag::Text(&source[position..start + 1]));
+                    position = start + 1;
+                    continue;
+                }
+            };
+            let end = source[start + 2..]
+                .find(close)
+                .map(|end| start + 2 + end)
+                .ok_or_else(|| {
+                    Self::syntax_error(source, start, &format!("missing '{}'", close))
+                })?;
+            let content = source[start + 2..end].trim();
+
+            tags.push(Tag::Text(&source[position..start]));
+            tags.push(match close {
+                "}}" => Tag::Output(content, start),
+                _ => Tag::Statement(content, start),
+            });
+            position = end + 2;
+        }
+
+        tags.push(Tag::Text(&source[position..]));
+
+        Ok(tags)
+    }
+
+    /// Parses tags until the end of the template or a closing statement
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    /// * `tags`: The tags
+    /// * `index`: The position of the next tag
+    fn parse_block<'a>(
+        source: &str,
+        tags: &[Tag<'a>],
+        index: &mut usize,
+    ) -> Result<Block<'a>, TemplateError> {
+        let mut nodes = Vec::new();
+
+        while let Some(tag) = tags.get(*index) {
+            *index += 1;
+
+            match tag {
+                Tag::Text("") => (),
+                Tag::Text(text) => match nodes.last_mut() {
+                    Some(Node::Text(previous)) => previous.push_str(text),
+                    _ => nodes.push(Node::Text(text.to_string())),
+                },
+                Tag::Output(content, position) => {
+                    nodes.push(Node::Output(
+                        Self::parse_expression(source, content, *position)?,
+                        *position,
+                    ));
+                }
+                Tag::Statement(content, position) => {
+                    let (keyword, rest) = content
+                        .split_once(char::is_whitespace)
+                        .unwrap_or((content, ""));
+
+                    match keyword {
+                        "if" => nodes.push(Self::parse_if(source, tags, index, rest, *position)?),
+                        "for" => nodes.push(Self::parse_for(source, tags, index, rest, *position)?),
+                        _ => return Ok((nodes, Some((content, *position)))),
+                    }
+                }
+            }
+        }
+
+        Ok((nodes, None))
+    }
+
+    /// Parses an `{% if %}` statement, until its `{% endif %}`
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    /// * `tags`: The tags
+    /// * `index`: The position of the tag following `{% if %}`
+    /// * `condition`: The first condition
+    /// * `position`: The position of `{% if %}`
+    fn parse_if(
+        source: &str,
+        tags: &[Tag],
+        index: &mut usize,
+        condition: &str,
+        position: usize,
+    ) -> Result<Node, TemplateError> {
+        let mut branches = Vec::new();
+        let mut condition = (
+            Self::parse_expression(source, condition, position)?,
+            position,
+        );
+
+        loop {
+            let (nodes, end) = Self::parse_block(source, tags, index)?;
+            let (statement, end_position) =
+                end.ok_or_else(|| Self::syntax_error(source, position, "missing {% endif %}"))?;
+            let (keyword, rest) = statement
+                .split_once(char::is_whitespace)
+                .unwrap_or((statement, ""));
+
+            branches.push((condition.0, condition.1, nodes));
+
+            match keyword {
+                "elseif" | "elif" => {
+                    condition = (
+                        Self::parse_expression(source, rest, end_position)?,
+                        end_position,
+                    );
+                }
+                "else" => {
+                    let (otherwise, end) = Self::parse_block(source, tags, index)?;
+
+                    return match end {
+                        Some(("endif", _)) => Ok(Node::If(branches, otherwise)),
+                        _ => Err(Self::syntax_error(
+                            source,
+                            end_position,
+                            "missing {% endif %}",
+                        )),
+                    };
+                }
+                "endif" => return Ok(Node::If(branches, Vec::new())),
+                _ => {
+                    return Err(Self::syntax_error(
+                        source,
+                        end_position,
+                        &format!("unexpected {{% {} %}}", statement),
+                    ))
+                }
+            }
+        }
+    }
+
+    /// Parses a `{% for %}` statement, until its `{% endfor %}`
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    /// * `tags`: The tags
+    /// * `index`: The position of the tag following `{% for %}`
+    /// * `header`: The loop header, like `item in var:items`
+    /// * `position`: The position of `{% for %}`
+    fn parse_for(
+        source: &str,
+        tags: &[Tag],
+        index: &mut usize,
+        header: &str,
+        position: usize,
+    ) -> Result<Node, TemplateError> {
+        let (item, list) = header
+            .split_once(" in ")
+            .map(|(item, list)| (item.trim(), list.trim()))
+            .filter(|(item, _)| {
+                !item.is_empty() && item.chars().all(|c| c.is_alphanumeric() || c == '_')
+            })
+            .ok_or_else(|| {
+                Self::syntax_error(source, position, "expected {% for item in list %}")
+            })?;
+        let list = Self::parse_expression(source, list, position)?;
+        let (nodes, end) = Self::parse_block(source, tags, index)?;
+
+        match end {
+            Some(("endfor", _)) => Ok(Node::For(item.to_string(), list, position, nodes)),
+            _ => Err(Self::syntax_error(source, position, "missing {% endfor %}")),
+        }
+    }
+
+    /// Parses an expression, and locates its errors
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    /// * `expression`: The expression
+    /// * `position`: The position of the expression's tag
+    fn parse_expression(
+        source: &str,
+        expression: &str,
+        position: usize,
+    ) -> Result<Expression, TemplateError> {
+        Expression::parse(expression).map_err(|e| Self::syntax_error(source, position, &e))
+    }
+
+    /// Creates a syntax error
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    /// * `position`: The byte position of the error
+    /// * `message`: What is wrong
+    fn syntax_error(source: &str, position: usize, message: &str) -> TemplateError {
+        let (line, column) = Self::locate(source, position);
+
+        TemplateError::Syntax {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    /// Converts a byte position to a line and a column, both starting at 1
+    ///
+    /// # Parameters
+    ///
+    /// * `source`: The template
+    /// * `position`: The byte position
+    fn locate(source: &str, position: usize) -> (usize, usize) {
+        let before = &source[..position.min(source.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before
+            .rsplit('\n')
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .count()
+            + 1;
+
+        (line, column)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Template;
+    use crate::template::TemplateError;
+    use serde_json::json;
+
+    #[test]
+    fn variables_and_defaults() {
+        let template =
+            Template::parse("{{var:firstname}} {{ var:lastname:\"Doe\" }} {{var:age:0}}").unwrap();
+        let variables = json!({ "firstname": "Jane" });
+
+        assert_eq!(
+            template.render(variables.as_object().unwrap()).unwrap(),
+            "Jane Doe 0"
+        );
+    }
+
+    #[test]
+    fn conditions() {
+        let template = Template::parse(
+            "{% if var:age >= 18 and not var:blocked %}adult{% elseif var:age > 12 %}teen{% else %}child{% endif %}",
+        )
+        .unwrap();
+
+        for (age, expected) in [(30, "adult"), (15, "teen"), (5, "child")] {
+            let variables = json!({ "age": age, "blocked": false });
+
+            assert_eq!(
+                template.render(variables.as_object().unwrap()).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn loops() {
+        let template = Template::parse(
+            "{% for band in var:bands %}{{band.name}}{% if band.rock %} (rock){% endif %};{% endfor %}",
+        )
+        .unwrap();
+        let variables = json!({ "bands": [{ "name": "AC/DC", "rock": true }, { "name": "ABBA", "rock": false }] });
+
+        assert_eq!(
+            template.render(variables.as_object().unwrap()).unwrap(),
+            "AC/DC (rock);ABBA;"
+        );
+    }
+
+    #[test]
+    fn undefined_variables() {
+        let template = Template::parse("Hello\n  {{var:name}}{{data:city}}").unwrap();
+        let errors = template.render(&Default::default()).unwrap_err();
+
+        assert_eq!(
+            errors,
+            vec![TemplateError::UndefinedVariable {
+                line: 2,
+                column: 3,
+                name: "var:name".to_string()
+            }]
+        );
+        assert_eq!(
+            errors[0].to_string(),
+            "Line 2, column 3: undefined variable var:name"
+        );
+    }
+
+    #[test]
+    fn syntax_errors() {
+        let cases = [
+            ("{% if var:a %}yes", "missing {% endif %}"),
+            (
+                "{% for x var:a %}{% endfor %}",
+                "expected {% for item in list %}",
+            ),
+            ("{{var:a", "missing '}}'"),
+            ("{% endif %}", "unexpected {% endif %}"),
+            ("{{ var:a > }}", "missing value"),
+        ];
+
+        for (source, expected) in cases {
+            match Template::parse(source) {
+                Err(TemplateError::Syntax { message, .. }) => assert_eq!(message, expected),
+                other => panic!("{} parsed as {:?}", source, other),
+            }
+        }
+    }
+}
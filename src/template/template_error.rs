@@ -0,0 +1,79 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+/// An error in a template, as Mailjet would report it after sending
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum TemplateError {
+    /// The template isn't valid, like an `{% if %}` without `{% endif %}`
+    Syntax {
+        /// The line of the error, starting at 1
+        line: usize,
+        /// The column of the error, starting at 1
+        column: usize,
+        /// What is wrong
+        message: String,
+    },
+    /// A variable is used without value nor default value
+    UndefinedVariable {
+        /// The line of the error, starting at 1
+        line: usize,
+        /// The column of the error, starting at 1
+        column: usize,
+        /// The variable, like `var:name`
+        name: String,
+    },
+    /// A `{% for %}` loop iterates over something that isn't a list
+    NotIterable {
+        /// The line of the error, starting at 1
+        line: usize,
+        /// The column of the error, starting at 1
+        column: usize,
+        /// The variable, like `var:items`
+        name: String,
+    },
+}
+
+impl Display for TemplateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Syntax {
+                line,
+                column,
+                message,
+            } => write!(f, "Line {}, column {}: {}", line, column, message),
+            TemplateError::UndefinedVariable { line, column, name } => {
+                write!(
+                    f,
+                    "Line {}, column {}: undefined variable {}",
+                    line, column, name
+                )
+            }
+            TemplateError::NotIterable { line, column, name } => {
+                write!(
+                    f,
+                    "Line {}, column {}: {} is not a list",
+                    line, column, name
+                )
+            }
+        }
+    }
+}
+
+impl StdError for TemplateError {}
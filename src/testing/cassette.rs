@@ -0,0 +1,353 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{CassetteError, RecordedInteraction, RecordedRequest, RecordedResponse};
+use crate::transport::{CurlTransport, HttpRequest, HttpResponse, Transport};
+use serde_json::Value;
+use std::error::Error as StdError;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, MutexGuard};
+
+/// A transport recording requests and responses to a JSON file, then replaying them
+///
+/// Record once against the real API, commit the file, and replay it in your tests: no network,
+/// no credentials and always the same responses. The API keys and the bearer token are replaced
+/// by [`Cassette::REDACTED`] in everything written to the file.
+///
+/// On replay, each request is answered by the first recorded interaction with the same method,
+/// path, query parameters and body that hasn't been used yet. The base URL and the credentials
+/// don't matter.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use mailjet_api_wrapper::{requests::MessageRequest, testing::Cassette, Mailjet};
+///
+/// // Once, with real credentials
+/// let cassette = Cassette::record("tests/cassettes/messages.json");
+/// let mailjet = Mailjet::from_api_keys("your_api_key", "your_api_secret").with_transport(cassette);
+/// let recorded = mailjet.message(&MessageRequest::default()).unwrap();
+///
+/// // Then in your tests
+/// let cassette = Cassette::replay("tests/cassettes/messages.json").unwrap();
+/// let mailjet = Mailjet::from_api_keys("any", "thing").with_transport(cassette);
+/// let replayed = mailjet.message(&MessageRequest::default()).unwrap();
+///
+/// assert_eq!(recorded.raw_data, replayed.raw_data);
+/// ```
+pub struct Cassette {
+    /// The file holding the interactions
+    path: PathBuf,
+
+    /// The transport executing the requests, only when recording
+    recorder: Option<Box<dyn Transport>>,
+
+    /// The recorded interactions, or the ones not replayed yet
+    interactions: Mutex<Vec<RecordedInteraction>>,
+}
+
+impl Cassette {
+    /// The text replacing credentials in cassettes
+    pub const REDACTED: &'static str = "[REDACTED]";
+
+    /// Creates a cassette recording requests sent with curl, the file is overwritten
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: The file where to write the interactions
+    pub fn record<P: AsRef<Path>>(path: P) -> Self {
+        Self::record_with(path, CurlTransport)
+    }
+
+    /// Creates a cassette recording requests sent with another transport, the file is overwritten
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: The file where to write the interactions
+    /// * `transport`: The transport executing the requests
+    pub fn record_with<P: AsRef<Path>, T: Transport + 'static>(path: P, transport: T) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            recorder: Some(Box::new(transport)),
+            interactions: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Creates a cassette replaying the interactions recorded in a file
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: The file where the interactions were written
+    pub fn replay<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        let content = fs::read_to_string(&path)?;
+        let interactions = serde_json::from_str(&content)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(Self {
+            path: path.as_ref().to_path_buf(),
+            recorder: None,
+            interactions: Mutex::new(interactions),
+        })
+    }
+
+    /// Returns `true` if the cassette records, `false` if it replays
+    pub fn is_recording(&self) -> bool {
+        self.recorder.is_some()
+    }
+
+    /// Returns the recorded interactions when recording, the ones not replayed yet otherwise
+    pub fn interactions(&self) -> Vec<RecordedInteraction> {
+        self.lock().clone()
+    }
+
+    /// Locks the interactions, even if a previous holder panicked
+    fn lock(&self) -> MutexGuard<'_, Vec<RecordedInteraction>> {
+        self.interactions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Replaces secrets in a text
+    ///
+    /// # Parameters
+    ///
+    /// * `text`: The text to redact
+    /// * `secrets`: The values to replace
+    fn redact(text: &str, secrets: &[&str]) -> String {
+        secrets
+            .iter()
+            .filter(|secret| !secret.is_empty())
+            .fold(text.to_string(), |text, secret| {
+                text.replace(secret, Self::REDACTED)
+            })
+    }
+
+    /// Converts a body to JSON, keeping it as a string if it isn't valid JSON
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The body
+    fn to_value(body: &str) -> Value {
+        match body.is_empty() {
+            true => Value::Null,
+            false => serde_json::from_str(body).unwrap_or_else(|_| Value::String(body.to_string())),
+        }
+    }
+
+    /// Converts a recorded body back to text
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The recorded body
+    fn from_value(body: &Value) -> String {
+        match body {
+            Value::Null => String::new(),
+            Value::String(body) => body.clone(),
+            body => body.to_string(),
+        }
+    }
+
+    /// Returns the path and query string of a URL, without the scheme and the host
+    ///
+    /// # Parameters
+    ///
+    /// * `url`: The complete URL
+    fn path_of(url: &str) -> String {
+        let without_scheme = url.split_once("://").map(|(_, rest)| rest).unwrap_or(url);
+
+        match without_scheme.find('/') {
+            Some(index) => without_scheme[index..].to_string(),
+            None => "/".to_string(),
+        }
+    }
+
+    /// Splits a path into its path and sorted query parameters, to compare paths regardless of
+    /// the parameters' order
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: The path and query string
+    fn normalize(path: &str) -> (&str, Vec<&str>) {
+        let (path, query) = path.split_once('?').unwrap_or((path, ""));
+        let mut parameters: Vec<&str> = query.split('&').filter(|p| !p.is_empty()).collect();
+
+        parameters.sort_unstable();
+        (path, parameters)
+    }
+
+    /// Converts a request to its recorded form, credentials redacted
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request
+    fn to_recorded(request: &HttpRequest) -> RecordedRequest {
+        let secrets = request.credentials.secrets();
+
+        RecordedRequest {
+            method: request.method,
+            path: Self::redact(&Self::path_of(&request.url), &secrets),
+            body: Self::to_value(&Self::redact(
+                request.body.as_deref().unwrap_or_default(),
+                &secrets,
+            )),
+        }
+    }
+
+    /// Checks whether two recorded requests are the same
+    ///
+    /// # Parameters
+    ///
+    /// * `a`: The first request
+    /// * `b`: The second request
+    fn matches(a: &RecordedRequest, b: &RecordedRequest) -> bool {
+        a.method == b.method
+            && Self::normalize(&a.path) == Self::normalize(&b.path)
+            && a.body == b.body
+    }
+
+    /// Writes the interactions to the cassette's file, creating its directory if needed
+    ///
+    /// # Parameters
+    ///
+    /// * `interactions`: The interactions to write
+    fn save(&self, interactions: &[RecordedInteraction]) -> io::Result<()> {
+        if let Some(directory) = self.path.parent().filter(|d| !d.as_os_str().is_empty()) {
+            fs::create_dir_all(directory)?;
+        }
+
+        let content = serde_json::to_string_pretty(interactions)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        fs::write(&self.path, content)
+    }
+}
+
+impl Transport for Cassette {
+    fn execute(&self, request: &HttpRequest) -> Result<HttpResponse, Box<dyn StdError>> {
+        let recorded = Self::to_recorded(request);
+
+        if let Some(recorder) = &self.recorder {
+            let response = recorder.execute(request)?;
+            let mut interactions = self.lock();
+
+            interactions.push(RecordedInteraction {
+                request: recorded,
+                response: RecordedResponse {
+                    http_code: response.http_code,
+                    body: Self::to_value(&Self::redact(
+                        &response.body,
+                        &request.credentials.secrets(),
+                    )),
+                },
+            });
+            self.save(&interactions)?;
+
+            return Ok(response);
+        }
+
+        let mut interactions = self.lock();
+        let index = interactions
+            .iter()
+            .position(|interaction| Self::matches(&interaction.request, &recorded))
+            .ok_or_else(|| CassetteError::Unmatched(recorded.method, recorded.path.clone()))?;
+        let interaction = interactions.remove(index);
+
+        Ok(HttpResponse {
+            http_code: interaction.response.http_code,
+            body: Self::from_value(&interaction.response.body),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Cassette;
+    use crate::data::ContactIdentifier;
+    use crate::requests::{ContactRequest, MessageRequest};
+    use crate::testing::{seed, MockServer};
+    use crate::Mailjet;
+    use std::fs;
+
+    #[test]
+    fn record_then_replay_without_server() {
+        let path =
+            std::env::temp_dir().join(format!("mailjet-cassette-{}.json", std::process::id()));
+        let request = ContactRequest {
+            email: Some("dummy@example.com".to_string()),
+            ..Default::default()
+        };
+
+        let server = MockServer::start().unwrap();
+        let recording = server.client().with_transport(Cassette::record(&path));
+        let created = recording.contact_create(&request).unwrap();
+        let api_key = recording.api_key_search_from_id(seed::API_KEY_ID).unwrap();
+        drop(server);
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(api_key.raw_data.contains(MockServer::API_SECRET));
+        assert!(!content.contains(MockServer::API_SECRET));
+        assert!(content.contains(Cassette::REDACTED));
+
+        let replaying = Mailjet::from_api_keys("other_key", "other_secret")
+            .with_transport(Cassette::replay(&path).unwrap());
+        let replayed = replaying.contact_create(&request).unwrap();
+
+        assert_eq!(replayed.raw_data, created.raw_data);
+        assert_eq!(replayed.http_code, Some(201));
+        assert!(replaying
+            .api_key_search_from_id(seed::API_KEY_ID)
+            .unwrap()
+            .raw_data
+            .contains(Cassette::REDACTED));
+        assert!(replaying
+            .contact_search_from_id_or_email(&ContactIdentifier::ContactId(seed::CONTACT_ID))
+            .is_err());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn query_order_is_ignored() {
+        let (a, b) = (
+            Cassette::normalize("/v3/REST/message?Limit=10&CampaignID=4001"),
+            Cassette::normalize("/v3/REST/message?CampaignID=4001&Limit=10"),
+        );
+
+        assert_eq!(a, b);
+        assert_eq!(
+            Cassette::path_of("http://127.0.0.1:8080/v3/REST/message"),
+            "/v3/REST/message"
+        );
+    }
+
+    #[test]
+    fn unused_requests_are_not_replayed() {
+        let cassette = Cassette {
+            path: Default::default(),
+            recorder: None,
+            interactions: Default::default(),
+        };
+        let mailjet = Mailjet::from_api_keys("key", "secret").with_transport(cassette);
+
+        let error = mailjet.message(&MessageRequest::default()).err().unwrap();
+
+        assert_eq!(
+            error.to_string(),
+            "No recorded interaction matches GET /v3/REST/message"
+        );
+    }
+}
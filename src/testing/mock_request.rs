@@ -0,0 +1,181 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde_json::Value;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+
+/// A request received by the mock server
+#[derive(Clone, Debug, Default)]
+pub struct MockRequest {
+    /// The HTTP method, like `GET`
+    pub method: String,
+
+    /// The path, without the query string
+    pub path: String,
+
+    /// The query string parameters, decoded
+    pub query: HashMap<String, String>,
+
+    /// The headers, in reception order
+    pub headers: Vec<(String, String)>,
+
+    /// The body
+    pub body: String,
+}
+
+impl MockRequest {
+    /// Returns the value of a header, the name is case insensitive
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: The header's name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Returns the body parsed as JSON, `Value::Null` if it isn't valid JSON
+    pub fn json(&self) -> Value {
+        serde_json::from_str(&self.body).unwrap_or(Value::Null)
+    }
+
+    /// Reads a request from a connection
+    ///
+    /// Answers `100 Continue` when the client expects it, and decodes chunked bodies
+    ///
+    /// # Parameters
+    ///
+    /// * `stream`: The connection
+    pub(crate) fn read_from(stream: &mut TcpStream) -> io::Result<Self> {
+        let mut reader = BufReader::new(stream.try_clone()?);
+        let mut line = String::new();
+
+        reader.read_line(&mut line)?;
+
+        let mut parts = line.split_whitespace();
+        let method = parts.next().unwrap_or_default().to_string();
+        let target = parts.next().unwrap_or_default().to_string();
+        let (path, query) = target.split_once('?').unwrap_or((&target, ""));
+        let mut request = Self {
+            method,
+            path: path.to_string(),
+            query: query
+                .split('&')
+                .filter(|pair| !pair.is_empty())
+                .map(|pair| {
+                    let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+                    (Self::decode(key), Self::decode(value))
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        loop {
+            line.clear();
+            reader.read_line(&mut line)?;
+
+            let header = line.trim_end();
+
+            if header.is_empty() {
+                break;
+            }
+
+            if let Some((key, value)) = header.split_once(':') {
+                request
+                    .headers
+                    .push((key.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        if request
+            .header("Expect")
+            .is_some_and(|v| v.eq_ignore_ascii_case("100-continue"))
+        {
+            stream.write_all(b"HTTP/1.1 100 Continue\r\n\r\n")?;
+        }
+
+        let mut body = Vec::new();
+
+        if request
+            .header("Transfer-Encoding")
+            .is_some_and(|v| v.eq_ignore_ascii_case("chunked"))
+        {
+            loop {
+                line.clear();
+                reader.read_line(&mut line)?;
+
+                let size = usize::from_str_radix(line.trim(), 16)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                if size == 0 {
+                    line.clear();
+                    reader.read_line(&mut line)?;
+                    break;
+                }
+
+                let mut chunk = vec![0; size + 2];
+                reader.read_exact(&mut chunk)?;
+                body.extend_from_slice(&chunk[..size]);
+            }
+        } else if let Some(length) = request.header("Content-Length") {
+            let length = length.parse().unwrap_or_default();
+            body.resize(length, 0);
+            reader.read_exact(&mut body)?;
+        }
+
+        request.body = String::from_utf8_lossy(&body).to_string();
+        Ok(request)
+    }
+
+    /// Decodes a percent-encoded query string component
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The encoded value
+    fn decode(value: &str) -> String {
+        let bytes = value.as_bytes();
+        let mut decoded = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'%' if i + 2 < bytes.len() => {
+                    let byte = std::str::from_utf8(&bytes[i + 1..i + 3])
+                        .ok()
+                        .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+
+                    match byte {
+                        Some(byte) => {
+                            decoded.push(byte);
+                            i += 2;
+                        }
+                        None => decoded.push(b'%'),
+                    }
+                }
+                b'+' => decoded.push(b' '),
+                byte => decoded.push(byte),
+            }
+
+            i += 1;
+        }
+
+        String::from_utf8_lossy(&decoded).to_string()
+    }
+}
@@ -0,0 +1,123 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde_json::{json, Value};
+use std::io::{self, Write};
+use std::net::TcpStream;
+
+/// A response sent by the mock server
+#[derive(Clone, Debug, Default)]
+pub struct MockResponse {
+    /// The HTTP status code
+    pub status: u16,
+
+    /// The body, empty or JSON
+    pub body: String,
+}
+
+impl MockResponse {
+    /// Creates a response with a JSON body
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: The HTTP status code
+    /// * `body`: The body
+    pub fn json(status: u16, body: &Value) -> Self {
+        Self {
+            status,
+            body: body.to_string(),
+        }
+    }
+
+    /// Creates a response without body
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: The HTTP status code
+    pub fn empty(status: u16) -> Self {
+        Self {
+            status,
+            body: String::new(),
+        }
+    }
+
+    /// Creates a list response like the ones of `/v3/REST` routes
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: The HTTP status code
+    /// * `data`: The objects of the `Data` array
+    /// * `total`: The total number of objects matching the request
+    pub fn list(status: u16, data: Vec<Value>, total: usize) -> Self {
+        Self::json(
+            status,
+            &json!({ "Count": data.len(), "Data": data, "Total": total }),
+        )
+    }
+
+    /// Creates an error response like Mailjet's
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: The HTTP status code
+    /// * `message`: The error message, a default one is used for the status when empty
+    pub fn error(status: u16, message: &str) -> Self {
+        let message = match (message, status) {
+            ("", 400) => "Bad request",
+            ("", 401) => "API key authentication/authorization failure. You may be unauthorized to access the API or your API key may be expired. Visit API keys management section to check your keys.",
+            ("", 404) => "Object not found",
+            ("", 405) => "Operation not allowed",
+            ("", 429) => "Too many requests, please slow down",
+            ("", _) => "Internal server error",
+            (message, _) => message,
+        };
+
+        Self::json(
+            status,
+            &json!({ "ErrorInfo": "", "ErrorMessage": message, "StatusCode": status }),
+        )
+    }
+
+    /// Writes the response to a connection, then closes it
+    ///
+    /// # Parameters
+    ///
+    /// * `stream`: The connection
+    pub(crate) fn write_to(&self, stream: &mut TcpStream) -> io::Result<()> {
+        let reason = match self.status {
+            200 => "OK",
+            201 => "Created",
+            204 => "No Content",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            429 => "Too Many Requests",
+            _ => "Internal Server Error",
+        };
+
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason,
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()
+    }
+}
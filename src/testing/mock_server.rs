@@ -0,0 +1,157 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::mock_state::MockState;
+use super::MockRequest;
+use crate::Mailjet;
+use std::io;
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// A mock Mailjet server, running in a background thread until dropped
+///
+/// The server starts with the objects described in [`seed`](super::seed), and keeps the objects
+/// created, updated or deleted by the requests it receives. Send routes validate messages like
+/// Mailjet does and answer `400 Bad Request` with the same errors.
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::{
+///     data::ContactIdentifier,
+///     testing::{seed, MockServer},
+/// };
+///
+/// let server = MockServer::start().unwrap();
+/// let mailjet = server.client();
+///
+/// let response = mailjet
+///     .contact_search_from_id_or_email(&ContactIdentifier::ContactId(seed::CONTACT_ID))
+///     .unwrap();
+///
+/// assert_eq!(response.object.unwrap().data[0].email, seed::CONTACT_EMAIL);
+/// ```
+pub struct MockServer {
+    /// The address the server listens on
+    address: SocketAddr,
+
+    /// The state shared with the server's thread
+    state: Arc<Mutex<MockState>>,
+
+    /// `false` when the server must stop
+    running: Arc<AtomicBool>,
+
+    /// The server's thread
+    handle: Option<JoinHandle<()>>,
+}
+
+impl MockServer {
+    /// The API key accepted by the server
+    pub const API_KEY: &'static str = "mock_api_key";
+
+    /// The API secret accepted by the server
+    pub const API_SECRET: &'static str = "mock_api_secret";
+
+    /// The bearer token accepted by the server's SMS routes
+    pub const BEARER_TOKEN: &'static str = "mock_bearer_token";
+
+    /// Starts a server on a random local port
+    pub fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(MockState::new()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread_state = state.clone();
+        let thread_running = running.clone();
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if !thread_running.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Ok(mut stream) = stream {
+                    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+
+                    if let Ok(request) = MockRequest::read_from(&mut stream) {
+                        let response = Self::lock(&thread_state).handle(request);
+                        let _ = response.write_to(&mut stream);
+                    }
+                }
+            }
+        });
+
+        Ok(Self {
+            address,
+            state,
+            running,
+            handle: Some(handle),
+        })
+    }
+
+    /// Returns the server's base URL, like `http://127.0.0.1:12345`
+    pub fn base_url(&self) -> String {
+        format!("http://{}", self.address)
+    }
+
+    /// Returns a client authenticated to the server
+    pub fn client(&self) -> Mailjet {
+        Mailjet::from_api_keys_and_bearer_token(Self::API_KEY, Self::API_SECRET, Self::BEARER_TOKEN)
+            .with_base_url(&self.base_url())
+    }
+
+    /// Makes the next request fail with a status code, like `429` to simulate rate limiting
+    ///
+    /// Calls are queued: each one makes one more request fail.
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: The HTTP status code
+    pub fn fail_next(&self, status: u16) {
+        Self::lock(&self.state).fail_next(status);
+    }
+
+    /// Returns the requests received so far
+    pub fn requests(&self) -> Vec<MockRequest> {
+        Self::lock(&self.state).requests.clone()
+    }
+
+    /// Locks the state, even if a previous holder panicked
+    ///
+    /// # Parameters
+    ///
+    /// * `state`: The shared state
+    fn lock(state: &Mutex<MockState>) -> MutexGuard<'_, MockState> {
+        state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for MockServer {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+
+        // Wakes the thread up, blocked on `accept`
+        let _ = TcpStream::connect(self.address);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
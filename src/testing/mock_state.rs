@@ -0,0 +1,840 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{MockRequest, MockResponse, MockServer};
+use crate::data::EmailAddress;
+use crate::dry_run::validate_message;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use serde_json::{json, Map, Value};
+use std::collections::{HashMap, VecDeque};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// `/v3/REST` resources that can be created, updated and deleted
+const WRITABLE_RESOURCES: [&str; 8] = [
+    "apikey",
+    "contact",
+    "contactfilter",
+    "contactslist",
+    "eventcallbackurl",
+    "metasender",
+    "parseroute",
+    "sender",
+];
+
+/// `/v3/REST` resources holding a single object, updated without id
+const SINGLETON_RESOURCES: [&str; 2] = ["myprofile", "user"];
+
+/// The date given to created objects
+const NOW: &str = "2024-01-01T00:00:00Z";
+
+/// The state of the mock server: stored objects, pending failures and received requests
+pub(crate) struct MockState {
+    /// The objects, by resource name
+    collections: HashMap<String, Vec<Value>>,
+
+    /// The id of the next created object
+    next_id: i128,
+
+    /// The status codes to answer to the next requests, whatever they are
+    failures: VecDeque<u16>,
+
+    /// The requests received so far
+    pub(crate) requests: Vec<MockRequest>,
+}
+
+impl MockState {
+    /// Creates a state holding the seeded objects
+    pub(crate) fn new() -> Self {
+        Self {
+            collections: serde_json::from_str(include_str!("seed.json")).unwrap_or_default(),
+            next_id: 100_000,
+            failures: VecDeque::new(),
+            requests: Vec::new(),
+        }
+    }
+
+    /// Makes the next request fail with a status code
+    ///
+    /// # Parameters
+    ///
+    /// * `status`: The HTTP status code
+    pub(crate) fn fail_next(&mut self, status: u16) {
+        self.failures.push_back(status);
+    }
+
+    /// Answers a request
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The received request
+    pub(crate) fn handle(&mut self, request: MockRequest) -> MockResponse {
+        self.requests.push(request.clone());
+
+        if let Some(status) = self.failures.pop_front() {
+            return MockResponse::error(status, "");
+        }
+
+        let segments: Vec<&str> = request.path.split('/').filter(|s| !s.is_empty()).collect();
+        let uses_token = segments.first() == Some(&"v4")
+            && segments.get(1).is_some_and(|s| s.starts_with("sms"));
+
+        if !self.is_authorized(&request, uses_token) {
+            return MockResponse::error(401, "");
+        }
+
+        let body = request.json();
+
+        match (request.method.as_str(), segments.as_slice()) {
+            ("POST", ["v3.1", "send"]) => self.send(&body),
+            ("POST", ["v3", "send"]) => self.send_v3(&body),
+            ("POST", ["v4", "sms-send"]) => self.sms_send(&body),
+            ("GET", ["v4", "sms"]) => self.search("sms", &request.query),
+            ("GET", ["v4", "sms", "count"]) => {
+                MockResponse::json(200, &json!({ "Count": self.collection("sms").len() }))
+            }
+            ("POST", ["v4", "sms", "export"]) => self.sms_export_create(&body),
+            ("GET", ["v4", "sms", "export", id]) => self.sms_export_get(id),
+            ("GET", ["v4", "sms", id]) => match self.find("sms", id) {
+                Some(sms) => MockResponse::json(200, &sms),
+                None => MockResponse::error(404, ""),
+            },
+            ("DELETE", ["v4", "contacts", id]) => match self.delete("contact", id) {
+                0 => MockResponse::error(404, ""),
+                _ => MockResponse::empty(200),
+            },
+            ("POST", ["v3", "REST", "contact", "managemanycontacts"]) => {
+                self.manage_many_contacts(&body)
+            }
+            ("GET", ["v3", "REST", "contact", "managemanycontacts", id]) => {
+                self.get("managemanycontacts", id)
+            }
+            ("POST", ["v3", "REST", "sender", id, "validate"]) => self.sender_validate(id),
+            ("POST", ["v3", "REST", "dns", id, "check"]) => self.dns_check(id),
+            (method, ["v3", "REST", resource]) => match method {
+                "GET" => self.search(resource, &request.query),
+                "POST" if WRITABLE_RESOURCES.contains(resource) => self.create(resource, &body),
+                "PUT" if SINGLETON_RESOURCES.contains(resource) => {
+                    self.update(resource, None, &body)
+                }
+                _ => MockResponse::error(405, ""),
+            },
+            (method, ["v3", "REST", resource, id]) => match method {
+                "GET" => self.get(resource, id),
+                "PUT" if WRITABLE_RESOURCES.contains(resource) => {
+                    self.update(resource, Some(id), &body)
+                }
+                "DELETE" if WRITABLE_RESOURCES.contains(resource) => {
+                    match self.delete(resource, id) {
+                        0 => MockResponse::error(404, ""),
+                        _ => MockResponse::empty(204),
+                    }
+                }
+                _ => MockResponse::error(405, ""),
+            },
+            _ => MockResponse::error(404, ""),
+        }
+    }
+
+    /// Checks the request's credentials
+    ///
+    /// Basic authentication accepts any active API key of the account, SMS routes expect
+    /// [`MockServer::BEARER_TOKEN`]
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The received request
+    /// * `uses_token`: `true` if the route expects a bearer token
+    fn is_authorized(&self, request: &MockRequest, uses_token: bool) -> bool {
+        let header = request.header("Authorization").unwrap_or_default();
+
+        if uses_token {
+            return header == format!("Bearer {}", MockServer::BEARER_TOKEN);
+        }
+
+        self.collection("apikey").iter().any(|key| {
+            let credentials = format!(
+                "{}:{}",
+                key["APIKey"].as_str().unwrap_or_default(),
+                key["SecretKey"].as_str().unwrap_or_default()
+            );
+
+            key["IsActive"] == true && header == format!("Basic {}", STANDARD.encode(credentials))
+        })
+    }
+
+    /// Returns the objects of a resource
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    fn collection(&self, resource: &str) -> &[Value] {
+        self.collections
+            .get(resource)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Returns a new unique id
+    fn new_id(&mut self) -> i128 {
+        self.next_id += 1;
+        self.next_id
+    }
+
+    /// Formats an id as a UUID
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: The id
+    fn uuid(id: i128) -> String {
+        format!("00000000-0000-4000-8000-{:012x}", id)
+    }
+
+    /// Returns the current Unix timestamp
+    fn timestamp() -> i64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default()
+    }
+
+    /// Converts a JSON value to a string for comparisons
+    ///
+    /// # Parameters
+    ///
+    /// * `value`: The value
+    fn as_string(value: &Value) -> String {
+        match value {
+            Value::String(v) => v.clone(),
+            v => v.to_string(),
+        }
+    }
+
+    /// Checks whether an object is identified by an URL identifier
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `object`: The object
+    /// * `id`: The identifier, an id or a unique field like the email address
+    fn is_identified_by(resource: &str, object: &Value, id: &str) -> bool {
+        let keys: &[&str] = match resource {
+            "contactstatistics" => &["ContactID"],
+            "senderstatistics" => &["SenderID"],
+            "messagehistory" => &["MessageID"],
+            _ => &["ID", "Email", "Address", "Domain", "Name"],
+        };
+
+        keys.iter()
+            .any(|key| object.get(key).is_some_and(|v| Self::as_string(v) == id))
+    }
+
+    /// Returns the first object identified by an URL identifier
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `id`: The identifier
+    fn find(&self, resource: &str, id: &str) -> Option<Value> {
+        self.collection(resource)
+            .iter()
+            .find(|o| Self::is_identified_by(resource, o, id))
+            .cloned()
+    }
+
+    /// Answers `GET /v3/REST/{resource}/{id}`
+    ///
+    /// Contact statistics can also be retrieved with the contact's email address
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `id`: The identifier
+    fn get(&self, resource: &str, id: &str) -> MockResponse {
+        let mut id = id.to_string();
+
+        if resource == "contactstatistics" && id.parse::<i128>().is_err() {
+            if let Some(contact) = self.find("contact", &id) {
+                id = Self::as_string(&contact["ID"]);
+            }
+        }
+
+        let data: Vec<Value> = self
+            .collection(resource)
+            .iter()
+            .filter(|o| Self::is_identified_by(resource, o, &id))
+            .cloned()
+            .collect();
+
+        match data.len() {
+            0 => MockResponse::error(404, ""),
+            total => MockResponse::list(200, data, total),
+        }
+    }
+
+    /// Answers `GET /v3/REST/{resource}` and `GET /v4/sms`
+    ///
+    /// Query parameters filter objects having a field of the same name, or of the same name
+    /// followed by `ID`. `Limit`, `Offset` and `countOnly` are supported
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `query`: The query parameters
+    fn search(&self, resource: &str, query: &HashMap<String, String>) -> MockResponse {
+        let limit: usize = query
+            .get("Limit")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(10);
+        let offset: usize = query
+            .get("Offset")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or_default();
+        let matching: Vec<&Value> = self
+            .collection(resource)
+            .iter()
+            .filter(|object| {
+                query.iter().all(|(key, expected)| {
+                    match object.get(key).or_else(|| object.get(format!("{}ID", key))) {
+                        Some(value) => Self::as_string(value).eq_ignore_ascii_case(expected),
+                        None => true,
+                    }
+                })
+            })
+            .collect();
+        let total = matching.len();
+
+        if query.get("countOnly").is_some_and(|v| v == "1") {
+            return MockResponse::list(200, Vec::new(), total);
+        }
+
+        let data = matching
+            .into_iter()
+            .skip(offset)
+            .take(if limit == 0 { usize::MAX } else { limit })
+            .cloned()
+            .collect();
+
+        MockResponse::list(200, data, total)
+    }
+
+    /// Answers `POST /v3/REST/{resource}`
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `body`: The request's body
+    fn create(&mut self, resource: &str, body: &Value) -> MockResponse {
+        let Some(fields) = body.as_object() else {
+            return MockResponse::error(400, "Invalid JSON");
+        };
+
+        let (required, unique): (&[&str], &[&str]) = match resource {
+            "contact" => (&["Email"], &["Email"]),
+            "contactslist" => (&["Name"], &["Name"]),
+            "sender" | "metasender" => (&["Email"], &[]),
+            "eventcallbackurl" => (&["Url"], &["EventType"]),
+            "parseroute" => (&["Url"], &[]),
+            "contactfilter" => (&["Name", "Expression"], &[]),
+            _ => (&["Name"], &[]),
+        };
+
+        for field in required {
+            if fields
+                .get(*field)
+                .map(Self::as_string)
+                .unwrap_or_default()
+                .is_empty()
+            {
+                return MockResponse::error(
+                    400,
+                    &format!("MJ03 A non-empty value is required for {}", field),
+                );
+            }
+        }
+
+        let id = self.new_id();
+        let mut object = match resource {
+            "apikey" => json!({
+                "ACL": "", "APIKey": format!("mock_api_key_{}", id), "CreatedAt": NOW,
+                "IsActive": true, "IsMaster": false, "QuarantineValue": 0, "Runlevel": "Normal",
+                "SecretKey": format!("mock_api_secret_{}", id), "TrackHost": "", "UserID": 8001
+            }),
+            "contact" => json!({
+                "CreatedAt": NOW, "DeliveredCount": 0, "ExclusionFromCampaignsUpdatedAt": "",
+                "IsExcludedFromCampaigns": false, "IsOptInPending": false,
+                "IsSpamComplaining": false, "LastActivityAt": "", "LastUpdateAt": "", "Name": ""
+            }),
+            "contactfilter" => json!({ "Description": "", "Status": "unused" }),
+            "contactslist" => json!({
+                "Address": format!("{:x}", id), "CreatedAt": NOW, "IsDeleted": false,
+                "SubscriberCount": 0
+            }),
+            "eventcallbackurl" => json!({
+                "APIKeyID": 7001, "EventType": "open", "IsBackup": false, "Status": "alive",
+                "Version": 1
+            }),
+            "metasender" => json!({ "CreatedAt": NOW, "Description": "", "IsEnabled": true }),
+            "parseroute" => json!({
+                "APIKeyID": 7001, "Email": format!("{:x}@parse-in1.mailjet.com", id),
+                "EmailType": "unknown"
+            }),
+            "sender" => json!({
+                "CreatedAt": NOW, "DNSID": 0, "EmailType": "unknown", "Filename": "",
+                "IsDefaultSender": false, "Name": "", "Status": "Inactive"
+            }),
+            _ => json!({}),
+        };
+
+        Self::merge(&mut object, fields);
+
+        for field in unique {
+            let value = &object[*field];
+
+            if self
+                .collection(resource)
+                .iter()
+                .any(|o| &o[*field] == value)
+            {
+                return MockResponse::error(
+                    400,
+                    &format!(
+                        "MJ18 A {} resource with value \"{}\" for {} already exists.",
+                        resource,
+                        Self::as_string(value),
+                        field
+                    ),
+                );
+            }
+        }
+
+        object["ID"] = json!(id);
+        self.collections
+            .entry(resource.to_string())
+            .or_default()
+            .push(object.clone());
+
+        MockResponse::list(201, vec![object], 1)
+    }
+
+    /// Answers `PUT /v3/REST/{resource}/{id}`, or `PUT /v3/REST/{resource}` for singletons
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `id`: The identifier, `None` for singletons
+    /// * `body`: The request's body
+    fn update(&mut self, resource: &str, id: Option<&str>, body: &Value) -> MockResponse {
+        let Some(fields) = body.as_object() else {
+            return MockResponse::error(400, "Invalid JSON");
+        };
+
+        let object = self
+            .collections
+            .get_mut(resource)
+            .and_then(|objects| match id {
+                Some(id) => objects
+                    .iter_mut()
+                    .find(|o| Self::is_identified_by(resource, o, id)),
+                None => objects.first_mut(),
+            });
+
+        match object {
+            Some(object) => {
+                if resource == "contact" && fields.contains_key("IsExcludedFromCampaigns") {
+                    object["ExclusionFromCampaignsUpdatedAt"] = json!(NOW);
+                }
+
+                Self::merge(object, fields);
+                MockResponse::list(200, vec![object.clone()], 1)
+            }
+            None => MockResponse::error(404, ""),
+        }
+    }
+
+    /// Deletes the objects identified by an URL identifier, and returns how many were deleted
+    ///
+    /// # Parameters
+    ///
+    /// * `resource`: The resource name
+    /// * `id`: The identifier
+    fn delete(&mut self, resource: &str, id: &str) -> usize {
+        match self.collections.get_mut(resource) {
+            Some(objects) => {
+                let before = objects.len();
+                objects.retain(|o| !Self::is_identified_by(resource, o, id));
+                before - objects.len()
+            }
+            None => 0,
+        }
+    }
+
+    /// Copies fields into an object
+    ///
+    /// # Parameters
+    ///
+    /// * `object`: The object to update
+    /// * `fields`: The fields to copy
+    fn merge(object: &mut Value, fields: &Map<String, Value>) {
+        for (key, value) in fields {
+            object[key.as_str()] = value.clone();
+        }
+    }
+
+    /// Answers `POST /v3.1/send`
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The request's body
+    fn send(&mut self, body: &Value) -> MockResponse {
+        let messages = body["Messages"].as_array().cloned().unwrap_or_default();
+        let sandbox = body["SandboxMode"] == true;
+        let mut failed = messages.is_empty();
+        let mut results = Vec::new();
+
+        for message in &messages {
+            let errors = validate_message(message, &body["Globals"]);
+
+            if !errors.is_empty() {
+                failed = true;
+                results.push(json!({ "Status": "error", "Errors": serde_json::to_value(errors).unwrap_or_default() }));
+                continue;
+            }
+
+            let mut result = json!({
+                "Status": "success",
+                "CustomID": message["CustomID"].as_str().unwrap_or_default(),
+            });
+
+            for kind in ["To", "Cc", "Bcc"] {
+                let sent: Vec<Value> = message[kind]
+                    .as_array()
+                    .cloned()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|recipient| {
+                        self.record_message(&recipient["Email"], message, sandbox)
+                    })
+                    .collect();
+
+                result[kind] = json!(sent);
+            }
+
+            results.push(result);
+        }
+
+        MockResponse::json(
+            if failed { 400 } else { 200 },
+            &json!({ "Messages": results }),
+        )
+    }
+
+    /// Records a sent message, and returns its sending information
+    ///
+    /// # Parameters
+    ///
+    /// * `email`: The recipient's email address
+    /// * `message`: The message, from the Send API v3.1 or v3
+    /// * `sandbox`: `true` if the message isn't really sent, it isn't recorded then
+    fn record_message(&mut self, email: &Value, message: &Value, sandbox: bool) -> Value {
+        let id = self.new_id();
+        let custom_id = message["CustomID"]
+            .as_str()
+            .or_else(|| message["Mj-CustomID"].as_str())
+            .unwrap_or_default();
+
+        if !sandbox {
+            self.collections
+                .entry("message".to_string())
+                .or_default()
+                .push(json!({
+                    "ArrivedAt": NOW,
+                    "ContactAlt": email,
+                    "CustomID": custom_id,
+                    "ID": id,
+                    "Status": "sent",
+                    "Subject": message["Subject"].as_str().unwrap_or_default(),
+                    "UUID": Self::uuid(id),
+                }));
+        }
+
+        json!({
+            "Email": email,
+            "MessageUUID": Self::uuid(id),
+            "MessageID": id,
+            "MessageHref": format!("https://api.mailjet.com/v3/REST/message/{}", id),
+        })
+    }
+
+    /// Answers `POST /v3/send`
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The request's body
+    fn send_v3(&mut self, body: &Value) -> MockResponse {
+        let messages = match body["Messages"].as_array() {
+            Some(messages) => messages.clone(),
+            None => vec![body.clone()],
+        };
+        let mut sent = Vec::new();
+
+        for message in &messages {
+            if message["FromEmail"].as_str().unwrap_or_default().is_empty() {
+                return MockResponse::error(400, "Missing FromEmail");
+            }
+
+            let mut emails: Vec<Value> = message["Recipients"]
+                .as_array()
+                .cloned()
+                .unwrap_or_default()
+                .iter()
+                .map(|recipient| recipient["Email"].clone())
+                .collect();
+
+            for kind in ["To", "Cc", "Bcc"] {
+                emails.extend(
+                    message[kind]
+                        .as_str()
+                        .unwrap_or_default()
+                        .split(',')
+                        .filter(|mailbox| !mailbox.trim().is_empty())
+                        .map(|mailbox| json!(EmailAddress::from_mailbox(mailbox).email)),
+                );
+            }
+
+            if emails.is_empty() {
+                return MockResponse::error(400, "Missing recipients");
+            }
+
+            for email in emails {
+                let mut result = self.record_message(&email, message, false);
+
+                if let Some(result) = result.as_object_mut() {
+                    result.remove("MessageHref");
+                }
+
+                sent.push(result);
+            }
+        }
+
+        MockResponse::json(200, &json!({ "Sent": sent }))
+    }
+
+    /// Answers `POST /v4/sms-send`
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The request's body
+    fn sms_send(&mut self, body: &Value) -> MockResponse {
+        for field in ["From", "To", "Text"] {
+            if body[field].as_str().unwrap_or_default().is_empty() {
+                return MockResponse::json(
+                    400,
+                    &json!({
+                        "ErrorIdentifier": Self::uuid(0),
+                        "ErrorCode": "sms-0002",
+                        "StatusCode": 400,
+                        "ErrorMessage": format!("\"{}\" is a mandatory property.", field),
+                        "ErrorRelatedTo": [field],
+                    }),
+                );
+            }
+        }
+
+        let id = self.new_id();
+        let text = body["Text"].as_str().unwrap_or_default();
+        let sms = json!({
+            "ID": Self::uuid(id),
+            "From": body["From"],
+            "To": body["To"],
+            "Text": text,
+            "Status": { "Code": 2, "Name": "sent", "Description": "Message sent" },
+            "Cost": { "Value": 0.0569, "Currency": "EUR" },
+            "SmsCount": text.chars().count().div_ceil(160),
+            "CreationTS": Self::timestamp(),
+            "SentTS": Self::timestamp(),
+        });
+
+        self.collections
+            .entry("sms".to_string())
+            .or_default()
+            .push(sms.clone());
+
+        MockResponse::json(200, &sms)
+    }
+
+    /// Answers `POST /v4/sms/export`
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The request's body
+    fn sms_export_create(&mut self, body: &Value) -> MockResponse {
+        if body["FromTS"].is_null() || body["ToTS"].is_null() {
+            return MockResponse::error(400, "\"FromTS\" and \"ToTS\" are mandatory properties.");
+        }
+
+        let id = self.new_id();
+        let export = json!({
+            "ID": Self::uuid(id),
+            "CreationTS": Self::timestamp(),
+            "ExpirationTS": Self::timestamp() + 86400,
+            "Status": { "Code": 1, "Name": "PENDING", "Description": "The request is accepted." },
+            "FromTS": body["FromTS"],
+            "ToTS": body["ToTS"],
+        });
+
+        self.collections
+            .entry("smsexport".to_string())
+            .or_default()
+            .push(export.clone());
+
+        MockResponse::json(200, &export)
+    }
+
+    /// Answers `GET /v4/sms/export/{id}`, the export is completed after being retrieved once
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: The export id
+    fn sms_export_get(&mut self, id: &str) -> MockResponse {
+        let export = self
+            .collections
+            .get_mut("smsexport")
+            .and_then(|exports| exports.iter_mut().find(|e| e["ID"] == id));
+
+        match export {
+            Some(export) => {
+                let response = MockResponse::json(200, export);
+
+                export["Status"] = json!({
+                    "Code": 2, "Name": "COMPLETED", "Description": "The export is ready."
+                });
+                export["URL"] = json!(format!("https://example.com/sms-export-{}.csv", id));
+
+                response
+            }
+            None => MockResponse::error(404, ""),
+        }
+    }
+
+    /// Answers `POST /v3/REST/contact/managemanycontacts`, the job is completed immediately
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The request's body
+    fn manage_many_contacts(&mut self, body: &Value) -> MockResponse {
+        let contacts = body["Contacts"].as_array().cloned().unwrap_or_default();
+
+        if contacts.is_empty() {
+            return MockResponse::error(400, "MJ03 A non-empty value is required for Contacts");
+        }
+
+        for contact in &contacts {
+            let email = contact["Email"].as_str().unwrap_or_default();
+            let fields = contact.as_object().cloned().unwrap_or_default();
+
+            if email.is_empty() {
+                return MockResponse::error(400, "MJ03 A non-empty value is required for Email");
+            }
+
+            if self.find("contact", email).is_some() {
+                self.update("contact", Some(email), contact);
+            } else {
+                self.create("contact", &Value::Object(fields));
+            }
+        }
+
+        let id = self.new_id();
+
+        self.collections
+            .entry("managemanycontacts".to_string())
+            .or_default()
+            .push(json!({
+                "ID": id,
+                "ContactsLists": body["ContactsLists"].as_array().cloned().unwrap_or_default(),
+                "Count": contacts.len(),
+                "Error": "",
+                "ErrorFile": "",
+                "JobEnd": NOW,
+                "JobStart": NOW,
+                "Status": "Completed",
+            }));
+
+        MockResponse::list(201, vec![json!({ "JobID": id })], 1)
+    }
+
+    /// Answers `POST /v3/REST/sender/{id}/validate`
+    ///
+    /// Senders on a seeded domain are validated by DNS, the others need an activation email
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: The sender's id or email address
+    fn sender_validate(&mut self, id: &str) -> MockResponse {
+        let domains: Vec<String> = self
+            .collection("dns")
+            .iter()
+            .map(|dns| Self::as_string(&dns["Domain"]))
+            .collect();
+        let sender = self.collections.get_mut("sender").and_then(|senders| {
+            senders
+                .iter_mut()
+                .find(|s| Self::is_identified_by("sender", s, id))
+        });
+
+        let Some(sender) = sender else {
+            return MockResponse::error(404, "");
+        };
+
+        let email = Self::as_string(&sender["Email"]);
+        let domain = email.rsplit('@').next().unwrap_or_default();
+
+        let method = if domains.iter().any(|d| d == domain) {
+            sender["Status"] = json!("Active");
+            "DNS"
+        } else {
+            "ActivationEmail"
+        };
+
+        MockResponse::json(
+            200,
+            &json!({ "Errors": {}, "ValidationMethod": method, "GlobalError": "" }),
+        )
+    }
+
+    /// Answers `POST /v3/REST/dns/{id}/check`
+    ///
+    /// # Parameters
+    ///
+    /// * `id`: The domain's id or name
+    fn dns_check(&self, id: &str) -> MockResponse {
+        match self.find("dns", id) {
+            Some(dns) => MockResponse::list(
+                200,
+                vec![json!({
+                    "DKIMErrors": [],
+                    "DKIMRecordCurrentValue": dns["DKIMRecordValue"],
+                    "DKIMStatus": dns["DKIMStatus"],
+                    "SPFErrors": [],
+                    "SPFRecordsCurrentValues": [dns["SPFRecordValue"]],
+                    "SPFStatus": dns["SPFStatus"],
+                })],
+                1,
+            ),
+            None => MockResponse::error(404, ""),
+        }
+    }
+}
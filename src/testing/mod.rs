@@ -0,0 +1,55 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The record and replay transport
+mod cassette;
+/// The reasons why a cassette can't answer a request
+mod cassette_error;
+/// A request received by the mock server
+mod mock_request;
+/// A response sent by the mock server
+mod mock_response;
+/// The mock server
+mod mock_server;
+/// The state of the mock server
+mod mock_state;
+/// An interaction recorded in a cassette
+mod recorded_interaction;
+/// A request recorded in a cassette
+mod recorded_request;
+/// A response recorded in a cassette
+mod recorded_response;
+/// Identifiers of the objects the mock server starts with
+///
+/// The seeded account has two contacts in one contact list, an active sender on a verified
+/// domain, a sent message, a sub-account and a completed batch job.
+pub mod seed;
+/// An email received by the SMTP sink
+mod smtp_mail;
+/// The local SMTP server
+mod smtp_sink;
+
+pub use cassette::Cassette;
+pub use cassette_error::CassetteError;
+pub use mock_request::MockRequest;
+pub use mock_response::MockResponse;
+pub use mock_server::MockServer;
+pub use recorded_interaction::RecordedInteraction;
+pub use recorded_request::RecordedRequest;
+pub use recorded_response::RecordedResponse;
+pub use smtp_mail::SmtpMail;
+pub use smtp_sink::SmtpSink;
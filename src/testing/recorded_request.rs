@@ -0,0 +1,39 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use crate::transport::RequestType;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A request recorded in a cassette, credentials redacted
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+pub struct RecordedRequest {
+    /// The HTTP method
+    #[serde(rename = "method")]
+    #[serde(default)]
+    pub method: RequestType,
+
+    /// The URL's path and query string, without the base URL
+    #[serde(rename = "path")]
+    #[serde(default)]
+    pub path: String,
+
+    /// The body, as JSON if it is valid JSON, `null` if there is none
+    #[serde(rename = "body")]
+    #[serde(default)]
+    pub body: Value,
+}
@@ -0,0 +1,64 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The id of the first seeded contact
+pub const CONTACT_ID: i128 = 1001;
+
+/// The email address of the first seeded contact
+pub const CONTACT_EMAIL: &str = "passenger1@mailjet.com";
+
+/// The id of the seeded contact list
+pub const CONTACTS_LIST_ID: i128 = 2001;
+
+/// The address of the seeded contact list
+pub const CONTACTS_LIST_ADDRESS: &str = "x1y2z3";
+
+/// The id of the seeded list recipient
+pub const LIST_RECIPIENT_ID: i128 = 2101;
+
+/// The id of the seeded message
+pub const MESSAGE_ID: i128 = 3001;
+
+/// The id of the campaign of the seeded message
+pub const CAMPAIGN_ID: i128 = 4001;
+
+/// The id of the seeded sender
+pub const SENDER_ID: i128 = 5001;
+
+/// The email address of the seeded sender
+pub const SENDER_EMAIL: &str = "pilot@mailjet.com";
+
+/// The id of the seeded sending domain
+pub const DNS_ID: i128 = 6001;
+
+/// The seeded sending domain
+pub const DOMAIN: &str = "mailjet.com";
+
+/// The id of the main account API key
+pub const API_KEY_ID: i128 = 7001;
+
+/// The id of the seeded sub-account API key
+pub const SUB_ACCOUNT_API_KEY_ID: i128 = 7002;
+
+/// The id of the seeded user
+pub const USER_ID: i128 = 8001;
+
+/// The id of the seeded batch job
+pub const BATCH_JOB_ID: i128 = 9001;
+
+/// The id of the seeded SMS
+pub const SMS_ID: &str = "2dfc6fef-e2b4-4b66-8a0c-08a0e8a4e6f1";
@@ -0,0 +1,51 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// An email received by the SMTP sink
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct SmtpMail {
+    /// The user name used to authenticate, if any
+    pub username: Option<String>,
+
+    /// The envelope's sender
+    pub mail_from: String,
+
+    /// The envelope's recipients
+    pub rcpt_to: Vec<String>,
+
+    /// The message, headers and body
+    pub data: String,
+}
+
+impl SmtpMail {
+    /// Returns the first value of a header of the message, the name is case insensitive
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: The header's name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.data
+            .split("\r\n\r\n")
+            .next()
+            .unwrap_or_default()
+            .lines()
+            .find_map(|line| {
+                let (k, v) = line.split_once(':')?;
+                k.eq_ignore_ascii_case(name).then(|| v.trim())
+            })
+    }
+}
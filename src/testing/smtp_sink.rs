@@ -0,0 +1,287 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::SmtpMail;
+use base64::{engine::general_purpose::STANDARD, Engine};
+use std::collections::VecDeque;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// The state shared by the SMTP sink and its thread
+#[derive(Default)]
+struct SinkState {
+    /// The emails received so far
+    mails: Vec<SmtpMail>,
+
+    /// The codes the next emails are refused with
+    failures: VecDeque<u16>,
+}
+
+/// A local SMTP server accepting every email, running in a background thread until dropped
+///
+/// Any user name and password are accepted, the received emails are kept in memory.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use mailjet_api_wrapper::{data::{EmailAddress, Message}, testing::SmtpSink};
+///
+/// let sink = SmtpSink::start().unwrap();
+/// let message = Message {
+///     from: EmailAddress::from_email("pilot@mailjet.com"),
+///     to: vec![EmailAddress::from_email("passenger1@mailjet.com")],
+///     text_part: "Welcome aboard".to_string(),
+///     ..Default::default()
+/// };
+///
+/// sink.sender().send_message(&message).unwrap();
+///
+/// assert_eq!(sink.mails()[0].rcpt_to, vec!["passenger1@mailjet.com"]);
+/// ```
+pub struct SmtpSink {
+    /// The address the sink listens on
+    address: SocketAddr,
+
+    /// The state shared with the sink's thread
+    state: Arc<Mutex<SinkState>>,
+
+    /// `false` when the sink must stop
+    running: Arc<AtomicBool>,
+
+    /// The sink's thread
+    handle: Option<JoinHandle<()>>,
+}
+
+impl SmtpSink {
+    /// Starts a sink on a random local port
+    pub fn start() -> io::Result<Self> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        let state = Arc::new(Mutex::new(SinkState::default()));
+        let running = Arc::new(AtomicBool::new(true));
+
+        let thread_state = state.clone();
+        let thread_running = running.clone();
+        let handle = thread::spawn(move || {
+            for stream in listener.incoming() {
+                if !thread_running.load(Ordering::SeqCst) {
+                    break;
+                }
+
+                if let Ok(stream) = stream {
+                    let _ = stream.set_read_timeout(Some(Duration::from_secs(5)));
+                    let _ = Self::serve(stream, &thread_state);
+                }
+            }
+        });
+
+        Ok(Self {
+            address,
+            state,
+            running,
+            handle: Some(handle),
+        })
+    }
+
+    /// Returns the sink's host, always `127.0.0.1`
+    pub fn host(&self) -> String {
+        self.address.ip().to_string()
+    }
+
+    /// Returns the sink's port
+    pub fn port(&self) -> u16 {
+        self.address.port()
+    }
+
+    /// Returns a sender connected to the sink
+    #[cfg(feature = "smtp")]
+    pub fn sender(&self) -> crate::smtp::SmtpSender {
+        crate::smtp::SmtpSender::unencrypted(
+            &self.host(),
+            self.port(),
+            super::MockServer::API_KEY,
+            super::MockServer::API_SECRET,
+        )
+    }
+
+    /// Makes the next email be refused with a code, like `550`
+    ///
+    /// Calls are queued: each one makes one more email be refused.
+    ///
+    /// # Parameters
+    ///
+    /// * `code`: The SMTP reply code
+    pub fn fail_next(&self, code: u16) {
+        Self::lock(&self.state).failures.push_back(code);
+    }
+
+    /// Returns the emails received so far
+    pub fn mails(&self) -> Vec<SmtpMail> {
+        Self::lock(&self.state).mails.clone()
+    }
+
+    /// Talks with one client until it quits
+    ///
+    /// # Parameters
+    ///
+    /// * `stream`: The connection
+    /// * `state`: The shared state
+    fn serve(stream: TcpStream, state: &Mutex<SinkState>) -> io::Result<()> {
+        let mut writer = stream.try_clone()?;
+        let mut reader = BufReader::new(stream);
+        let mut mail = SmtpMail::default();
+        let mut line = String::new();
+
+        writer.write_all(b"220 localhost ESMTP SmtpSink\r\n")?;
+
+        loop {
+            line.clear();
+
+            if reader.read_line(&mut line)? == 0 {
+                return Ok(());
+            }
+
+            let command = line.trim_end();
+            let verb = command
+                .split([' ', ':'])
+                .next()
+                .unwrap_or_default()
+                .to_ascii_uppercase();
+            let argument = |prefix: &str| {
+                command[prefix.len().min(command.len())..]
+                    .split_whitespace()
+                    .next()
+                    .unwrap_or_default()
+                    .trim_matches(['<', '>'])
+                    .to_string()
+            };
+
+            let reply = match verb.as_str() {
+                "EHLO" => "250-localhost\r\n250-AUTH PLAIN\r\n250 8BITMIME".to_string(),
+                "HELO" => "250 localhost".to_string(),
+                "AUTH" => {
+                    let mut response = command.split_whitespace().nth(2).map(str::to_string);
+
+                    if response.is_none() {
+                        writer.write_all(b"334 \r\n")?;
+                        line.clear();
+                        reader.read_line(&mut line)?;
+                        response = Some(line.trim_end().to_string());
+                    }
+
+                    let decoded = STANDARD
+                        .decode(response.unwrap_or_default())
+                        .unwrap_or_default();
+
+                    mail.username = String::from_utf8_lossy(&decoded)
+                        .split('\0')
+                        .nth(1)
+                        .map(str::to_string);
+                    "235 2.7.0 Authentication successful".to_string()
+                }
+                "MAIL" => {
+                    mail.mail_from = argument("MAIL FROM:");
+                    "250 2.1.0 Ok".to_string()
+                }
+                "RCPT" => {
+                    mail.rcpt_to.push(argument("RCPT TO:"));
+                    "250 2.1.5 Ok".to_string()
+                }
+                "DATA" => {
+                    writer.write_all(b"354 End data with <CR><LF>.<CR><LF>\r\n")?;
+                    mail.data = Self::read_data(&mut reader)?;
+
+                    let mut state = Self::lock(state);
+                    let reply = match state.failures.pop_front() {
+                        Some(code) => format!("{} 5.7.1 Refused by the sink", code),
+                        None => {
+                            state.mails.push(mail.clone());
+                            "250 2.0.0 Ok: queued".to_string()
+                        }
+                    };
+
+                    mail = SmtpMail {
+                        username: mail.username.take(),
+                        ..Default::default()
+                    };
+                    reply
+                }
+                "RSET" => {
+                    mail = SmtpMail {
+                        username: mail.username.take(),
+                        ..Default::default()
+                    };
+                    "250 2.0.0 Ok".to_string()
+                }
+                "NOOP" => "250 2.0.0 Ok".to_string(),
+                "QUIT" => {
+                    writer.write_all(b"221 2.0.0 Bye\r\n")?;
+                    return Ok(());
+                }
+                _ => "502 5.5.2 Command not implemented".to_string(),
+            };
+
+            writer.write_all(format!("{}\r\n", reply).as_bytes())?;
+        }
+    }
+
+    /// Reads a message until the line with a single dot, and removes the dot stuffing
+    ///
+    /// # Parameters
+    ///
+    /// * `reader`: The connection
+    fn read_data(reader: &mut BufReader<TcpStream>) -> io::Result<String> {
+        let mut data = String::new();
+        let mut line = String::new();
+
+        loop {
+            line.clear();
+
+            if reader.read_line(&mut line)? == 0 || line == ".\r\n" || line == ".\n" {
+                return Ok(data);
+            }
+
+            data.push_str(line.strip_prefix('.').unwrap_or(&line));
+        }
+    }
+
+    /// Locks the state, even if a previous holder panicked
+    ///
+    /// # Parameters
+    ///
+    /// * `state`: The shared state
+    fn lock(state: &Mutex<SinkState>) -> MutexGuard<'_, SinkState> {
+        state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Drop for SmtpSink {
+    fn drop(&mut self) {
+        self.running.store(false, Ordering::SeqCst);
+
+        // Wakes the thread up, blocked on `accept`
+        let _ = TcpStream::connect(self.address);
+
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
@@ -0,0 +1,50 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The credentials sent with a request
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub enum Credentials {
+    /// No credentials
+    #[default]
+    None,
+
+    /// Basic authentication with API keys
+    Basic {
+        /// The API key
+        api_key: String,
+
+        /// The secret key
+        api_secret: String,
+    },
+
+    /// Bearer token authentication
+    Bearer(String),
+}
+
+impl Credentials {
+    /// Returns the values that must never be written anywhere, like in a log or a file
+    pub fn secrets(&self) -> Vec<&str> {
+        match self {
+            Credentials::None => Vec::new(),
+            Credentials::Basic {
+                api_key,
+                api_secret,
+            } => vec![api_key.as_str(), api_secret.as_str()],
+            Credentials::Bearer(token) => vec![token.as_str()],
+        }
+    }
+}
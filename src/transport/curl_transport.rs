@@ -0,0 +1,95 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{Credentials, HttpRequest, HttpResponse, RequestType, Transport};
+use curl::easy::{Easy, List};
+use std::{error::Error as StdError, io::Read};
+
+/// The default transport, sending requests with curl
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CurlTransport;
+
+impl Transport for CurlTransport {
+    fn execute(&self, request: &HttpRequest) -> Result<HttpResponse, Box<dyn StdError>> {
+        let mut curl = Easy::new();
+        let mut response: Vec<u8> = Vec::new(); // That's where the response will be written on
+
+        // Convert the data in a byte array
+        let mut raw_data = match &request.body {
+            Some(body) => body.as_bytes(),
+            None => &[],
+        };
+
+        // Create the HTTP request
+        curl.url(&request.url)?;
+
+        if let Credentials::Basic {
+            api_key,
+            api_secret,
+        } = &request.credentials
+        {
+            curl.username(api_key)?;
+            curl.password(api_secret)?;
+        }
+
+        // Change HTTP request
+        match request.method {
+            RequestType::Post => curl.post(true)?,
+            RequestType::Put => curl.put(true)?,
+            RequestType::Delete => curl.custom_request("DELETE")?,
+            _ => (),
+        }
+
+        let mut header_list = List::new();
+
+        // Change body type
+        if matches!(request.method, RequestType::Post | RequestType::Put) {
+            header_list.append("Content-Type: application/json")?;
+        }
+
+        // Add the token
+        if let Credentials::Bearer(token) = &request.credentials {
+            header_list.append(&format!("Authorization: Bearer {}", token))?;
+        }
+
+        curl.http_headers(header_list)?;
+
+        {
+            // We need this for lifetime reasons
+            let mut transfer = curl.transfer();
+
+            // How we pass data to mailjet
+            if !raw_data.is_empty() {
+                transfer.read_function(|buffer| Ok(raw_data.read(buffer).unwrap_or_default()))?
+            };
+
+            // How we read mailjet's response
+            transfer.write_function(|buffer| {
+                response.extend_from_slice(buffer);
+                Ok(buffer.len())
+            })?;
+
+            // Request execution
+            transfer.perform()?;
+        }
+
+        Ok(HttpResponse {
+            http_code: curl.response_code().ok(),
+            body: String::from_utf8_lossy(&response).to_string(),
+        })
+    }
+}
@@ -0,0 +1,36 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The credentials sent with a request
+mod credentials;
+/// The default transport, based on curl
+mod curl_transport;
+/// A request given to a transport
+mod http_request;
+/// A response returned by a transport
+mod http_response;
+/// HTTP methods
+mod request_type;
+/// The transport trait
+mod transport_trait;
+
+pub use credentials::Credentials;
+pub use curl_transport::CurlTransport;
+pub use http_request::HttpRequest;
+pub use http_response::HttpResponse;
+pub use request_type::RequestType;
+pub use transport_trait::Transport;
@@ -0,0 +1,55 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{HttpRequest, HttpResponse};
+use std::error::Error as StdError;
+
+/// Executes the HTTP requests of a [`Mailjet`](crate::Mailjet) client
+///
+/// The default transport is [`CurlTransport`](super::CurlTransport), implement this trait to
+/// send requests another way, or to not send them at all during tests.
+///
+/// # Example
+///
+/// ```rust
+/// use mailjet_api_wrapper::transport::{HttpRequest, HttpResponse, Transport};
+/// use mailjet_api_wrapper::Mailjet;
+/// use std::error::Error;
+///
+/// struct AlwaysEmpty;
+///
+/// impl Transport for AlwaysEmpty {
+///     fn execute(&self, _request: &HttpRequest) -> Result<HttpResponse, Box<dyn Error>> {
+///         Ok(HttpResponse {
+///             http_code: Some(200),
+///             body: r#"{"Count": 0, "Data": [], "Total": 0}"#.to_string(),
+///         })
+///     }
+/// }
+///
+/// let mailjet = Mailjet::from_api_keys("your_api_key", "your_api_secret").with_transport(AlwaysEmpty);
+/// ```
+pub trait Transport: Send + Sync {
+    /// Executes a request and returns the response
+    ///
+    /// HTTP error codes are not errors, they are returned in the response
+    ///
+    /// # Parameters
+    ///
+    /// * `request`: The request to execute
+    fn execute(&self, request: &HttpRequest) -> Result<HttpResponse, Box<dyn StdError>>;
+}
@@ -0,0 +1,151 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{EventSink, WebhookReceiver};
+use axum::{
+    body::Bytes,
+    extract::{DefaultBodyLimit, State},
+    http::{HeaderMap, StatusCode},
+    routing::post,
+    Router,
+};
+use std::sync::Arc;
+
+impl<S: EventSink + Send + Sync + 'static> WebhookReceiver<S> {
+    /// Creates an axum router handling webhook calls with `POST` requests on a path
+    ///
+    /// The sink is called on Tokio's blocking thread pool, so it can block without stalling
+    /// the server
+    ///
+    /// # Parameters
+    ///
+    /// * `path`: The path of the webhook, like `/mailjet/events`
+    pub fn into_router(self, path: &str) -> Router {
+        // Let the receiver answer oversized bodies itself
+        let limit = DefaultBodyLimit::max(self.max_body_size.saturating_add(1));
+
+        Router::new()
+            .route(path, post(handle_request::<S>))
+            .layer(limit)
+            .with_state(Arc::new(self))
+    }
+}
+
+/// Handles a webhook call with axum
+///
+/// # Parameters
+///
+/// * `receiver`: The webhook receiver
+/// * `headers`: The request headers
+/// * `body`: The request body
+async fn handle_request<S: EventSink + Send + Sync + 'static>(
+    State(receiver): State<Arc<WebhookReceiver<S>>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let handled = tokio::task::spawn_blocking(move || {
+        let headers = headers
+            .iter()
+            .filter_map(|(k, v)| Some((k.as_str(), v.to_str().ok()?)));
+
+        receiver.handle(headers, &body).map_err(|e| e.status_code())
+    })
+    .await;
+
+    match handled {
+        Ok(Ok(_)) => StatusCode::OK,
+        Ok(Err(code)) => StatusCode::from_u16(code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR),
+        Err(_) => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::webhooks::{WebhookEvent, WebhookReceiver};
+    use curl::easy::{Easy, List};
+    use std::error::Error as StdError;
+    use std::io::Read;
+    use std::sync::{Arc, Mutex};
+
+    /// Posts a body to a URL and returns the HTTP status code
+    fn post(url: &str, body: &'static [u8], authorization: Option<&str>) -> u32 {
+        let mut curl = Easy::new();
+        let mut data = body;
+        let mut headers = List::new();
+
+        curl.url(url).unwrap();
+        curl.post(true).unwrap();
+        curl.post_field_size(body.len() as u64).unwrap();
+        headers.append("Content-Type: application/json").unwrap();
+
+        if let Some(v) = authorization {
+            headers.append(&format!("Authorization: {}", v)).unwrap();
+        }
+
+        curl.http_headers(headers).unwrap();
+
+        {
+            let mut transfer = curl.transfer();
+            transfer
+                .read_function(|buffer| Ok(data.read(buffer).unwrap_or_default()))
+                .unwrap();
+            transfer.write_function(|buffer| Ok(buffer.len())).unwrap();
+            transfer.perform().unwrap();
+        }
+
+        curl.response_code().unwrap()
+    }
+
+    #[tokio::test(flavor = "multi_thread")]
+    async fn axum_router_receives_events() {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let received_by_sink = received.clone();
+        let sink = move |event: &WebhookEvent| -> Result<(), Box<dyn StdError + Send + Sync>> {
+            received_by_sink.lock().unwrap().push(event.event_type());
+            Ok(())
+        };
+        let mut receiver = WebhookReceiver::from_sink_and_credentials(sink, "mailjet", "secret");
+        receiver.max_body_size = 2048;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/events", listener.local_addr().unwrap());
+        let router = receiver.into_router("/events");
+        tokio::spawn(async move { axum::serve(listener, router).await });
+
+        let codes = tokio::task::spawn_blocking(move || {
+            let grouped = include_bytes!("../../tests/fixtures/webhooks/grouped.json");
+            let open = include_bytes!("../../tests/fixtures/webhooks/open.json");
+            let oversized: &'static [u8] = Box::leak(vec![b' '; 4096].into_boxed_slice());
+
+            [
+                post(&url, grouped, Some("Basic bWFpbGpldDpzZWNyZXQ=")),
+                post(&url, open, None),
+                post(
+                    &url,
+                    b"{\"event\": \"nope\"}",
+                    Some("Basic bWFpbGpldDpzZWNyZXQ="),
+                ),
+                post(&url, oversized, Some("Basic bWFpbGpldDpzZWNyZXQ=")),
+            ]
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(codes, [200, 401, 400, 413]);
+        assert_eq!(received.lock().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,56 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::EventBase;
+use serde::{Deserialize, Serialize};
+
+/// A message bounced
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct BounceEvent {
+    /// The fields shared by every event
+    #[serde(flatten)]
+    pub base: EventBase,
+
+    /// Indicates whether the contact has been blocked because of this bounce or not
+    #[serde(rename = "blocked")]
+    #[serde(default)]
+    pub blocked: bool,
+
+    /// Indicates whether the bounce is permanent or not
+    #[serde(rename = "hard_bounce")]
+    #[serde(default)]
+    pub hard_bounce: bool,
+
+    /// The category of the error: `recipient`, `domain`, `content`, `spam`, `system` etc.
+    ///
+    /// See <https://dev.mailjet.com/email/guides/webhooks/#possible-values-for-errors>
+    #[serde(rename = "error_related_to")]
+    #[serde(default)]
+    pub error_related_to: String,
+
+    /// The error, like `user unknown` or `mailbox inactive`
+    ///
+    /// See <https://dev.mailjet.com/email/guides/webhooks/#possible-values-for-errors>
+    #[serde(rename = "error")]
+    #[serde(default)]
+    pub error: String,
+
+    /// The reply of the recipient's SMTP server
+    #[serde(rename = "comment")]
+    #[serde(default)]
+    pub comment: String,
+}
@@ -0,0 +1,67 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use serde::{Deserialize, Serialize};
+
+/// The fields shared by every event
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct EventBase {
+    /// Unix timestamp of when the event occurred
+    #[serde(rename = "time")]
+    #[serde(default)]
+    pub time: i64,
+
+    /// The legacy mailjet's email unique id
+    #[serde(rename = "MessageID")]
+    #[serde(default)]
+    pub message_id: i64,
+
+    /// The mailjet's email unique id
+    #[serde(rename = "Message_GUID")]
+    #[serde(default)]
+    pub message_guid: String,
+
+    /// The recipient's email address
+    #[serde(rename = "email")]
+    #[serde(default)]
+    pub email: String,
+
+    /// Unique numeric ID of the campaign the message is part of
+    #[serde(rename = "mj_campaign_id")]
+    #[serde(default)]
+    pub mj_campaign_id: i64,
+
+    /// Unique numeric ID of the recipient contact
+    #[serde(rename = "mj_contact_id")]
+    #[serde(default)]
+    pub mj_contact_id: i64,
+
+    /// The custom campaign name set at sending
+    #[serde(rename = "customcampaign")]
+    #[serde(default)]
+    pub custom_campaign: String,
+
+    /// The custom message id set at sending
+    #[serde(rename = "CustomID")]
+    #[serde(default)]
+    pub custom_id: String,
+
+    /// The payload set at sending
+    #[serde(rename = "Payload")]
+    #[serde(default)]
+    pub payload: String,
+}
@@ -0,0 +1,46 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::WebhookEvent;
+use std::error::Error as StdError;
+
+/// A destination for the events received by a [`WebhookReceiver`](super::WebhookReceiver)
+///
+/// Events are delivered at least once: when a sink error fails a webhook call, Mailjet sends
+/// the whole call again, including the events of a grouped payload which were already handled.
+/// Sinks must be idempotent, for example by ignoring events already stored for the same message
+/// ID, event type and time.
+pub trait EventSink {
+    /// Handles one event
+    ///
+    /// When an error is returned, the webhook call fails and Mailjet will retry it later, with
+    /// all its events
+    ///
+    /// # Parameters
+    ///
+    /// * `event`: The received event
+    fn receive(&self, event: &WebhookEvent) -> Result<(), Box<dyn StdError + Send + Sync>>;
+}
+
+impl<F> EventSink for F
+where
+    F: Fn(&WebhookEvent) -> Result<(), Box<dyn StdError + Send + Sync>>,
+{
+    fn receive(&self, event: &WebhookEvent) -> Result<(), Box<dyn StdError + Send + Sync>> {
+        self(event)
+    }
+}
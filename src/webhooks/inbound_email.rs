@@ -0,0 +1,273 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::InboundPart;
+use crate::data::{Attachment, EmailAddress};
+use serde::{Deserialize, Deserializer};
+use serde_json::Value;
+use std::collections::HashMap;
+
+/// An email received with the Parse API
+#[doc = include_str!("../../doc/receive_inbound_emails.md")]
+#[derive(Deserialize, Default, Clone, Debug)]
+#[serde(from = "RawInboundEmail")]
+pub struct InboundEmail {
+    /// The envelope sender address
+    pub sender: String,
+
+    /// The envelope recipient address, the parse route's one
+    pub recipient: String,
+
+    /// The reception date, like `20150410T160638`
+    pub date: String,
+
+    /// The sender, as written in the `From` header
+    pub from: EmailAddress,
+
+    /// The email's subject
+    pub subject: String,
+
+    /// The email's headers, each header can appear multiple times
+    pub headers: HashMap<String, Vec<String>>,
+
+    /// The MIME parts of the email
+    pub parts: Vec<InboundPart>,
+
+    /// The email's body in plain text form
+    pub text_part: String,
+
+    /// The email's body in HTML format
+    pub html_part: String,
+
+    /// SpamAssassin score for this email
+    pub spam_assassin_score: String,
+
+    /// The custom ID of the email, if any
+    pub custom_id: String,
+
+    /// The attached files, including inline ones which have a `content_id`
+    pub attachments: Vec<Attachment>,
+}
+
+impl InboundEmail {
+    /// Parses a Parse API call body
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The body of the Parse API call
+    pub fn parse(body: &[u8]) -> Result<InboundEmail, serde_json::Error> {
+        serde_json::from_slice(body)
+    }
+
+    /// Returns the first value of a header, the name is case insensitive
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: The header's name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .and_then(|(_, v)| v.first())
+            .map(String::as_str)
+    }
+}
+
+/// An email received with the Parse API, as sent by Mailjet
+///
+/// The attachments are stored in dynamic fields named after the parts' `ContentRef`
+#[derive(Deserialize)]
+struct RawInboundEmail {
+    /// The envelope sender address
+    #[serde(rename = "Sender")]
+    #[serde(default)]
+    sender: String,
+
+    /// The envelope recipient address
+    #[serde(rename = "Recipient")]
+    #[serde(default)]
+    recipient: String,
+
+    /// The reception date
+    #[serde(rename = "Date")]
+    #[serde(default)]
+    date: String,
+
+    /// The `From` header
+    #[serde(rename = "From")]
+    #[serde(default)]
+    from: String,
+
+    /// The email's subject
+    #[serde(rename = "Subject")]
+    #[serde(default)]
+    subject: String,
+
+    /// The email's headers
+    #[serde(rename = "Headers")]
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_headers")]
+    headers: HashMap<String, Vec<String>>,
+
+    /// The MIME parts of the email
+    #[serde(rename = "Parts")]
+    #[serde(default)]
+    parts: Vec<InboundPart>,
+
+    /// The email's body in plain text form
+    #[serde(rename = "Text-part")]
+    #[serde(default)]
+    text_part: String,
+
+    /// The email's body in HTML format
+    #[serde(rename = "Html-part")]
+    #[serde(default)]
+    html_part: String,
+
+    /// SpamAssassin score for this email
+    #[serde(rename = "SpamAssassinScore")]
+    #[serde(default)]
+    spam_assassin_score: String,
+
+    /// The custom ID of the email
+    #[serde(rename = "CustomID")]
+    #[serde(default)]
+    custom_id: String,
+
+    /// The other fields, holding the attachments' contents
+    #[serde(flatten)]
+    contents: HashMap<String, Value>,
+}
+
+impl From<RawInboundEmail> for InboundEmail {
+    fn from(raw: RawInboundEmail) -> Self {
+        let attachments = raw
+            .parts
+            .iter()
+            .filter_map(|part| {
+                let content = raw.contents.get(&part.content_ref)?.as_str()?;
+
+                Some(Attachment {
+                    content_type: part.content_type().to_string(),
+                    filename: part.filename().unwrap_or_else(|| part.content_ref.clone()),
+                    base64_content: content.to_string(),
+                    content_id: part.header("Content-ID").map(|v| {
+                        v.trim()
+                            .trim_start_matches('<')
+                            .trim_end_matches('>')
+                            .to_string()
+                    }),
+                })
+            })
+            .collect();
+
+        Self {
+            sender: raw.sender,
+            recipient: raw.recipient,
+            date: raw.date,
+            from: EmailAddress::from_mailbox(&raw.from),
+            subject: raw.subject,
+            headers: raw.headers,
+            parts: raw.parts,
+            text_part: raw.text_part,
+            html_part: raw.html_part,
+            spam_assassin_score: raw.spam_assassin_score,
+            custom_id: raw.custom_id,
+            attachments,
+        }
+    }
+}
+
+/// Deserializes headers whose values are either a string or an array of strings
+///
+/// # Parameters
+///
+/// * `deserializer`: The serde deserializer
+pub(crate) fn deserialize_headers<'de, D>(
+    deserializer: D,
+) -> Result<HashMap<String, Vec<String>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    /// A header value, Mailjet uses arrays only for repeated headers
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum HeaderValue {
+        /// A header appearing once
+        One(String),
+        /// A header appearing multiple times
+        Many(Vec<String>),
+    }
+
+    let headers = HashMap::<String, HeaderValue>::deserialize(deserializer)?;
+
+    Ok(headers
+        .into_iter()
+        .map(|(k, v)| match v {
+            HeaderValue::One(v) => (k, vec![v]),
+            HeaderValue::Many(v) => (k, v),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod test {
+    use super::InboundEmail;
+
+    #[test]
+    fn parse_inbound_email() {
+        let email =
+            InboundEmail::parse(include_bytes!("../../tests/fixtures/parse/inbound.json")).unwrap();
+
+        assert_eq!(email.sender, "pilot@mailjet.com");
+        assert_eq!(email.recipient, "passenger@mailjet.com");
+        assert_eq!(email.from.name, "Pilot");
+        assert_eq!(email.from.email, "pilot@mailjet.com");
+        assert_eq!(email.subject, "Hey! It's Friday!");
+        assert!(email.text_part.starts_with("Hi,"));
+        assert!(email.html_part.contains("cid:logo"));
+        assert_eq!(email.spam_assassin_score, "0.602");
+        assert_eq!(email.parts.len(), 4);
+        assert_eq!(email.parts[1].content_type(), "text/html");
+    }
+
+    #[test]
+    fn parse_inbound_headers() {
+        let email =
+            InboundEmail::parse(include_bytes!("../../tests/fixtures/parse/inbound.json")).unwrap();
+
+        assert_eq!(email.headers["Received"].len(), 2);
+        assert_eq!(email.header("mime-version"), Some("1.0"));
+        assert_eq!(email.header("Return-Path"), Some("<pilot@mailjet.com>"));
+        assert_eq!(email.header("X-Missing"), None);
+    }
+
+    #[test]
+    fn parse_inbound_attachments() {
+        let email =
+            InboundEmail::parse(include_bytes!("../../tests/fixtures/parse/inbound.json")).unwrap();
+
+        assert_eq!(email.attachments.len(), 2);
+        assert_eq!(email.attachments[0].filename, "notice.txt");
+        assert_eq!(email.attachments[0].content_type, "text/plain");
+        assert_eq!(email.attachments[0].base64_content, "SXQncyBGcmlkYXkh");
+        assert_eq!(email.attachments[0].content_id, None);
+        assert_eq!(email.attachments[1].filename, "logo.png");
+        assert_eq!(email.attachments[1].content_type, "image/png");
+        assert_eq!(email.attachments[1].content_id.as_deref(), Some("logo"));
+    }
+}
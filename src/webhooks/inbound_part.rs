@@ -0,0 +1,69 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::inbound_email::deserialize_headers;
+use crate::mime::header_parameter;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// One MIME part of an email received with the Parse API
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct InboundPart {
+    /// The part's headers, each header can appear multiple times
+    #[serde(rename = "Headers")]
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_headers")]
+    pub headers: HashMap<String, Vec<String>>,
+
+    /// The name of the payload field holding the part's content, like `Text-part` or `Attachment1`
+    #[serde(rename = "ContentRef")]
+    #[serde(default)]
+    pub content_ref: String,
+}
+
+impl InboundPart {
+    /// Returns the first value of a header, the name is case insensitive
+    ///
+    /// # Parameters
+    ///
+    /// * `name`: The header's name
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .and_then(|(_, v)| v.first())
+            .map(String::as_str)
+    }
+
+    /// Returns the content type without its parameters, like `image/png`
+    pub fn content_type(&self) -> &str {
+        self.header("Content-Type")
+            .and_then(|v| v.split(';').next())
+            .unwrap_or("text/plain")
+            .trim()
+    }
+
+    /// Returns the file name of the part if any, read from `Content-Disposition` or `Content-Type`
+    pub fn filename(&self) -> Option<String> {
+        [
+            ("Content-Disposition", "filename"),
+            ("Content-Type", "name"),
+        ]
+        .iter()
+        .find_map(|(header, parameter)| header_parameter(self.header(header)?, parameter))
+    }
+}
@@ -0,0 +1,69 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+/// The axum adapter of the webhook receiver
+#[cfg(feature = "axum")]
+mod axum_adapter;
+/// A message has been blocked
+mod blocked_event;
+/// A message bounced
+mod bounce_event;
+/// A link inside a message has been clicked
+mod click_event;
+/// The fields shared by every event
+mod event_base;
+/// A destination for received events
+#[cfg(feature = "webhook-receiver")]
+mod event_sink;
+/// An email received with the Parse API
+mod inbound_email;
+/// One MIME part of an email received with the Parse API
+mod inbound_part;
+/// A message has been opened
+mod open_event;
+/// A message has been sent
+mod sent_event;
+/// A message has been reported as spam
+mod spam_event;
+/// A recipient unsubscribed
+mod unsub_event;
+/// An error occurring while receiving a webhook call
+#[cfg(feature = "webhook-receiver")]
+mod webhook_error;
+/// An event sent by Mailjet to a webhook
+mod webhook_event;
+/// A ready-made webhook call handler
+#[cfg(feature = "webhook-receiver")]
+mod webhook_receiver;
+
+pub use blocked_event::BlockedEvent;
+pub use bounce_event::BounceEvent;
+pub use click_event::ClickEvent;
+pub use event_base::EventBase;
+#[cfg(feature = "webhook-receiver")]
+pub use event_sink::EventSink;
+pub use inbound_email::InboundEmail;
+pub use inbound_part::InboundPart;
+pub use open_event::OpenEvent;
+pub use sent_event::SentEvent;
+pub use spam_event::SpamEvent;
+pub use unsub_event::UnsubEvent;
+#[cfg(feature = "webhook-receiver")]
+pub use webhook_error::WebhookError;
+pub use webhook_event::WebhookEvent;
+#[cfg(feature = "webhook-receiver")]
+pub use webhook_receiver::{WebhookReceiver, DEFAULT_MAX_BODY_SIZE};
@@ -0,0 +1,42 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::EventBase;
+use serde::{Deserialize, Serialize};
+
+/// A message has been opened
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct OpenEvent {
+    /// The fields shared by every event
+    #[serde(flatten)]
+    pub base: EventBase,
+
+    /// The IP address of the recipient
+    #[serde(rename = "ip")]
+    #[serde(default)]
+    pub ip: String,
+
+    /// The country code of the recipient
+    #[serde(rename = "geo")]
+    #[serde(default)]
+    pub geo: String,
+
+    /// The user agent (browser) of the recipient
+    #[serde(rename = "agent")]
+    #[serde(default)]
+    pub agent: String,
+}
@@ -0,0 +1,37 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::EventBase;
+use serde::{Deserialize, Serialize};
+
+/// A message has been sent
+#[derive(Serialize, Deserialize, Default, Clone, Debug)]
+pub struct SentEvent {
+    /// The fields shared by every event
+    #[serde(flatten)]
+    pub base: EventBase,
+
+    /// The legacy mailjet's email unique id, as a string
+    #[serde(rename = "mj_message_id")]
+    #[serde(default)]
+    pub mj_message_id: String,
+
+    /// The reply of the recipient's SMTP server
+    #[serde(rename = "smtp_reply")]
+    #[serde(default)]
+    pub smtp_reply: String,
+}
@@ -0,0 +1,71 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use std::error::Error as StdError;
+use std::fmt::{self, Display, Formatter};
+
+/// An error occurring while receiving a webhook call
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The credentials are missing or wrong
+    Unauthorized,
+
+    /// The body is bigger than the configured limit
+    PayloadTooLarge,
+
+    /// The body is not a valid event payload
+    InvalidPayload(serde_json::Error),
+
+    /// The event sink failed to handle an event
+    Sink(Box<dyn StdError + Send + Sync>),
+}
+
+impl WebhookError {
+    /// Returns the HTTP status code to answer Mailjet with
+    ///
+    /// Mailjet retries the calls answered with an error, so only sink errors are
+    /// reported as server errors
+    pub fn status_code(&self) -> u16 {
+        match self {
+            WebhookError::Unauthorized => 401,
+            WebhookError::PayloadTooLarge => 413,
+            WebhookError::InvalidPayload(_) => 400,
+            WebhookError::Sink(_) => 500,
+        }
+    }
+}
+
+impl Display for WebhookError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::Unauthorized => write!(f, "Missing or invalid credentials"),
+            WebhookError::PayloadTooLarge => write!(f, "Payload too large"),
+            WebhookError::InvalidPayload(e) => write!(f, "Invalid payload: {}", e),
+            WebhookError::Sink(e) => write!(f, "Event sink error: {}", e),
+        }
+    }
+}
+
+impl StdError for WebhookError {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self {
+            WebhookError::InvalidPayload(e) => Some(e),
+            WebhookError::Sink(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,266 @@
+// This file is part of rust-mailjet <https://github.com/nevermille/rust-mailjet>
+// Copyright (C) 2023 Camille Nevermind
+//
+// This program is free software; you can redistribute it and/or
+// modify it under the terms of the GNU Lesser General Public
+// License as published by the Free Software Foundation; either
+// version 3 of the License, or (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the GNU
+// Lesser General Public License for more details.
+//
+// You should have received a copy of the GNU Lesser General Public License
+// along with this program; if not, write to the Free Software Foundation,
+// Inc., 51 Franklin Street, Fifth Floor, Boston, MA  02110-1301, USA.
+
+use super::{
+    BlockedEvent, BounceEvent, ClickEvent, EventBase, OpenEvent, SentEvent, SpamEvent, UnsubEvent,
+};
+use crate::data::EventType;
+use crate::macros::log::info;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// An event sent by Mailjet to a webhook
+#[doc = include_str!("../../doc/parse_webhook_events.md")]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "event")]
+pub enum WebhookEvent {
+    /// A message has been sent
+    #[serde(rename = "sent")]
+    Sent(SentEvent),
+
+    /// A message has been opened
+    #[serde(rename = "open")]
+    Open(OpenEvent),
+
+    /// A link inside a message has been clicked
+    #[serde(rename = "click")]
+    Click(ClickEvent),
+
+    /// A message bounced
+    #[serde(rename = "bounce")]
+    Bounce(BounceEvent),
+
+    /// A message has been blocked
+    #[serde(rename = "blocked")]
+    Blocked(BlockedEvent),
+
+    /// A message has been reported as spam
+    #[serde(rename = "spam")]
+    Spam(SpamEvent),
+
+    /// A recipient unsubscribed
+    #[serde(rename = "unsub")]
+    Unsub(UnsubEvent),
+}
+
+impl WebhookEvent {
+    /// Parses a webhook call body
+    ///
+    /// Both single event and grouped events payloads are accepted. In grouped events, events
+    /// that can't be parsed, like event types added by Mailjet later, are logged and skipped
+    /// so that the other events aren't lost
+    ///
+    /// # Parameters
+    ///
+    /// * `body`: The body of the webhook call
+    pub fn parse(body: &[u8]) -> Result<Vec<WebhookEvent>, serde_json::Error> {
+        // Grouped events are sent as an array, single events as an object
+        match body.iter().find(|c| !c.is_ascii_whitespace()) {
+            Some(b'[') => {
+                let events: Vec<Value> = serde_json::from_slice(body)?;
+
+                Ok(events
+                    .into_iter()
+                    .filter_map(|event| {
+                        serde_json::from_value(event)
+                            .inspect_err(|_e| {
+                                info!("Skipping a webhook event that can't be parsed: {}", _e);
+                            })
+                            .ok()
+                    })
+                    .collect())
+            }
+            _ => Ok(vec![serde_json::from_slice(body)?]),
+        }
+    }
+
+    /// Returns the type of the event
+    pub fn event_type(&self) -> EventType {
+        match self {
+            WebhookEvent::Sent(_) => EventType::Sent,
+            WebhookEvent::Open(_) => EventType::Open,
+            WebhookEvent::Click(_) => EventType::Click,
+            WebhookEvent::Bounce(_) => EventType::Bounce,
+            WebhookEvent::Blocked(_) => EventType::Blocked,
+            WebhookEvent::Spam(_) => EventType::Spam,
+            WebhookEvent::Unsub(_) => EventType::Unsub,
+        }
+    }
+
+    /// Returns the fields shared by every event
+    pub fn base(&self) -> &EventBase {
+        match self {
+            WebhookEvent::Sent(e) => &e.base,
+            WebhookEvent::Open(e) => &e.base,
+            WebhookEvent::Click(e) => &e.base,
+            WebhookEvent::Bounce(e) => &e.base,
+            WebhookEvent::Blocked(e) => &e.base,
+            WebhookEvent::Spam(e) => &e.base,
+            WebhookEvent::Unsub(e) => &e.base,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::WebhookEvent;
+    use crate::data::EventType;
+
+    #[test]
+    fn parse_sent() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/sent.json")).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), EventType::Sent);
+        assert_eq!(events[0].base().message_id, 19421777835146490);
+        assert_eq!(events[0].base().custom_id, "helloworld");
+
+        match &events[0] {
+            WebhookEvent::Sent(e) => {
+                assert_eq!(e.mj_message_id, "19421777835146490");
+                assert!(e.smtp_reply.starts_with("sent (250"));
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_open() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/open.json")).unwrap();
+
+        match &events[0] {
+            WebhookEvent::Open(e) => {
+                assert_eq!(e.base.mj_campaign_id, 4);
+                assert_eq!(e.ip, "127.0.0.1");
+                assert_eq!(e.geo, "US");
+                assert!(e.agent.starts_with("Mozilla/5.0"));
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_click() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/click.json"))
+                .unwrap();
+
+        match &events[0] {
+            WebhookEvent::Click(e) => {
+                assert_eq!(e.url, "https://mailjet.com");
+                assert_eq!(e.geo, "FR");
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_bounce() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/bounce.json"))
+                .unwrap();
+
+        match &events[0] {
+            WebhookEvent::Bounce(e) => {
+                assert_eq!(e.base.email, "bounce@mailjet.com");
+                assert!(!e.blocked);
+                assert!(e.hard_bounce);
+                assert_eq!(e.error_related_to, "recipient");
+                assert_eq!(e.error, "user unknown");
+                assert!(e.comment.starts_with("Host or domain name not found"));
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_blocked() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/blocked.json"))
+                .unwrap();
+
+        match &events[0] {
+            WebhookEvent::Blocked(e) => {
+                assert_eq!(e.error_related_to, "recipient");
+                assert_eq!(e.error, "user unknown");
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_spam() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/spam.json")).unwrap();
+
+        match &events[0] {
+            WebhookEvent::Spam(e) => {
+                assert_eq!(e.base.mj_contact_id, 2);
+                assert_eq!(e.source, "JMRPP");
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_unsub() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/unsub.json"))
+                .unwrap();
+
+        match &events[0] {
+            WebhookEvent::Unsub(e) => {
+                assert_eq!(e.mj_list_id, 1);
+                assert_eq!(e.ip, "127.0.0.1");
+            }
+            _ => panic!("Wrong event type"),
+        }
+    }
+
+    #[test]
+    fn parse_grouped() {
+        let events =
+            WebhookEvent::parse(include_bytes!("../../tests/fixtures/webhooks/grouped.json"))
+                .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type(), EventType::Open);
+        assert_eq!(events[1].event_type(), EventType::Click);
+        assert_eq!(events[0].base().message_id, events[1].base().message_id);
+    }
+
+    #[test]
+    fn parse_unknown_event() {
+        assert!(WebhookEvent::parse(br#"{"event": "unknown", "time": 1433334941}"#).is_err());
+        assert!(WebhookEvent::parse(b"not json").is_err());
+        assert!(WebhookEvent::parse(b"[not json]").is_err());
+    }
+
+    #[test]
+    fn parse_grouped_with_unknown_event() {
+        let body = br#"[
+            {"event": "unknown", "time": 1433334941},
+            {"event": "spam", "time": 1430812195, "email": "bounce@mailjet.com", "source": "JMRPP"}
+        ]"#;
+        let events = WebhookEvent::parse(body).unwrap();
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), EventType::Spam);
+    }
+}